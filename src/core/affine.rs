@@ -0,0 +1,248 @@
+//! Affine points and the deltas between them.
+//!
+//! Some quantities describe a position on a scale rather than an amount: a
+//! thermometer reading, an instant in time, a location along an axis or an
+//! absolute pressure. The difference between two such points is an ordinary
+//! quantity, but the sum of two points has no meaning. [`AffinePoint`]
+//! encodes these rules in the type system:
+//!
+//! - point − point = delta
+//! - point ± delta = point
+//! - point + point does not compile
+//!
+//! [`Temperature`](crate::thermal::Temperature) is the point type for
+//! [`TemperatureDelta`](crate::thermal::TemperatureDelta). For other
+//! quantities, [`Point`] measures a point as a delta from the origin of a
+//! [`Frame`]. The crate defines positions with `Length` displacements
+//! ([`Position`](crate::space::Position)), instants with `Time` durations
+//! ([`Instant`](crate::time::Instant)), and absolute and gauge pressures
+//! ([`AbsolutePressure`](crate::motion::AbsolutePressure),
+//! [`GaugePressure`](crate::motion::GaugePressure)).
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::prelude::*;
+//!
+//! let morning = Temperature::celsius(12.0);
+//! let afternoon = Temperature::fahrenheit(77.0);
+//!
+//! let rise: TemperatureDelta = afternoon - morning;
+//! assert!((rise.to_celsius_degrees() - 13.0).abs() < 1e-9);
+//! assert!(((morning + rise).to_celsius_scale() - 25.0).abs() < 1e-9);
+//! ```
+//!
+//! Adding two points is rejected at compile time:
+//!
+//! ```compile_fail
+//! use rquants::prelude::*;
+//!
+//! let sum = Temperature::celsius(12.0) + Temperature::celsius(25.0);
+//! ```
+
+use super::quantity::Quantity;
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// A position on an affine scale.
+///
+/// Subtracting two points yields a [`Delta`](AffinePoint::Delta), and a
+/// delta can be added to or subtracted from a point. Points cannot be added
+/// to each other.
+pub trait AffinePoint:
+    Copy
+    + PartialOrd
+    + Sub<Output = Self::Delta>
+    + Add<Self::Delta, Output = Self>
+    + Sub<Self::Delta, Output = Self>
+{
+    /// The quantity measuring the distance between two points.
+    type Delta: Quantity;
+
+    /// Returns the point a fraction `t` of the way from `self` to `other`.
+    fn lerp(self, other: Self, t: f64) -> Self {
+        self + (other - self).map(|v| v * t)
+    }
+
+    /// Returns the point halfway between `self` and `other`.
+    fn midpoint(self, other: Self) -> Self {
+        self.lerp(other, 0.5)
+    }
+}
+
+/// Marker type naming the origin that [`Point`]s are measured from.
+///
+/// Points in different frames have different types, so they cannot be
+/// subtracted or compared by accident; [`Point::to_frame`] converts between
+/// them.
+pub trait Frame: Copy + fmt::Debug {}
+
+/// The default frame, for points measured from an arbitrary fixed origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Origin;
+
+impl Frame for Origin {}
+
+/// A point measured as a delta `Q` from the origin of frame `F`.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::prelude::*;
+/// use rquants::space::Position;
+///
+/// let start = Position::new(Length::meters(120.0));
+/// let end = Position::new(Length::kilometers(1.0));
+///
+/// let displacement: Length = end - start;
+/// assert_eq!(displacement.to_meters(), 880.0);
+/// assert_eq!(start + displacement, end);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Point<Q: Quantity, F: Frame = Origin> {
+    offset: Q,
+    frame: PhantomData<F>,
+}
+
+impl<Q: Quantity, F: Frame> Point<Q, F> {
+    /// Creates the point at `offset` from the origin of the frame.
+    pub fn new(offset: Q) -> Self {
+        Self {
+            offset,
+            frame: PhantomData,
+        }
+    }
+
+    /// Returns the delta from the origin of the frame to this point.
+    pub fn offset(&self) -> Q {
+        self.offset
+    }
+
+    /// Returns this point with its offset expressed in another unit.
+    pub fn in_unit(&self, unit: Q::Unit) -> Self {
+        Self::new(self.offset.in_unit(unit))
+    }
+
+    /// Expresses this point in frame `G`, given where the origin of this
+    /// point's frame lies in `G`.
+    pub fn to_frame<G: Frame>(self, origin: Point<Q, G>) -> Point<Q, G> {
+        origin + self.offset
+    }
+
+    /// Adds a delta expressed in any unit, keeping this point's unit.
+    fn shifted(self, delta: Q, sign: f64) -> Self {
+        let unit = self.offset.unit();
+        Self::new(Q::new(self.offset.value() + sign * delta.to(unit), unit))
+    }
+}
+
+impl<Q: Quantity, F: Frame> AffinePoint for Point<Q, F> {
+    type Delta = Q;
+}
+
+impl<Q: Quantity, F: Frame> PartialEq for Point<Q, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+    }
+}
+
+impl<Q: Quantity, F: Frame> PartialOrd for Point<Q, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.offset.partial_cmp(&other.offset)
+    }
+}
+
+impl<Q: Quantity, F: Frame> fmt::Display for Point<Q, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.offset, f)
+    }
+}
+
+impl<Q: Quantity, F: Frame> Sub for Point<Q, F> {
+    type Output = Q;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let unit = self.offset.unit();
+        Q::new(self.offset.value() - rhs.offset.to(unit), unit)
+    }
+}
+
+impl<Q: Quantity, F: Frame> Add<Q> for Point<Q, F> {
+    type Output = Point<Q, F>;
+
+    fn add(self, rhs: Q) -> Self::Output {
+        self.shifted(rhs, 1.0)
+    }
+}
+
+impl<Q: Quantity, F: Frame> Sub<Q> for Point<Q, F> {
+    type Output = Point<Q, F>;
+
+    fn sub(self, rhs: Q) -> Self::Output {
+        self.shifted(rhs, -1.0)
+    }
+}
+
+impl<Q: Quantity, F: Frame> AddAssign<Q> for Point<Q, F> {
+    fn add_assign(&mut self, rhs: Q) {
+        *self = *self + rhs;
+    }
+}
+
+impl<Q: Quantity, F: Frame> SubAssign<Q> for Point<Q, F> {
+    fn sub_assign(&mut self, rhs: Q) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::space::{Length, LengthUnit};
+
+    #[derive(Debug, Clone, Copy)]
+    struct Datum;
+
+    impl Frame for Datum {}
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a: Point<Length> = Point::new(Length::meters(2.0));
+        let b = Point::new(Length::centimeters(50.0));
+        assert_eq!(a - b, Length::meters(1.5));
+        assert_eq!((b - a).unit(), LengthUnit::Centimeters);
+        assert_eq!(a + Length::meters(1.0), Point::new(Length::meters(3.0)));
+        assert_eq!((b - Length::meters(1.0)).offset().value(), -50.0);
+
+        let mut c = a;
+        c += Length::centimeters(25.0);
+        c -= Length::meters(0.5);
+        assert_eq!(c.offset(), Length::meters(1.75));
+        assert!(b < a);
+    }
+
+    #[test]
+    fn test_lerp_and_midpoint() {
+        let a: Point<Length> = Point::new(Length::meters(2.0));
+        let b = Point::new(Length::meters(6.0));
+        assert_eq!(a.midpoint(b).offset(), Length::meters(4.0));
+        assert_eq!(a.lerp(b, 0.25).offset(), Length::meters(3.0));
+    }
+
+    #[test]
+    fn test_to_frame() {
+        // The datum's origin lies 100 m from the default origin
+        let datum_origin: Point<Length> = Point::new(Length::meters(100.0));
+        let p: Point<Length, Datum> = Point::new(Length::meters(5.0));
+        assert_eq!(p.to_frame(datum_origin).offset(), Length::meters(105.0));
+    }
+
+    #[test]
+    fn test_display() {
+        let p: Point<Length> = Point::new(Length::kilometers(1.5));
+        assert_eq!(p.to_string(), "1.5 km");
+        assert_eq!(p.in_unit(LengthUnit::Meters).to_string(), "1500 m");
+    }
+}
@@ -0,0 +1,266 @@
+//! Choosing the most readable unit for a quantity.
+//!
+//! [`Quantity::to_best_unit`] rescales a quantity to the largest unit in
+//! which its magnitude is at least one, so three billion bytes read
+//! `3 GiB` and 93 784 seconds read `1.09 d`. A [`UnitPolicy`] restricts
+//! the units to choose from.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::prelude::*;
+//!
+//! let size = Information::bytes(3_221_225_472.0);
+//! assert_eq!(size.display_auto().to_string(), "3 GiB");
+//! assert_eq!(Time::seconds(93784.0).display_auto().to_string(), "1.09 d");
+//!
+//! let height = Length::meters(1.8).to_best_unit_with(UnitPolicy::only(LengthUnit::IMPERIAL));
+//! assert_eq!(height.unit(), LengthUnit::Feet);
+//! ```
+
+use crate::core::{Quantity, Scalar, UnitOfMeasure};
+
+/// The units [`Quantity::to_best_unit_with`] may choose from.
+///
+/// Units with an offset or non-linear conversion functions, such as
+/// temperature scales or wire gauges, are never chosen, and a quantity in
+/// such a unit is left in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitPolicy<U: 'static> {
+    kind: PolicyKind<U>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PolicyKind<U: 'static> {
+    Family,
+    All,
+    Si,
+    Only(&'static [U]),
+}
+
+impl<U: UnitOfMeasure> UnitPolicy<U> {
+    /// Rescales within the [family](UnitOfMeasure::family) of the
+    /// quantity's unit, so metric stays metric and binary multiples of the
+    /// byte stay binary. SI units outside any family are rescaled among all
+    /// SI units, and other units are kept. This is the default.
+    pub const fn family() -> Self {
+        Self {
+            kind: PolicyKind::Family,
+        }
+    }
+
+    /// Chooses from every unit of the dimension.
+    pub const fn all() -> Self {
+        Self {
+            kind: PolicyKind::All,
+        }
+    }
+
+    /// Chooses from the SI units of the dimension.
+    pub const fn si() -> Self {
+        Self {
+            kind: PolicyKind::Si,
+        }
+    }
+
+    /// Chooses from the given units, such as `LengthUnit::IMPERIAL` or
+    /// `InformationUnit::BINARY_BYTES`.
+    pub const fn only(units: &'static [U]) -> Self {
+        Self {
+            kind: PolicyKind::Only(units),
+        }
+    }
+
+    /// Returns the best unit for a value of `primary` in the primary unit,
+    /// currently expressed in `unit`.
+    fn choose(&self, primary: f64, unit: U) -> U {
+        let magnitude = primary.abs();
+        if magnitude == 0.0 || !magnitude.is_finite() || !unit.is_linear() {
+            return unit;
+        }
+        let (candidates, si_only) = match self.kind {
+            PolicyKind::Family if !unit.family().is_empty() => (unit.family(), false),
+            PolicyKind::Family if !unit.is_si() => return unit,
+            PolicyKind::Family | PolicyKind::Si => (U::all_units(), true),
+            PolicyKind::All => (U::all_units(), false),
+            PolicyKind::Only(units) => (units, false),
+        };
+
+        // The largest unit no larger than the magnitude, or failing that
+        // the smallest unit.
+        let mut largest_below: Option<(f64, U)> = None;
+        let mut smallest: Option<(f64, U)> = None;
+        for &candidate in candidates {
+            if !candidate.is_linear() || (si_only && !candidate.is_si()) {
+                continue;
+            }
+            let factor = candidate.conversion_factor();
+            // Allow for rounding, so that 1000 m is 1 km
+            if factor <= magnitude * (1.0 + 1e-12)
+                && largest_below.is_none_or(|(largest, _)| factor > largest)
+            {
+                largest_below = Some((factor, candidate));
+            }
+            if smallest.is_none_or(|(least, _)| factor < least) {
+                smallest = Some((factor, candidate));
+            }
+        }
+        largest_below.or(smallest).map_or(unit, |(_, best)| best)
+    }
+}
+
+impl<U: UnitOfMeasure> Default for UnitPolicy<U> {
+    fn default() -> Self {
+        Self::family()
+    }
+}
+
+/// Returns the unit `policy` chooses for `quantity`.
+pub(crate) fn best_unit<T: Scalar, Q: Quantity<T>>(
+    quantity: &Q,
+    policy: UnitPolicy<Q::Unit>,
+) -> Q::Unit {
+    policy.choose(quantity.to_primary().to_f64(), quantity.unit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::information::{Information, InformationUnit};
+    use crate::mass::{Mass, MassUnit};
+    use crate::space::{Length, LengthUnit};
+    use crate::thermal::{Temperature, TemperatureScale};
+    use crate::time::{Time, TimeUnit};
+
+    #[test]
+    fn test_family_policy() {
+        assert_eq!(
+            Length::meters(1500.0).to_best_unit().unit(),
+            LengthUnit::Kilometers
+        );
+        assert_eq!(
+            Length::meters(0.05).to_best_unit().unit(),
+            LengthUnit::Centimeters
+        );
+        assert_eq!(
+            Length::meters(500.0).to_best_unit().unit(),
+            LengthUnit::Meters
+        );
+        assert_eq!(
+            Length::meters(1000.0).to_best_unit().unit(),
+            LengthUnit::Kilometers
+        );
+        assert_eq!(Length::inches(30.0).to_best_unit().unit(), LengthUnit::Feet);
+        assert_eq!(
+            Length::feet(10_000.0).to_best_unit().unit(),
+            LengthUnit::Miles
+        );
+        assert_eq!(
+            Length::meters(-2500.0).to_best_unit().unit(),
+            LengthUnit::Kilometers
+        );
+        // Smaller than every unit of the family
+        assert_eq!(
+            Length::meters(1e-12).to_best_unit().unit(),
+            LengthUnit::Nanometers
+        );
+        // Units outside any family that are not SI stay
+        assert_eq!(
+            Length::yards(5000.0).to_best_unit().unit(),
+            LengthUnit::Yards
+        );
+        // SI units outside any family rescale among the SI units
+        assert_eq!(
+            Mass::grams(2500.0).to_best_unit().unit(),
+            MassUnit::Kilograms
+        );
+        assert_eq!(
+            Mass::kilograms(0.002).to_best_unit().unit(),
+            MassUnit::Grams
+        );
+        assert_eq!(Mass::pounds(3.0).to_best_unit().unit(), MassUnit::Pounds);
+    }
+
+    #[test]
+    fn test_information_and_time() {
+        let size = Information::bytes(3_221_225_472.0).to_best_unit();
+        assert_eq!(size.unit(), InformationUnit::Gibibytes);
+        assert_eq!(size.value(), 3.0);
+        let size = Information::kilobytes(2500.0).to_best_unit();
+        assert_eq!(size.unit(), InformationUnit::Megabytes);
+        assert_eq!(
+            Information::bits(8000.0).to_best_unit().unit(),
+            InformationUnit::Kilobits
+        );
+
+        assert_eq!(Time::seconds(93784.0).to_best_unit().unit(), TimeUnit::Days);
+        assert_eq!(Time::seconds(90.0).to_best_unit().unit(), TimeUnit::Minutes);
+        assert_eq!(
+            Time::seconds(0.0025).to_best_unit().unit(),
+            TimeUnit::Milliseconds
+        );
+    }
+
+    #[test]
+    fn test_other_policies() {
+        let length = Length::meters(1.8);
+        assert_eq!(
+            length
+                .to_best_unit_with(UnitPolicy::only(LengthUnit::IMPERIAL))
+                .unit(),
+            LengthUnit::Feet
+        );
+        assert_eq!(
+            Length::feet(5000.0)
+                .to_best_unit_with(UnitPolicy::si())
+                .unit(),
+            LengthUnit::Kilometers
+        );
+        assert_eq!(
+            Length::meters(0.05)
+                .to_best_unit_with(UnitPolicy::si())
+                .unit(),
+            LengthUnit::Centimeters
+        );
+        assert_eq!(
+            Length::meters(1e17)
+                .to_best_unit_with(UnitPolicy::all())
+                .unit(),
+            LengthUnit::Parsecs
+        );
+        let size = Information::bytes(3e9);
+        assert_eq!(
+            size.to_best_unit_with(UnitPolicy::only(InformationUnit::DECIMAL_BYTES))
+                .unit(),
+            InformationUnit::Gigabytes
+        );
+        assert_eq!(UnitPolicy::<LengthUnit>::default(), UnitPolicy::family());
+    }
+
+    #[test]
+    fn test_special_values_keep_their_unit() {
+        assert_eq!(Length::feet(0.0).to_best_unit().unit(), LengthUnit::Feet);
+        assert_eq!(
+            Length::feet(f64::NAN).to_best_unit().unit(),
+            LengthUnit::Feet
+        );
+        let t = Temperature::celsius(5000.0).to_best_unit();
+        assert_eq!(t.unit(), TemperatureScale::Celsius);
+    }
+
+    #[test]
+    fn test_display_auto() {
+        assert_eq!(
+            Information::bytes(3_221_225_472.0)
+                .display_auto()
+                .to_string(),
+            "3 GiB"
+        );
+        assert_eq!(Time::seconds(93784.0).display_auto().to_string(), "1.09 d");
+        assert_eq!(Length::meters(1234.0).display_auto().to_string(), "1.23 km");
+        assert_eq!(
+            format!("{:>9}", Length::millimeters(1500.0).display_auto()),
+            "    1.5 m"
+        );
+    }
+}
@@ -0,0 +1,614 @@
+//! Quantities written in several units at once.
+//!
+//! Heights, durations and angles are often written as a sum of descending
+//! units: `5 ft 11 in`, `1h 23m 4s` or `12° 30′ 15″`. A [`CompositeUnit`]
+//! lists those units, decomposes a quantity into them and writes the
+//! result; [`Dimension::parse_composite`](crate::core::Dimension::parse_composite)
+//! parses it back. Only the last component may have a fractional part, and
+//! it is rounded as configured.
+//!
+//! [`Dimension::parse`](crate::core::Dimension::parse) accepts composite
+//! strings written with unit symbols, such as `"5 ft 11 in"`, as well.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::prelude::*;
+//! use rquants::time::TimeDimension;
+//!
+//! let height = Length::meters(1.8);
+//! assert_eq!(CompositeUnit::FEET_INCHES.display(height).to_string(), "5 ft 11 in");
+//!
+//! let duration = Time::seconds(4984.0);
+//! assert_eq!(CompositeUnit::DURATION.display(duration).to_string(), "1h 23m 4s");
+//!
+//! let parsed = TimeDimension::parse_composite("1h 23m 4s", CompositeUnit::DURATION).unwrap();
+//! assert_eq!(parsed, duration);
+//!
+//! let angle = Angle::degrees(12.504166666666666);
+//! assert_eq!(CompositeUnit::DMS.display(angle).to_string(), "12° 30′ 15″");
+//! ```
+
+#[cfg(feature = "alloc")]
+use crate::core::error::ParseErrorKind;
+#[cfg(feature = "alloc")]
+use crate::core::format::is_ascii_spelling;
+use crate::core::format::{is_attached_symbol, write_padded};
+use crate::core::{Quantity, Scalar, UnitOfMeasure};
+use crate::math;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+
+/// How the last component of a composite quantity is rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rounding {
+    /// Rounds to the nearest value, so `5 ft 11.6 in` becomes `6 ft`.
+    #[default]
+    Nearest,
+    /// Rounds the magnitude down, toward zero, so a duration of 59.9 s
+    /// stays under a minute.
+    Down,
+    /// Rounds the magnitude up, away from zero.
+    Up,
+}
+
+/// A descending list of units that a quantity is written in, such as feet
+/// and inches.
+///
+/// Every component but the last is a whole number; the last is rounded to
+/// [`precision`](Self::precision) decimals with the given
+/// [`rounding`](Self::rounding). Components that are zero are left out
+/// unless [`keep_zeros`](Self::keep_zeros) is set.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::prelude::*;
+///
+/// let composite = CompositeUnit::new(&[LengthUnit::Miles, LengthUnit::Yards]).precision(1);
+/// assert_eq!(composite.display(Length::meters(2000.0)).to_string(), "1 mi 427.2 yd");
+///
+/// let composite = CompositeUnit::FEET_INCHES.rounding(Rounding::Down);
+/// assert_eq!(composite.display(Length::inches(71.9)).to_string(), "5 ft 11 in");
+/// assert_eq!(format!("{:.1}", composite.display(Length::inches(71.9))), "5 ft 11.9 in");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompositeUnit<U: 'static> {
+    units: &'static [U],
+    labels: Option<&'static [&'static str]>,
+    decimals: usize,
+    rounding: Rounding,
+    compact: bool,
+    keep_zeros: bool,
+}
+
+impl<U: UnitOfMeasure> CompositeUnit<U> {
+    /// Creates a composite unit from units in descending order, rounding
+    /// the last to the nearest whole number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `units` is empty.
+    pub const fn new(units: &'static [U]) -> Self {
+        assert!(
+            !units.is_empty(),
+            "a composite unit needs at least one unit"
+        );
+        Self {
+            units,
+            labels: None,
+            decimals: 0,
+            rounding: Rounding::Nearest,
+            compact: false,
+            keep_zeros: false,
+        }
+    }
+
+    /// Writes the components with these labels instead of the unit
+    /// symbols, such as `m` for minutes. Parsing accepts both.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one label per unit.
+    pub const fn labels(mut self, labels: &'static [&'static str]) -> Self {
+        assert!(
+            labels.len() == self.units.len(),
+            "a composite unit needs one label per unit"
+        );
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Rounds the last component to `decimals` digits after the decimal
+    /// point.
+    pub const fn precision(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Sets how the last component is rounded.
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Writes each label directly after its number, as in `1h 23m`.
+    pub const fn compact(mut self) -> Self {
+        self.compact = true;
+        self
+    }
+
+    /// Writes components that are zero, as in `12° 0′ 15″`.
+    pub const fn keep_zeros(mut self) -> Self {
+        self.keep_zeros = true;
+        self
+    }
+
+    /// Returns the units, largest first.
+    pub const fn units(&self) -> &'static [U] {
+        self.units
+    }
+
+    /// Splits a quantity into one quantity per unit, largest first,
+    /// including components that are zero.
+    ///
+    /// The components share the sign of the quantity and add up to it, up
+    /// to the rounding of the last one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    ///
+    /// let parts = CompositeUnit::FEET_INCHES.decompose(&Length::inches(-74.0));
+    /// assert_eq!(parts, [Length::feet(-6.0), Length::inches(-2.0)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn decompose<T: Scalar, Q: Quantity<T, Unit = U>>(&self, quantity: &Q) -> Vec<Q> {
+        let (negative, components) = self.components(quantity, self.decimals);
+        components
+            .map(|(unit, value)| {
+                let value = if negative { -value } else { value };
+                Q::new(T::from_f64(value), unit)
+            })
+            .collect()
+    }
+
+    /// Returns a value that writes `quantity` in this composite unit.
+    ///
+    /// A precision in the format string, as in `{:.1}`, overrides
+    /// [`precision`](Self::precision); the plus sign, width, fill and
+    /// alignment flags are honored as well.
+    pub fn display<T: Scalar, Q: Quantity<T, Unit = U>>(
+        &self,
+        quantity: Q,
+    ) -> CompositeDisplay<Q, T> {
+        CompositeDisplay {
+            composite: *self,
+            quantity,
+            scalar: PhantomData,
+        }
+    }
+
+    /// Returns the unit with this symbol, label or ASCII spelling of the
+    /// symbol.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn unit_by_label(&self, text: &str) -> Option<U> {
+        self.units.iter().enumerate().find_map(|(i, unit)| {
+            let symbol = unit.symbol();
+            let matches = symbol == text
+                || self.labels.is_some_and(|labels| labels[i] == text)
+                || is_ascii_spelling(symbol, text, false);
+            matches.then_some(*unit)
+        })
+    }
+
+    fn label(&self, index: usize) -> &'static str {
+        match self.labels {
+            Some(labels) => labels[index],
+            None => self.units[index].symbol(),
+        }
+    }
+
+    /// Rounds the magnitude of `quantity` in the last unit and returns
+    /// whether it is negative along with its components.
+    fn components<T: Scalar, Q: Quantity<T, Unit = U>>(
+        &self,
+        quantity: &Q,
+        decimals: usize,
+    ) -> (bool, Components<U>) {
+        let last = self.units[self.units.len() - 1];
+        let value = quantity.to(last).to_f64();
+        let scale = math::powi(10.0, decimals.min(17) as i32);
+        let scaled = snap(value.abs() * scale);
+        let rounded = match self.rounding {
+            Rounding::Nearest => math::round(scaled),
+            Rounding::Down => math::floor(scaled),
+            Rounding::Up => math::ceil(scaled),
+        };
+        let components = Components {
+            units: self.units,
+            last,
+            rest: rounded / scale,
+            scale,
+        };
+        (value.is_sign_negative() && !value.is_nan(), components)
+    }
+}
+
+/// Rounds `value` to a whole number if it is within rounding error of one,
+/// so that 11.999999999999998 inches count as a foot.
+fn snap(value: f64) -> f64 {
+    let rounded = math::round(value);
+    if math::abs(value - rounded) <= 1e-9 * rounded.max(1.0) {
+        rounded
+    } else {
+        value
+    }
+}
+
+/// The magnitude of each component of a rounded value, largest unit first.
+#[derive(Clone)]
+struct Components<U: 'static> {
+    units: &'static [U],
+    last: U,
+    rest: f64,
+    scale: f64,
+}
+
+impl<U: UnitOfMeasure> Iterator for Components<U> {
+    type Item = (U, f64);
+
+    fn next(&mut self) -> Option<(U, f64)> {
+        let (&unit, units) = self.units.split_first()?;
+        self.units = units;
+        if units.is_empty() {
+            // Drop the error accumulated by subtracting the larger units
+            return Some((unit, math::round(self.rest * self.scale) / self.scale));
+        }
+        if !self.rest.is_finite() {
+            return Some((unit, 0.0));
+        }
+        let ratio = snap(unit.convert_to(1.0, &self.last));
+        // The rest is a multiple of `1 / scale`, so half a step is enough
+        // to absorb rounding error in the division.
+        let count = math::floor((self.rest + 0.5 / self.scale) / ratio);
+        self.rest = (self.rest - count * ratio).max(0.0);
+        Some((unit, count))
+    }
+}
+
+/// A quantity written in a [`CompositeUnit`], created by
+/// [`CompositeUnit::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct CompositeDisplay<Q: Quantity<T>, T: Scalar = f64>
+where
+    Q::Unit: 'static,
+{
+    composite: CompositeUnit<Q::Unit>,
+    quantity: Q,
+    scalar: PhantomData<T>,
+}
+
+impl<T: Scalar, Q: Quantity<T>> CompositeDisplay<Q, T>
+where
+    Q::Unit: 'static,
+{
+    fn write_to<W: Write>(&self, out: &mut W, decimals: usize, plus: bool) -> fmt::Result {
+        let composite = &self.composite;
+        let (negative, components) = composite.components(&self.quantity, decimals);
+        let last = composite.units.len() - 1;
+        let rest = components.rest;
+        if !rest.is_finite() {
+            let value = if negative { -rest } else { rest };
+            if plus {
+                write!(out, "{:+}", value)?;
+            } else {
+                write!(out, "{}", value)?;
+            }
+            return self.write_label(out, last);
+        }
+
+        if negative && components.clone().any(|(_, value)| value != 0.0) {
+            out.write_char('-')?;
+        } else if plus {
+            out.write_char('+')?;
+        }
+        let mut written = false;
+        for (i, (_, value)) in components.enumerate() {
+            if value == 0.0 && !composite.keep_zeros && (written || i != last) {
+                continue;
+            }
+            if written {
+                out.write_char(' ')?;
+            }
+            if i == last {
+                write!(out, "{:.*}", decimals, value)?;
+            } else {
+                write!(out, "{}", value)?;
+            }
+            self.write_label(out, i)?;
+            written = true;
+        }
+        Ok(())
+    }
+
+    fn write_label<W: Write>(&self, out: &mut W, index: usize) -> fmt::Result {
+        let label = self.composite.label(index);
+        if !self.composite.compact && !is_attached_symbol(label) {
+            out.write_char(' ')?;
+        }
+        out.write_str(label)
+    }
+}
+
+impl<T: Scalar, Q: Quantity<T>> fmt::Display for CompositeDisplay<Q, T>
+where
+    Q::Unit: 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimals = f.precision().unwrap_or(self.composite.decimals);
+        let plus = f.sign_plus();
+        write_padded(f, |mut out| self.write_to(&mut out, decimals, plus))
+    }
+}
+
+/// Parses a sum of components such as `5 ft 11 in`, looking up each unit
+/// with `unit_by_label`, and returns its value in the last unit.
+///
+/// The units must be linear and strictly descending. A sign may only
+/// precede the first component and applies to the whole sum. On failure,
+/// returns what went wrong and its byte range in the trimmed input.
+#[cfg(feature = "alloc")]
+pub(crate) fn parse_components<U: UnitOfMeasure>(
+    s: &str,
+    unit_by_label: impl Fn(&str) -> Option<U>,
+) -> Result<(f64, U), (ParseErrorKind, Range<usize>)> {
+    let s = s.trim();
+    let (negative, mut rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let mut total: Option<(f64, U)> = None;
+    while !rest.is_empty() {
+        let start = s.len() - rest.len();
+        let number_end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        if number_end == 0 {
+            return Err((ParseErrorKind::MissingNumber, start..start));
+        }
+        let value: f64 = rest[..number_end]
+            .parse()
+            .map_err(|_| (ParseErrorKind::BadNumber, start..start + number_end))?;
+        rest = &rest[number_end..];
+        let label_end = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let label = rest[..label_end].trim();
+        let label_start = s.len() - rest.trim_start().len();
+        let span = label_start..label_start + label.len();
+        if label.is_empty() {
+            return Err((ParseErrorKind::MissingUnit, span));
+        }
+        let unit = unit_by_label(label).ok_or((ParseErrorKind::UnknownUnit, span.clone()))?;
+        rest = rest[label_end..].trim_start();
+        if !unit.is_linear() {
+            return Err((ParseErrorKind::Invalid, span));
+        }
+        total = Some(match total {
+            None => (value, unit),
+            Some((sum, previous)) if previous.convert_to(1.0, &unit) > 1.0 => {
+                (previous.convert_to(sum, &unit) + value, unit)
+            }
+            Some(_) => return Err((ParseErrorKind::Invalid, span)),
+        });
+    }
+    total
+        .map(|(sum, unit)| (if negative { -sum } else { sum }, unit))
+        .ok_or((ParseErrorKind::MissingNumber, s.len()..s.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Dimension;
+    use crate::mass::{Mass, MassUnit};
+    use crate::space::angle::AngleDimension;
+    use crate::space::length::LengthDimension;
+    use crate::space::{Angle, AngleUnit, Length, LengthUnit};
+    use crate::time::{Time, TimeDimension, TimeUnit};
+
+    #[test]
+    fn test_display_presets() {
+        let feet_inches = CompositeUnit::FEET_INCHES;
+        assert_eq!(
+            feet_inches.display(Length::inches(71.0)).to_string(),
+            "5 ft 11 in"
+        );
+        assert_eq!(
+            feet_inches.display(Length::inches(72.0)).to_string(),
+            "6 ft"
+        );
+        assert_eq!(feet_inches.display(Length::inches(7.0)).to_string(), "7 in");
+        assert_eq!(feet_inches.display(Length::inches(0.2)).to_string(), "0 in");
+        // Rounding carries into the larger unit
+        assert_eq!(
+            feet_inches.display(Length::inches(71.6)).to_string(),
+            "6 ft"
+        );
+        assert_eq!(
+            feet_inches.display(Length::centimeters(180.34)).to_string(),
+            "5 ft 11 in"
+        );
+
+        let duration = CompositeUnit::DURATION;
+        assert_eq!(
+            duration.display(Time::seconds(4984.0)).to_string(),
+            "1h 23m 4s"
+        );
+        assert_eq!(duration.display(Time::hours(26.0)).to_string(), "1d 2h");
+        assert_eq!(
+            duration.display(Time::minutes(90.5)).to_string(),
+            "1h 30m 30s"
+        );
+
+        let dms = CompositeUnit::DMS;
+        assert_eq!(dms.display(Angle::degrees(12.5)).to_string(), "12° 30′ 0″");
+        assert_eq!(dms.display(Angle::degrees(0.25)).to_string(), "0° 15′ 0″");
+
+        let pounds = CompositeUnit::POUNDS_OUNCES;
+        assert_eq!(pounds.display(Mass::ounces(116.0)).to_string(), "7 lb 4 oz");
+    }
+
+    #[test]
+    fn test_display_rounding_and_flags() {
+        let length = Length::inches(71.96);
+        let composite = CompositeUnit::FEET_INCHES;
+        assert_eq!(composite.precision(1).display(length).to_string(), "6 ft");
+        assert_eq!(
+            composite.precision(2).display(length).to_string(),
+            "5 ft 11.96 in"
+        );
+        assert_eq!(
+            composite
+                .rounding(Rounding::Down)
+                .display(length)
+                .to_string(),
+            "5 ft 11 in"
+        );
+        assert_eq!(
+            composite
+                .rounding(Rounding::Up)
+                .display(Length::inches(60.1))
+                .to_string(),
+            "5 ft 1 in"
+        );
+        assert_eq!(format!("{:.1}", composite.display(length)), "6 ft");
+        assert_eq!(format!("{:.2}", composite.display(length)), "5 ft 11.96 in");
+        assert_eq!(format!("{:+}", composite.display(length)), "+6 ft");
+        assert_eq!(
+            format!("{:>12}|", composite.display(Length::inches(71.0))),
+            "  5 ft 11 in|"
+        );
+        assert_eq!(
+            composite
+                .keep_zeros()
+                .display(Length::inches(7.0))
+                .to_string(),
+            "0 ft 7 in"
+        );
+    }
+
+    #[test]
+    fn test_display_negative_and_special_values() {
+        let composite = CompositeUnit::FEET_INCHES;
+        assert_eq!(
+            composite.display(Length::inches(-74.0)).to_string(),
+            "-6 ft 2 in"
+        );
+        assert_eq!(composite.display(Length::inches(-0.2)).to_string(), "0 in");
+        assert_eq!(
+            composite.display(Length::feet(f64::NAN)).to_string(),
+            "NaN in"
+        );
+        assert_eq!(
+            composite
+                .display(Length::feet(f64::NEG_INFINITY))
+                .to_string(),
+            "-inf in"
+        );
+    }
+
+    #[test]
+    fn test_decompose() {
+        let parts = CompositeUnit::DURATION.decompose(&Time::seconds(4984.0));
+        assert_eq!(
+            parts,
+            [
+                Time::days(0.0),
+                Time::hours(1.0),
+                Time::minutes(23.0),
+                Time::seconds(4.0)
+            ]
+        );
+        assert_eq!(parts[1].unit(), TimeUnit::Hours);
+        let parts = CompositeUnit::DMS.decompose(&Angle::degrees(12.504166666666666));
+        assert_eq!(parts[1], Angle::arc_minutes(30.0));
+        assert_eq!(parts[2].unit(), AngleUnit::ArcSeconds);
+        assert_eq!(parts[2].value(), 15.0);
+    }
+
+    #[test]
+    fn test_parse_composite() {
+        let parsed = LengthDimension::parse_composite("5 ft 11 in", CompositeUnit::FEET_INCHES);
+        assert_eq!(parsed.unwrap(), Length::inches(71.0));
+        let parsed = LengthDimension::parse_composite("5ft11in", CompositeUnit::FEET_INCHES);
+        assert_eq!(parsed.unwrap().unit(), LengthUnit::Inches);
+        let parsed = LengthDimension::parse_composite("-6 ft 2 in", CompositeUnit::FEET_INCHES);
+        assert_eq!(parsed.unwrap(), Length::inches(-74.0));
+        let parsed = LengthDimension::parse_composite("6 ft", CompositeUnit::FEET_INCHES);
+        assert_eq!(parsed.unwrap(), Length::feet(6.0));
+
+        let parsed = TimeDimension::parse_composite("1h 23m 4s", CompositeUnit::DURATION);
+        assert_eq!(parsed.unwrap(), Time::seconds(4984.0));
+        let parsed = TimeDimension::parse_composite("1 h 23 min", CompositeUnit::DURATION);
+        assert_eq!(parsed.unwrap(), Time::minutes(83.0));
+
+        let angle = Angle::degrees(12.504166666666666);
+        let parsed = AngleDimension::parse_composite("12° 30′ 15″", CompositeUnit::DMS).unwrap();
+        assert!((parsed - angle).to_degrees().abs() < 1e-12);
+        let parsed = AngleDimension::parse_composite("12deg 30' 15\"", CompositeUnit::DMS).unwrap();
+        assert!((parsed - angle).to_degrees().abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_parse_composite_errors() {
+        let composite = CompositeUnit::FEET_INCHES;
+        // Ascending, repeated, foreign, missing and signed units
+        assert!(LengthDimension::parse_composite("11 in 5 ft", composite).is_err());
+        assert!(LengthDimension::parse_composite("5 ft 6 ft", composite).is_err());
+        assert!(LengthDimension::parse_composite("5 ft 3 cm", composite).is_err());
+        assert!(LengthDimension::parse_composite("5 ft 11", composite).is_err());
+        assert!(LengthDimension::parse_composite("5 ft -11 in", composite).is_err());
+        assert!(LengthDimension::parse_composite("", composite).is_err());
+        let err = LengthDimension::parse_composite("tall", composite).unwrap_err();
+        assert_eq!(err.dimension, "Length");
+        assert_eq!((err.kind, err.span), (ParseErrorKind::MissingNumber, 0..0));
+
+        let err = LengthDimension::parse_composite(" 5 ft 3 cm", composite).unwrap_err();
+        assert_eq!((err.kind, err.span), (ParseErrorKind::UnknownUnit, 7..9));
+        let err = LengthDimension::parse_composite("5 ft 11", composite).unwrap_err();
+        assert_eq!((err.kind, err.span), (ParseErrorKind::MissingUnit, 7..7));
+        let err = LengthDimension::parse_composite("5 ft 1.2.3 in", composite).unwrap_err();
+        assert_eq!((err.kind, err.span), (ParseErrorKind::BadNumber, 5..10));
+        let err = LengthDimension::parse_composite("11 in 5 ft", composite).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::Invalid);
+        assert_eq!(err.span, 8..10);
+    }
+
+    #[test]
+    fn test_dimension_parse_accepts_composites() {
+        assert_eq!(
+            LengthDimension::parse("5 ft 11 in").unwrap(),
+            Length::inches(71.0)
+        );
+        assert_eq!(
+            LengthDimension::parse("1 m 20 cm").unwrap(),
+            Length::centimeters(120.0)
+        );
+        assert_eq!(
+            "1 h 23 min 4 s".parse::<Time>().unwrap(),
+            Time::seconds(4984.0)
+        );
+        assert_eq!(
+            "2 lb 8 oz".parse::<Mass>().unwrap().unit(),
+            MassUnit::Ounces
+        );
+        assert!(LengthDimension::parse("11 in 5 ft").is_err());
+    }
+}
@@ -1,8 +1,210 @@
 //! Dimension trait for quantity metadata and parsing.
 
-use super::error::QuantityParseError;
+#[cfg(feature = "alloc")]
+use super::composite::{parse_components, CompositeUnit};
+use super::dynamic::{self, DimensionVector, DynQuantity, DIMENSIONLESS};
+#[cfg(feature = "alloc")]
+use super::error::{ParseErrorKind, QuantityParseError};
+#[cfg(feature = "alloc")]
+use super::locale::NumberFormat;
+#[cfg(feature = "alloc")]
+use super::measured::{parse_measured, Measured};
 use super::quantity::Quantity;
 use super::unit::UnitOfMeasure;
+#[cfg(feature = "std")]
+use super::unit_expr::{self, UnitExpr};
+use super::unit_match::{self, UnitMatchError, UnitMatching};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+use core::ops::{Div, Mul};
+
+/// Exponent signature of a dimension.
+///
+/// Holds the exponents of the seven SI base dimensions plus two
+/// pseudo-dimensions that SI treats as dimensionless but that are useful to
+/// keep apart: plane angle (so `SolidAngle` is angle² and `LuminousFlux`
+/// differs from `LuminousIntensity`) and information.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::core::dimension::BaseDimensions;
+/// use rquants::energy::energy::EnergyDimension;
+/// use rquants::energy::specific_energy::SpecificEnergyDimension;
+/// use rquants::mass::mass::MassDimension;
+/// use rquants::radio::dose::DoseDimension;
+/// use rquants::Dimension;
+///
+/// // Gy and J/kg share a signature
+/// assert!(DoseDimension::is_compatible_with::<SpecificEnergyDimension>());
+///
+/// let per_mass = EnergyDimension::base_dimensions() / MassDimension::base_dimensions();
+/// assert_eq!(per_mass, SpecificEnergyDimension::base_dimensions());
+/// assert_eq!(per_mass.to_string(), "L²·T⁻²");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BaseDimensions {
+    si: DimensionVector,
+    angle: i8,
+    information: i8,
+}
+
+impl BaseDimensions {
+    /// A dimensionless signature.
+    pub const DIMENSIONLESS: BaseDimensions = BaseDimensions::new(DIMENSIONLESS);
+
+    /// Creates a signature from SI base-dimension exponents (L, M, T, I, Θ, N, J).
+    pub const fn new(si: DimensionVector) -> Self {
+        Self {
+            si,
+            angle: 0,
+            information: 0,
+        }
+    }
+
+    /// Sets the plane-angle exponent.
+    pub const fn with_angle(mut self, exponent: i8) -> Self {
+        self.angle = exponent;
+        self
+    }
+
+    /// Sets the information exponent.
+    pub const fn with_information(mut self, exponent: i8) -> Self {
+        self.information = exponent;
+        self
+    }
+
+    /// Returns the SI base-dimension exponents.
+    pub const fn si(&self) -> DimensionVector {
+        self.si
+    }
+
+    /// Returns the plane-angle exponent.
+    pub const fn angle(&self) -> i8 {
+        self.angle
+    }
+
+    /// Returns the information exponent.
+    pub const fn information(&self) -> i8 {
+        self.information
+    }
+
+    /// Returns true if every exponent, including pseudo-dimensions, is zero.
+    pub fn is_dimensionless(&self) -> bool {
+        *self == Self::DIMENSIONLESS
+    }
+
+    /// Returns true if the SI exponents match, ignoring pseudo-dimensions.
+    ///
+    /// For example, `Frequency` and `DataRate` are SI-compatible (both T⁻¹)
+    /// but not equal, since `DataRate` carries information.
+    pub fn is_si_compatible(&self, other: &BaseDimensions) -> bool {
+        self.si == other.si
+    }
+
+    /// Raises this signature to an integer power.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a resulting exponent does not fit in an `i8`.
+    pub fn powi(self, n: i8) -> Self {
+        self.checked_powi(i32::from(n))
+            .expect("dimension exponent overflow")
+    }
+
+    /// Raises this signature to an integer power, or returns `None` if a
+    /// resulting exponent does not fit in an `i8`.
+    pub(crate) fn checked_powi(self, n: i32) -> Option<Self> {
+        let pow = |d: i8| i8::try_from(i32::from(d).checked_mul(n)?).ok();
+        let mut si = self.si;
+        for d in &mut si {
+            *d = pow(*d)?;
+        }
+        Some(Self {
+            si,
+            angle: pow(self.angle)?,
+            information: pow(self.information)?,
+        })
+    }
+
+    /// Multiplies two signatures, or returns `None` if a resulting exponent
+    /// does not fit in an `i8`.
+    pub(crate) fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.combine(rhs, i8::checked_add)
+    }
+
+    /// Combines the exponents pairwise with `op`, a checked add or subtract.
+    fn combine(self, rhs: Self, op: fn(i8, i8) -> Option<i8>) -> Option<Self> {
+        let mut si = self.si;
+        for (out, d) in si.iter_mut().zip(rhs.si) {
+            *out = op(*out, d)?;
+        }
+        Some(Self {
+            si,
+            angle: op(self.angle, rhs.angle)?,
+            information: op(self.information, rhs.information)?,
+        })
+    }
+}
+
+/// # Panics
+///
+/// Panics if a resulting exponent does not fit in an `i8`.
+impl Mul for BaseDimensions {
+    type Output = BaseDimensions;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("dimension exponent overflow")
+    }
+}
+
+/// # Panics
+///
+/// Panics if a resulting exponent does not fit in an `i8`.
+impl Div for BaseDimensions {
+    type Output = BaseDimensions;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, i8::checked_sub)
+            .expect("dimension exponent overflow")
+    }
+}
+
+impl From<DimensionVector> for BaseDimensions {
+    fn from(si: DimensionVector) -> Self {
+        BaseDimensions::new(si)
+    }
+}
+
+/// Formats the signature using dimension symbols (e.g., "L·M·T⁻²").
+impl fmt::Display for BaseDimensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const SYMBOLS: [&str; 9] = ["L", "M", "T", "I", "Θ", "N", "J", "angle", "information"];
+        let exponents = self
+            .si
+            .iter()
+            .copied()
+            .chain([self.angle, self.information]);
+        let mut first = true;
+        for (d, symbol) in exponents.zip(SYMBOLS).filter(|(d, _)| *d != 0) {
+            if !first {
+                f.write_str("·")?;
+            }
+            first = false;
+            f.write_str(symbol)?;
+            if d != 1 {
+                dynamic::write_superscript(f, d)?;
+            }
+        }
+        if first {
+            f.write_str("1")?;
+        }
+        Ok(())
+    }
+}
 
 /// Trait for dimension metadata and factory operations.
 ///
@@ -13,6 +215,7 @@ use super::unit::UnitOfMeasure;
 /// # Example
 ///
 /// ```rust
+/// use rquants::core::dimension::BaseDimensions;
 /// use rquants::space::{Length, LengthUnit};
 /// use rquants::Dimension;
 ///
@@ -37,6 +240,10 @@ use super::unit::UnitOfMeasure;
 ///     fn units() -> &'static [Self::Unit] {
 ///         &[LengthUnit::Meters, LengthUnit::Kilometers, LengthUnit::Feet]
 ///     }
+///
+///     fn base_dimensions() -> BaseDimensions {
+///         BaseDimensions::new([1, 0, 0, 0, 0, 0, 0])
+///     }
 /// }
 /// ```
 pub trait Dimension {
@@ -62,73 +269,454 @@ pub trait Dimension {
     /// Returns all available units for this dimension.
     fn units() -> &'static [Self::Unit];
 
-    /// Attempts to find a unit by its symbol.
+    /// Returns the exponent signature of this dimension.
+    ///
+    /// The default is [`BaseDimensions::DIMENSIONLESS`]; dimensions with a
+    /// signature should override it, as quantities generated by the
+    /// `quantity!` macro do.
+    fn base_dimensions() -> BaseDimensions {
+        BaseDimensions::DIMENSIONLESS
+    }
+
+    /// Returns true if this dimension has the same signature as `Other`.
+    ///
+    /// Pseudo-dimensions (angle, information) must match as well;
+    /// use [`BaseDimensions::is_si_compatible`] to ignore them.
+    fn is_compatible_with<Other: Dimension>() -> bool {
+        Self::base_dimensions() == Other::base_dimensions()
+    }
+
+    /// Attempts to find a unit by its symbol, one of its
+    /// [aliases](UnitOfMeasure::aliases) or its long name, such as "km",
+    /// "kilometres" or "kilometer".
+    ///
+    /// Returns `None` if no unit or more than one unit matches; see
+    /// [`Dimension::find_unit`] for other matching modes.
     fn unit_by_symbol(symbol: &str) -> Option<Self::Unit> {
-        Self::units().iter().find(|u| u.symbol() == symbol).copied()
+        Self::find_unit(symbol, UnitMatching::exact()).ok()
+    }
+
+    /// Finds the unit that `text` denotes, as described in
+    /// [`unit_match`](crate::core::unit_match).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitMatchError::Ambiguous`] if several units match equally
+    /// well and [`UnitMatchError::Unknown`] if none does.
+    fn find_unit(
+        text: &str,
+        matching: UnitMatching,
+    ) -> Result<Self::Unit, UnitMatchError<Self::Unit>> {
+        unit_match::find_unit(Self::units(), text, matching)
+    }
+
+    /// Returns how [`Dimension::parse`] matches units. The default is
+    /// [`UnitMatching::exact`].
+    fn unit_matching() -> UnitMatching {
+        UnitMatching::exact()
+    }
+
+    /// Converts a runtime-dimensioned quantity into this dimension's quantity.
+    ///
+    /// Returns `None` if the dimensions are incompatible. The default
+    /// implementation supports no conversions; quantities generated by the
+    /// `quantity!` macro check the SI dimension vector.
+    fn from_dynamic(quantity: DynQuantity) -> Option<Self::Quantity> {
+        let _ = quantity;
+        None
     }
 
     /// Parses a string into a quantity.
     ///
     /// The string should be in the format "value unit" (e.g., "10 m", "5.5 kg").
+    /// If the unit is not one of this dimension's symbols, it is parsed as a
+    /// compound unit expression (e.g., "9.81 m/s^2", "3 kJ/min") and converted
+    /// with [`Dimension::from_dynamic`]. Unit expressions require the `std`
+    /// feature. Failing that, it is parsed as a sum of values in descending
+    /// units (e.g., "5 ft 11 in"), returned in the last unit.
+    ///
+    /// Units are matched as [`Dimension::unit_matching`] specifies, so
+    /// aliases and long names ("10 metres", "5.5 kilograms") are accepted.
     ///
     /// # Errors
     ///
-    /// Returns a `QuantityParseError` if the string cannot be parsed.
+    /// Returns a `QuantityParseError` if the string cannot be parsed or its
+    /// unit is ambiguous.
+    #[cfg(feature = "alloc")]
     fn parse(s: &str) -> Result<Self::Quantity, QuantityParseError> {
-        let s = s.trim();
+        Self::parse_with(s, Self::unit_matching())
+    }
 
-        // Try to find the split between value and unit
-        // Handle formats like "10m", "10 m", "10.5 m", "-10.5 m"
-        let (value_str, unit_str) = parse_value_and_unit(s, Self::name())?;
+    /// Parses a string into a quantity like [`Dimension::parse`], matching
+    /// units as `matching` specifies.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::core::UnitMatching;
+    /// use rquants::prelude::*;
+    /// use rquants::space::length::LengthDimension;
+    ///
+    /// let length = LengthDimension::parse_with("10 UM", UnitMatching::relaxed()).unwrap();
+    /// assert_eq!(length, Length::micrometers(10.0));
+    /// assert!(LengthDimension::parse("10 UM").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `QuantityParseError` if the string cannot be parsed or its
+    /// unit is ambiguous.
+    #[cfg(feature = "alloc")]
+    fn parse_with(s: &str, matching: UnitMatching) -> Result<Self::Quantity, QuantityParseError> {
+        parse_quantity::<Self>(s, matching, NumberFormat::PLAIN)
+    }
 
-        let value: f64 = value_str.parse().map_err(|_| QuantityParseError {
-            dimension: Self::name().to_string(),
-            input: s.to_string(),
-        })?;
+    /// Parses a string into a quantity like [`Dimension::parse`], reading
+    /// the number with the decimal and group separators of `format`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    /// use rquants::mass::mass::MassDimension;
+    /// use rquants::space::length::LengthDimension;
+    ///
+    /// let length = LengthDimension::parse_localized("1,5 km", NumberFormat::DE_DE).unwrap();
+    /// assert_eq!(length, Length::kilometers(1.5));
+    /// let mass = MassDimension::parse_localized("1.234,5 kg", NumberFormat::DE_DE).unwrap();
+    /// assert_eq!(mass, Mass::kilograms(1234.5));
+    /// assert!(LengthDimension::parse_localized("1,5 km", NumberFormat::EN_US).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `QuantityParseError` if the string cannot be parsed or its
+    /// unit is ambiguous.
+    #[cfg(feature = "alloc")]
+    fn parse_localized(
+        s: &str,
+        format: NumberFormat,
+    ) -> Result<Self::Quantity, QuantityParseError> {
+        parse_quantity::<Self>(s, Self::unit_matching(), format)
+    }
 
-        let unit = Self::unit_by_symbol(unit_str.trim()).ok_or_else(|| QuantityParseError {
-            dimension: Self::name().to_string(),
-            input: s.to_string(),
-        })?;
+    /// Parses a quantity written in a composite unit, such as "5 ft 11 in"
+    /// or "1h 23m 4s", and returns it in the last unit given.
+    ///
+    /// Each component is a number followed by the symbol or label of one of
+    /// the composite's units, in descending order; components may be left
+    /// out. A sign may precede the first component only.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    /// use rquants::space::angle::AngleDimension;
+    ///
+    /// let angle = AngleDimension::parse_composite("12° 30′ 36″", CompositeUnit::DMS).unwrap();
+    /// assert_eq!(angle.unit(), AngleUnit::ArcSeconds);
+    /// assert!((angle.to_degrees() - 12.51).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `QuantityParseError` if the string is not a sum of
+    /// components in the composite's units.
+    #[cfg(feature = "alloc")]
+    fn parse_composite(
+        s: &str,
+        composite: CompositeUnit<Self::Unit>,
+    ) -> Result<Self::Quantity, QuantityParseError> {
+        parse_components(s, |label| composite.unit_by_label(label))
+            .map(|(value, unit)| Self::Quantity::new(value, unit))
+            .map_err(|(kind, span)| QuantityParseError::new(Self::name(), s).with_kind(kind, span))
+    }
+
+    /// Parses a quantity with a standard uncertainty.
+    ///
+    /// Accepts `"10.0 ± 0.2 m"`, `"10.0 m ± 2 cm"`, `"(10.0 ± 0.2) m"` and
+    /// `"10.0 m ± 2%"`, with `+/-` as an alternative to `±`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    /// use rquants::space::length::LengthDimension;
+    ///
+    /// let measured = LengthDimension::parse_measured("10.0 ± 0.2 m").unwrap();
+    /// assert_eq!(measured.value(), Length::meters(10.0));
+    /// assert_eq!(measured.to_string(), "10 ± 0.2 m");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn parse_measured(s: &str) -> Result<Measured<Self::Quantity>, QuantityParseError> {
+        parse_measured(s, Self::name(), Self::parse)
+    }
+}
 
-        Ok(Self::Quantity::new(value, unit))
+/// Invokes the macro `$callback` with the paths of the built-in dimensions.
+///
+/// Base dimensions come first so that shared symbols ("g", "rad") resolve to
+/// the more common meaning.
+#[cfg(feature = "alloc")]
+macro_rules! builtin_dimensions {
+    ($callback:ident) => {
+        $callback! {
+            crate::space::length::LengthDimension,
+            crate::mass::mass::MassDimension,
+            crate::time::time::TimeDimension,
+            crate::electro::electric_current::ElectricCurrentDimension,
+            crate::mass::chemical_amount::ChemicalAmountDimension,
+            crate::photo::luminous_intensity::LuminousIntensityDimension,
+            crate::space::angle::AngleDimension,
+            crate::space::solid_angle::SolidAngleDimension,
+            crate::space::area::AreaDimension,
+            crate::space::volume::VolumeDimension,
+            crate::space::fuel_economy::FuelEconomyDimension,
+            crate::time::frequency::FrequencyDimension,
+            crate::motion::velocity::VelocityDimension,
+            crate::motion::acceleration::AccelerationDimension,
+            crate::motion::force::ForceDimension,
+            crate::motion::momentum::MomentumDimension,
+            crate::motion::pressure::PressureDimension,
+            crate::motion::volume_flow::VolumeFlowDimension,
+            crate::energy::energy::EnergyDimension,
+            crate::energy::power::PowerDimension,
+            crate::energy::power_ramp::PowerRampDimension,
+            crate::energy::specific_energy::SpecificEnergyDimension,
+            crate::energy::energy_density::EnergyDensityDimension,
+            crate::energy::power_density::PowerDensityDimension,
+            crate::energy::molar_energy::MolarEnergyDimension,
+            crate::mass::density::DensityDimension,
+            crate::mass::area_density::AreaDensityDimension,
+            crate::mass::moment_of_inertia::MomentOfInertiaDimension,
+            crate::electro::electric_charge::ElectricChargeDimension,
+            crate::electro::electric_potential::ElectricPotentialDimension,
+            crate::electro::electrical_resistance::ElectricalResistanceDimension,
+            crate::electro::electrical_conductance::ElectricalConductanceDimension,
+            crate::electro::capacitance::CapacitanceDimension,
+            crate::electro::inductance::InductanceDimension,
+            crate::electro::magnetic_flux::MagneticFluxDimension,
+            crate::electro::magnetic_flux_density::MagneticFluxDensityDimension,
+            crate::electro::resistivity::ResistivityDimension,
+            crate::electro::conductivity::ConductivityDimension,
+            crate::thermal::thermal_capacity::ThermalCapacityDimension,
+            crate::information::information::InformationDimension,
+            crate::information::data_rate::DataRateDimension,
+            crate::photo::luminous_flux::LuminousFluxDimension,
+            crate::photo::illuminance::IlluminanceDimension,
+            crate::photo::luminance::LuminanceDimension,
+            crate::photo::luminous_energy::LuminousEnergyDimension,
+            crate::photo::luminous_exposure::LuminousExposureDimension,
+            crate::radio::activity::ActivityDimension,
+            crate::radio::dose::DoseDimension,
+            crate::radio::irradiance::IrradianceDimension,
+            crate::radio::radiance::RadianceDimension,
+            crate::radio::radiant_intensity::RadiantIntensityDimension,
+            crate::radio::spectral_power::SpectralPowerDimension,
+            crate::radio::spectral_irradiance::SpectralIrradianceDimension,
+            crate::radio::particle_flux::ParticleFluxDimension,
+            crate::core::dimensionless::DimensionlessDimension,
+        }
+    };
+}
+#[cfg(feature = "std")]
+pub(crate) use builtin_dimensions;
+
+/// Parses a string into a quantity of `D`, as [`Dimension::parse_with`]
+/// and [`Dimension::parse_localized`] describe.
+#[cfg(feature = "alloc")]
+fn parse_quantity<D: Dimension + ?Sized>(
+    s: &str,
+    matching: UnitMatching,
+    format: NumberFormat,
+) -> Result<D::Quantity, QuantityParseError> {
+    let s = s.trim();
+
+    // Try to find the split between value and unit
+    // Handle formats like "10m", "10 m", "10.5 m", "-10.5 m"
+    let (value_str, unit_str) = parse_value_and_unit_in(s, D::name(), format)?;
+
+    let value = format.parse_number(value_str).ok_or_else(|| {
+        QuantityParseError::new(D::name(), s)
+            .with_kind(ParseErrorKind::BadNumber, 0..value_str.len())
+    })?;
+
+    let unit_str = unit_str.trim();
+    match D::find_unit(unit_str, matching) {
+        Ok(unit) => return Ok(D::Quantity::new(value, unit)),
+        Err(UnitMatchError::Ambiguous(first, second)) => {
+            return Err(QuantityParseError::new(D::name(), s)
+                .with_kind(ParseErrorKind::AmbiguousUnit, unit_span(s, unit_str))
+                .with_suggestions(Vec::from([first.symbol().into(), second.symbol().into()])));
+        }
+        Err(UnitMatchError::Unknown) => {}
+    }
+
+    #[cfg(feature = "std")]
+    if let Some(quantity) = UnitExpr::parse(unit_str)
+        .ok()
+        .filter(|expr| expr.base_dimensions() == D::base_dimensions())
+        .and_then(|expr| D::from_dynamic(expr.quantity(value)))
+    {
+        return Ok(quantity);
     }
+
+    let unit_by_label = |label: &str| D::find_unit(label, matching).ok();
+    if let Ok((value, unit)) = parse_components(s, unit_by_label) {
+        return Ok(D::Quantity::new(value, unit));
+    }
+
+    Err(unknown_unit_error::<D>(s, unit_str))
 }
 
 /// Helper function to parse a value and unit from a string.
-fn parse_value_and_unit<'a>(
+#[cfg(feature = "alloc")]
+pub(crate) fn parse_value_and_unit<'a>(
     s: &'a str,
     dimension: &str,
 ) -> Result<(&'a str, &'a str), QuantityParseError> {
-    let s = s.trim_start();
-    let value_end = s
-        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E')))
-        .unwrap_or(s.len());
+    parse_value_and_unit_in(s, dimension, NumberFormat::PLAIN)
+}
+
+/// Splits a string into a value written in `format` and a unit.
+#[cfg(feature = "alloc")]
+fn parse_value_and_unit_in<'a>(
+    s: &'a str,
+    dimension: &str,
+    format: NumberFormat,
+) -> Result<(&'a str, &'a str), QuantityParseError> {
+    let s = s.trim();
+    let value_end = format.number_len(s);
 
     if value_end == 0 {
-        return Err(QuantityParseError {
-            dimension: dimension.to_string(),
-            input: s.to_string(),
-        });
+        return Err(
+            QuantityParseError::new(dimension, s).with_kind(ParseErrorKind::MissingNumber, 0..0)
+        );
     }
 
     let (value_str, unit_str) = s.split_at(value_end);
     let unit_str = unit_str.trim();
 
     if unit_str.is_empty() {
-        return Err(QuantityParseError {
-            dimension: dimension.to_string(),
-            input: s.to_string(),
-        });
+        return Err(QuantityParseError::new(dimension, s)
+            .with_kind(ParseErrorKind::MissingUnit, s.len()..s.len()));
     }
 
     Ok((value_str, unit_str))
 }
 
+/// Returns the byte range of `unit`, a trailing part of `input`.
+#[cfg(feature = "alloc")]
+fn unit_span(input: &str, unit: &str) -> Range<usize> {
+    input.len() - unit.len()..input.len()
+}
+
+/// Returns the error for `unit`, a trailing part of `input` that is no unit
+/// of `D`: the dimension it belongs to if it is another's, or else the
+/// closest units of `D`.
+#[cfg(feature = "alloc")]
+fn unknown_unit_error<D: Dimension + ?Sized>(input: &str, unit: &str) -> QuantityParseError {
+    let error = QuantityParseError::new(D::name(), input);
+    #[cfg(feature = "std")]
+    let found = unit_expr::dimension_of(unit);
+    #[cfg(not(feature = "std"))]
+    let found = builtin_dimension_of(unit);
+    if let Some(found) = found.filter(|&found| found != D::name()) {
+        let kind = ParseErrorKind::WrongDimension {
+            found: found.into(),
+        };
+        return error.with_kind(kind, unit_span(input, unit));
+    }
+    error
+        .with_kind(ParseErrorKind::UnknownUnit, unit_span(input, unit))
+        .with_suggestions(unit_match::suggestions(D::units(), unit))
+}
+
+/// Returns the name of the built-in dimension with a unit spelled `symbol`.
+///
+/// Without `std` there is no unit expression parser, so only plain symbols
+/// are recognized.
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+fn builtin_dimension_of(symbol: &str) -> Option<&'static str> {
+    macro_rules! find_dimension {
+        ($($dimension:ty),+ $(,)?) => {
+            $(
+                if <$dimension>::find_unit(symbol, UnitMatching::exact()).is_ok() {
+                    return Some(<$dimension>::name());
+                }
+            )+
+        };
+    }
+    builtin_dimensions!(find_dimension);
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_base_dimensions_arithmetic() {
+        let length = BaseDimensions::new([1, 0, 0, 0, 0, 0, 0]);
+        let time = BaseDimensions::new([0, 0, 1, 0, 0, 0, 0]);
+        let acceleration = length / time.powi(2);
+        assert_eq!(acceleration.si(), [1, 0, -2, 0, 0, 0, 0]);
+        assert_eq!(acceleration.to_string(), "L·T⁻²");
+        assert_eq!((length / length), BaseDimensions::DIMENSIONLESS);
+        assert_eq!(BaseDimensions::DIMENSIONLESS.to_string(), "1");
+    }
+
+    #[test]
+    fn test_pseudo_dimensions() {
+        let steradian = BaseDimensions::DIMENSIONLESS.with_angle(2);
+        assert!(!steradian.is_dimensionless());
+        assert!(steradian.is_si_compatible(&BaseDimensions::DIMENSIONLESS));
+        assert_eq!(steradian.to_string(), "angle²");
+
+        let data_rate = BaseDimensions::new([0, 0, -1, 0, 0, 0, 0]).with_information(1);
+        assert_eq!(data_rate.information(), 1);
+        assert_eq!(data_rate.to_string(), "T⁻¹·information");
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension exponent overflow")]
+    fn test_mul_exponent_overflow() {
+        let length = BaseDimensions::new([100, 0, 0, 0, 0, 0, 0]);
+        let _ = length * length;
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension exponent overflow")]
+    fn test_powi_exponent_overflow() {
+        let _ = BaseDimensions::DIMENSIONLESS.with_angle(-100).powi(2);
+    }
+
+    #[test]
+    fn test_generated_base_dimensions() {
+        use crate::energy::energy::EnergyDimension;
+        use crate::energy::specific_energy::SpecificEnergyDimension;
+        use crate::mass::mass::MassDimension;
+        use crate::photo::luminous_flux::LuminousFluxDimension;
+        use crate::photo::luminous_intensity::LuminousIntensityDimension;
+        use crate::radio::dose::DoseDimension;
+        use crate::time::frequency::FrequencyDimension;
+        use crate::time::TimeDimension;
+
+        assert!(DoseDimension::is_compatible_with::<SpecificEnergyDimension>());
+        assert!(!EnergyDimension::is_compatible_with::<MassDimension>());
+        assert_eq!(
+            EnergyDimension::base_dimensions() / MassDimension::base_dimensions(),
+            SpecificEnergyDimension::base_dimensions()
+        );
+        assert_eq!(
+            BaseDimensions::DIMENSIONLESS / TimeDimension::base_dimensions(),
+            FrequencyDimension::base_dimensions()
+        );
+        assert!(!LuminousFluxDimension::is_compatible_with::<
+            LuminousIntensityDimension,
+        >());
+    }
+
     #[test]
     fn test_parse_value_and_unit() {
         assert_eq!(parse_value_and_unit("10 m", "Test").unwrap(), ("10", "m"));
@@ -152,8 +740,110 @@ mod tests {
     fn test_parse_value_and_unit_errors() {
         let err = parse_value_and_unit("m", "Length").unwrap_err();
         assert_eq!(err.dimension, "Length");
+        assert_eq!(err.kind, ParseErrorKind::MissingNumber);
 
-        assert!(parse_value_and_unit("10", "Length").is_err()); // No unit
+        let err = parse_value_and_unit("10", "Length").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::MissingUnit);
+        assert_eq!(err.span, 2..2);
+        let err = parse_value_and_unit(" 10  ", "Length").unwrap_err();
+        assert_eq!((err.input.as_str(), err.span), ("10", 2..2));
         assert!(parse_value_and_unit("", "Length").is_err()); // Empty
     }
+
+    #[test]
+    fn test_parse_error_diagnostics() {
+        use crate::energy::power::PowerDimension;
+        use crate::mass::mass::MassDimension;
+        use crate::space::length::LengthDimension;
+
+        let err = LengthDimension::parse(" 1.2.3 m").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::BadNumber);
+        assert_eq!(&err.input[err.span.clone()], "1.2.3");
+
+        let err = LengthDimension::parse("  10 kms ").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::UnknownUnit);
+        assert_eq!(err.input, "10 kms");
+        assert_eq!(err.span, 3..6);
+        assert_eq!(err.suggestions, ["km"]);
+        assert_eq!(
+            err.to_string(),
+            "Unable to parse Length: '10 kms': unknown unit 'kms' (did you mean 'km'?)"
+        );
+
+        let err = LengthDimension::parse("10 qwerty").unwrap_err();
+        assert!(err.suggestions.is_empty());
+        assert!(err.to_string().ends_with("unknown unit 'qwerty'"));
+
+        let err = PowerDimension::parse_with("5 mw", UnitMatching::relaxed()).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::AmbiguousUnit);
+        assert_eq!(err.suggestions, ["mW", "MW"]);
+
+        let err = MassDimension::parse("3 kilogramz").unwrap_err();
+        assert_eq!(err.suggestions, ["kilograms"]);
+    }
+
+    #[test]
+    fn test_parse_localized() {
+        use crate::mass::mass::MassDimension;
+        use crate::mass::Mass;
+        use crate::space::length::LengthDimension;
+        use crate::space::Length;
+
+        let de = NumberFormat::DE_DE;
+        assert_eq!(
+            LengthDimension::parse_localized("1,5 km", de).unwrap(),
+            Length::kilometers(1.5)
+        );
+        assert_eq!(
+            MassDimension::parse_localized("-1.234,5kg", de).unwrap(),
+            Mass::kilograms(-1234.5)
+        );
+        assert_eq!(
+            LengthDimension::parse_localized("1 234,5 m", NumberFormat::FR_FR).unwrap(),
+            Length::meters(1234.5)
+        );
+        assert_eq!(
+            LengthDimension::parse_localized("12,34,567 m", NumberFormat::EN_IN).unwrap(),
+            Length::meters(1_234_567.0)
+        );
+        assert_eq!(
+            LengthDimension::parse_localized("10 metres", NumberFormat::EN_US).unwrap(),
+            Length::meters(10.0)
+        );
+
+        let err = LengthDimension::parse_localized("1.23,5 km", de).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::BadNumber);
+        assert_eq!(err.span, 0..6);
+        // Plain parsing is unaffected
+        assert!(LengthDimension::parse("1,5 km").is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_error_wrong_dimension() {
+        use crate::energy::energy::EnergyDimension;
+        use crate::space::length::LengthDimension;
+
+        let err = LengthDimension::parse("5 kg").unwrap_err();
+        let found = String::from("Mass");
+        assert_eq!(err.kind, ParseErrorKind::WrongDimension { found });
+        assert_eq!(err.span, 2..4);
+        assert_eq!(
+            err.to_string(),
+            "Unable to parse Length: '5 kg': 'kg' is a Mass unit, expected Length"
+        );
+
+        let err = EnergyDimension::parse("1 N").unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("'N' is a Force unit, expected Energy"));
+
+        #[cfg(feature = "std")]
+        {
+            let err = LengthDimension::parse("5 m/s").unwrap_err();
+            assert!(err
+                .to_string()
+                .ends_with("'m/s' is a Velocity unit, expected Length"));
+        }
+    }
 }
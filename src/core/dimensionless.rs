@@ -3,10 +3,10 @@
 //! Represents quantities with no physical dimension, such as counts,
 //! percentages, and ratios between like quantities.
 
-use crate::core::{Dimension, Quantity, UnitOfMeasure};
-use std::cmp::Ordering;
-use std::fmt;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::core::{Dimension, Quantity, Rational, Scalar, UnitName, UnitOfMeasure};
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// Units of dimensionless measurement.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -51,6 +51,16 @@ impl UnitOfMeasure for DimensionlessUnit {
         }
     }
 
+    fn name(&self) -> UnitName {
+        UnitName::new(match self {
+            DimensionlessUnit::Each => "each",
+            DimensionlessUnit::Percent => "percent",
+            DimensionlessUnit::Dozen => "dozen",
+            DimensionlessUnit::Score => "score",
+            DimensionlessUnit::Gross => "gross",
+        })
+    }
+
     fn conversion_factor(&self) -> f64 {
         match self {
             DimensionlessUnit::Each => 1.0,
@@ -61,6 +71,16 @@ impl UnitOfMeasure for DimensionlessUnit {
         }
     }
 
+    fn exact_factor(&self) -> Option<Rational> {
+        Some(match self {
+            DimensionlessUnit::Each => Rational::ONE,
+            DimensionlessUnit::Percent => Rational::new(1, 100),
+            DimensionlessUnit::Dozen => Rational::integer(12),
+            DimensionlessUnit::Score => Rational::integer(20),
+            DimensionlessUnit::Gross => Rational::integer(144),
+        })
+    }
+
     fn is_primary(&self) -> bool {
         matches!(self, DimensionlessUnit::Each)
     }
@@ -68,6 +88,10 @@ impl UnitOfMeasure for DimensionlessUnit {
     fn is_si(&self) -> bool {
         matches!(self, DimensionlessUnit::Each)
     }
+
+    fn all_units() -> &'static [Self] {
+        DimensionlessUnit::ALL
+    }
 }
 
 /// A dimensionless quantity (counts, percentages, ratios).
@@ -91,14 +115,14 @@ impl UnitOfMeasure for DimensionlessUnit {
 /// assert!((result - 50.0).abs() < 1e-10);
 /// ```
 #[derive(Debug, Clone, Copy)]
-pub struct Dimensionless {
-    value: f64,
+pub struct Dimensionless<T = f64> {
+    value: T,
     unit: DimensionlessUnit,
 }
 
 impl Dimensionless {
     /// Creates a new Dimensionless quantity.
-    pub const fn new_const(value: f64, unit: DimensionlessUnit) -> Self {
+    pub fn new(value: f64, unit: DimensionlessUnit) -> Self {
         Self { value, unit }
     }
 
@@ -143,59 +167,98 @@ impl Dimensionless {
         Self::each(value * 1_000_000.0)
     }
 
+    /// Converts a runtime-dimensioned quantity, returning `None` unless it is
+    /// dimensionless. Not part of the public API; use
+    /// `Dimension::from_dynamic`.
+    #[doc(hidden)]
+    pub fn __from_dynamic(quantity: crate::core::DynQuantity) -> Option<Self> {
+        quantity
+            .is_dimensionless()
+            .then(|| Self::each(quantity.value()))
+    }
+}
+
+impl<T: Scalar> Dimensionless<T> {
+    /// Creates a new Dimensionless quantity.
+    pub const fn new_const(value: T, unit: DimensionlessUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Returns the value in "each". Used by relations generated with
+    /// `quantity!`; not part of the public API.
+    #[doc(hidden)]
+    pub fn __to_coherent(&self) -> T {
+        self.to_each()
+    }
+
+    /// Creates a quantity in "each". Used by relations generated with
+    /// `quantity!`; not part of the public API.
+    #[doc(hidden)]
+    pub fn __from_coherent(value: T) -> Self {
+        Self::new_const(value, DimensionlessUnit::Each)
+    }
+
+    /// Converts the stored value to another scalar type.
+    pub fn cast<U: Scalar>(self) -> Dimensionless<U> {
+        Dimensionless {
+            value: U::from_f64(self.value.to_f64()),
+            unit: self.unit,
+        }
+    }
+
     // Conversion methods
     /// Converts to "each" units.
-    pub fn to_each(&self) -> f64 {
+    pub fn to_each(&self) -> T {
         self.to(DimensionlessUnit::Each)
     }
 
     /// Converts to percent.
-    pub fn to_percent(&self) -> f64 {
+    pub fn to_percent(&self) -> T {
         self.to(DimensionlessUnit::Percent)
     }
 
     /// Converts to dozens.
-    pub fn to_dozen(&self) -> f64 {
+    pub fn to_dozen(&self) -> T {
         self.to(DimensionlessUnit::Dozen)
     }
 
     /// Converts to score.
-    pub fn to_score(&self) -> f64 {
+    pub fn to_score(&self) -> T {
         self.to(DimensionlessUnit::Score)
     }
 
     /// Converts to gross.
-    pub fn to_gross(&self) -> f64 {
+    pub fn to_gross(&self) -> T {
         self.to(DimensionlessUnit::Gross)
     }
 }
 
-impl fmt::Display for Dimensionless {
+impl<T: Scalar> fmt::Display for Dimensionless<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.value, self.unit.symbol())
+        fmt::Display::fmt(&self.display(), f)
     }
 }
 
-impl PartialEq for Dimensionless {
+impl<T: Scalar> PartialEq for Dimensionless<T> {
     fn eq(&self, other: &Self) -> bool {
         self.to_primary() == other.to_primary()
     }
 }
 
-impl PartialOrd for Dimensionless {
+impl<T: Scalar> PartialOrd for Dimensionless<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.to_primary().partial_cmp(&other.to_primary())
     }
 }
 
-impl Quantity for Dimensionless {
+impl<T: Scalar> Quantity<T> for Dimensionless<T> {
     type Unit = DimensionlessUnit;
 
-    fn new(value: f64, unit: Self::Unit) -> Self {
+    fn new(value: T, unit: Self::Unit) -> Self {
         Self { value, unit }
     }
 
-    fn value(&self) -> f64 {
+    fn value(&self) -> T {
         self.value
     }
 
@@ -206,127 +269,136 @@ impl Quantity for Dimensionless {
 
 // Arithmetic operations
 
-impl Add for Dimensionless {
-    type Output = Dimensionless;
+impl<T: Scalar> Add for Dimensionless<T> {
+    type Output = Dimensionless<T>;
 
     fn add(self, rhs: Self) -> Self::Output {
         let rhs_value = rhs.to(self.unit);
-        Dimensionless::new(self.value + rhs_value, self.unit)
+        Dimensionless::new_const(self.value + rhs_value, self.unit)
     }
 }
 
-impl AddAssign for Dimensionless {
+impl<T: Scalar> AddAssign for Dimensionless<T> {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs;
     }
 }
 
-impl Add<f64> for Dimensionless {
-    type Output = Dimensionless;
+impl<T: Scalar> Add<T> for Dimensionless<T> {
+    type Output = Dimensionless<T>;
 
-    fn add(self, rhs: f64) -> Self::Output {
-        self + Dimensionless::each(rhs)
+    fn add(self, rhs: T) -> Self::Output {
+        self + Dimensionless::new_const(rhs, DimensionlessUnit::Each)
     }
 }
 
-impl Sub for Dimensionless {
-    type Output = Dimensionless;
+impl<T: Scalar> Sub for Dimensionless<T> {
+    type Output = Dimensionless<T>;
 
     fn sub(self, rhs: Self) -> Self::Output {
         let rhs_value = rhs.to(self.unit);
-        Dimensionless::new(self.value - rhs_value, self.unit)
+        Dimensionless::new_const(self.value - rhs_value, self.unit)
     }
 }
 
-impl SubAssign for Dimensionless {
+impl<T: Scalar> SubAssign for Dimensionless<T> {
     fn sub_assign(&mut self, rhs: Self) {
         *self = *self - rhs;
     }
 }
 
-impl Sub<f64> for Dimensionless {
-    type Output = Dimensionless;
+impl<T: Scalar> Sub<T> for Dimensionless<T> {
+    type Output = Dimensionless<T>;
 
-    fn sub(self, rhs: f64) -> Self::Output {
-        self - Dimensionless::each(rhs)
+    fn sub(self, rhs: T) -> Self::Output {
+        self - Dimensionless::new_const(rhs, DimensionlessUnit::Each)
     }
 }
 
-impl Mul<f64> for Dimensionless {
-    type Output = Dimensionless;
+impl<T: Scalar> Mul<T> for Dimensionless<T> {
+    type Output = Dimensionless<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
-        Dimensionless::new(self.value * rhs, self.unit)
+    fn mul(self, rhs: T) -> Self::Output {
+        Dimensionless::new_const(self.value * rhs, self.unit)
     }
 }
 
-impl MulAssign<f64> for Dimensionless {
-    fn mul_assign(&mut self, rhs: f64) {
+impl<T: Scalar> MulAssign<T> for Dimensionless<T> {
+    fn mul_assign(&mut self, rhs: T) {
         self.value *= rhs;
     }
 }
 
-impl Mul<Dimensionless> for f64 {
-    type Output = Dimensionless;
+macro_rules! scalar_times_dimensionless {
+    ($($scalar:ty),+) => {
+        $(
+            impl Mul<Dimensionless<$scalar>> for $scalar {
+                type Output = Dimensionless<$scalar>;
 
-    fn mul(self, rhs: Dimensionless) -> Self::Output {
-        Dimensionless::new(self * rhs.value, rhs.unit)
-    }
+                fn mul(self, rhs: Dimensionless<$scalar>) -> Self::Output {
+                    Dimensionless::new_const(self * rhs.value, rhs.unit)
+                }
+            }
+        )+
+    };
 }
 
+scalar_times_dimensionless!(f32, f64);
+
 // Dimensionless * Dimensionless = Dimensionless
-impl Mul<Dimensionless> for Dimensionless {
-    type Output = Dimensionless;
+impl<T: Scalar> Mul<Dimensionless<T>> for Dimensionless<T> {
+    type Output = Dimensionless<T>;
 
-    fn mul(self, rhs: Dimensionless) -> Self::Output {
-        Dimensionless::each(self.to_each() * rhs.to_each())
+    fn mul(self, rhs: Dimensionless<T>) -> Self::Output {
+        Dimensionless::new_const(self.to_each() * rhs.to_each(), DimensionlessUnit::Each)
     }
 }
 
-impl Div<f64> for Dimensionless {
-    type Output = Dimensionless;
+impl<T: Scalar> Div<T> for Dimensionless<T> {
+    type Output = Dimensionless<T>;
 
-    fn div(self, rhs: f64) -> Self::Output {
-        Dimensionless::new(self.value / rhs, self.unit)
+    fn div(self, rhs: T) -> Self::Output {
+        Dimensionless::new_const(self.value / rhs, self.unit)
     }
 }
 
-impl DivAssign<f64> for Dimensionless {
-    fn div_assign(&mut self, rhs: f64) {
+impl<T: Scalar> DivAssign<T> for Dimensionless<T> {
+    fn div_assign(&mut self, rhs: T) {
         self.value /= rhs;
     }
 }
 
-impl Div<Dimensionless> for Dimensionless {
-    type Output = f64;
+impl<T: Scalar> Div<Dimensionless<T>> for Dimensionless<T> {
+    type Output = T;
 
-    fn div(self, rhs: Dimensionless) -> Self::Output {
+    fn div(self, rhs: Dimensionless<T>) -> Self::Output {
         self.to_primary() / rhs.to_primary()
     }
 }
 
-impl Neg for Dimensionless {
-    type Output = Dimensionless;
+impl<T: Scalar> Neg for Dimensionless<T> {
+    type Output = Dimensionless<T>;
 
     fn neg(self) -> Self::Output {
-        Dimensionless::new(-self.value, self.unit)
+        Dimensionless::new_const(-self.value, self.unit)
     }
 }
 
-impl std::iter::Sum for Dimensionless {
+impl<T: Scalar> core::iter::Sum for Dimensionless<T> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let total = iter.fold(0.0, |total, quantity| total + quantity.to_primary());
-        Dimensionless::new(total, DimensionlessUnit::Each)
+        let total = iter.fold(T::ZERO, |total, quantity| total + quantity.to_primary());
+        Dimensionless::new_const(total, DimensionlessUnit::Each)
     }
 }
 
-impl<'a> std::iter::Sum<&'a Dimensionless> for Dimensionless {
-    fn sum<I: Iterator<Item = &'a Dimensionless>>(iter: I) -> Self {
+impl<'a, T: Scalar> core::iter::Sum<&'a Dimensionless<T>> for Dimensionless<T> {
+    fn sum<I: Iterator<Item = &'a Dimensionless<T>>>(iter: I) -> Self {
         iter.copied().sum()
     }
 }
 
-impl std::str::FromStr for Dimensionless {
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for Dimensionless {
     type Err = crate::core::error::QuantityParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -335,23 +407,25 @@ impl std::str::FromStr for Dimensionless {
 }
 
 #[cfg(feature = "serde")]
-impl serde::Serialize for Dimensionless {
+impl<T: Scalar> serde::Serialize for Dimensionless<T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        serializer.collect_str(self)
     }
 }
 
 #[cfg(feature = "serde")]
-impl<'de> serde::Deserialize<'de> for Dimensionless {
+impl<'de, T: Scalar> serde::Deserialize<'de> for Dimensionless<T> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
-        <Self as std::str::FromStr>::from_str(&s).map_err(serde::de::Error::custom)
+        let s = <alloc::string::String as serde::Deserialize>::deserialize(deserializer)?;
+        <Dimensionless as core::str::FromStr>::from_str(&s)
+            .map(Dimensionless::cast)
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -384,6 +458,10 @@ impl Dimension for DimensionlessDimension {
     fn units() -> &'static [Self::Unit] {
         DimensionlessUnit::ALL
     }
+
+    fn from_dynamic(quantity: crate::core::DynQuantity) -> Option<Self::Quantity> {
+        Dimensionless::__from_dynamic(quantity)
+    }
 }
 
 /// Extension trait for creating Dimensionless quantities from numeric types.
@@ -0,0 +1,534 @@
+//! Runtime dimension-checked quantities.
+//!
+//! Every static quantity type (e.g., [`Length`](crate::space::Length)) only
+//! supports the cross-quantity operations that have been written for it. A
+//! [`DynQuantity`] instead carries its SI base-dimension exponents at runtime,
+//! so arbitrary products and quotients can be formed and converted back to a
+//! static type once the result is known.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::core::dynamic::DynQuantity;
+//! use rquants::prelude::*;
+//!
+//! // Specific heat capacity (J/(kg·K)) has no static type in the crate.
+//! let energy = DynQuantity::from(Energy::joules(8400.0));
+//! let mass = DynQuantity::from(Mass::kilograms(2.0));
+//! let delta_t = DynQuantity::from(TemperatureDelta::kelvin_degrees(1.0));
+//! let specific_heat = energy / mass / delta_t;
+//! assert_eq!(specific_heat.dims(), [2, 0, -2, 0, -1, 0, 0]);
+//!
+//! // Multiplying back by mass and temperature difference recovers an Energy.
+//! let back: Energy = (specific_heat * mass * delta_t).try_into().unwrap();
+//! assert!((back.to_joules() - 8400.0).abs() < 1e-9);
+//! ```
+
+#[cfg(feature = "std")]
+use super::dimension::parse_value_and_unit;
+use super::dimensionless::Dimensionless;
+#[cfg(feature = "alloc")]
+use super::dimensionless::DimensionlessUnit;
+#[cfg(feature = "alloc")]
+use super::error::QuantityError;
+#[cfg(feature = "std")]
+use super::error::QuantityParseError;
+use super::quantity::Quantity;
+use super::scalar::Scalar;
+#[cfg(feature = "std")]
+use super::unit_expr::{self, UnitExpr};
+use crate::math;
+use crate::thermal::Temperature;
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use core::fmt;
+use core::ops::{Div, Mul, Neg};
+
+/// Number of SI base dimensions.
+pub const BASE_DIMENSION_COUNT: usize = 7;
+
+/// Exponents of the SI base dimensions, in the order
+/// length (L), mass (M), time (T), electric current (I),
+/// thermodynamic temperature (Θ), amount of substance (N) and
+/// luminous intensity (J).
+pub type DimensionVector = [i8; BASE_DIMENSION_COUNT];
+
+/// The dimension vector of a dimensionless quantity.
+pub const DIMENSIONLESS: DimensionVector = [0; BASE_DIMENSION_COUNT];
+
+/// Coherent SI unit symbols for each base dimension.
+const BASE_SYMBOLS: [&str; BASE_DIMENSION_COUNT] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+/// A quantity whose dimension is tracked at runtime.
+///
+/// The value is always stored in coherent SI units (m, kg, s, A, K, mol, cd
+/// and their products), so multiplication and division never need unit
+/// conversion.
+///
+/// Multiplication and division are always defined. Addition and subtraction
+/// require matching dimensions and therefore return a `Result`, mirroring how
+/// [`Money`](crate::market::Money) handles mismatched currencies.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct DynQuantity {
+    value: f64,
+    dims: DimensionVector,
+}
+
+impl DynQuantity {
+    /// Creates a new dynamic quantity from a coherent SI value and its dimension vector.
+    pub const fn new(value: f64, dims: DimensionVector) -> Self {
+        Self { value, dims }
+    }
+
+    /// Creates a dimensionless dynamic quantity.
+    pub const fn dimensionless(value: f64) -> Self {
+        Self::new(value, DIMENSIONLESS)
+    }
+
+    /// Returns the value in coherent SI units.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the base-dimension exponents.
+    pub fn dims(&self) -> DimensionVector {
+        self.dims
+    }
+
+    /// Returns true if all base-dimension exponents are zero.
+    pub fn is_dimensionless(&self) -> bool {
+        self.dims == DIMENSIONLESS
+    }
+
+    /// Returns true if this quantity has the same dimension as `other`.
+    pub fn is_compatible(&self, other: &DynQuantity) -> bool {
+        self.dims == other.dims
+    }
+
+    /// Raises this quantity to an integer power.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a resulting exponent does not fit in an `i8`.
+    pub fn powi(self, n: i32) -> Self {
+        let mut dims = DIMENSIONLESS;
+        for (out, d) in dims.iter_mut().zip(self.dims) {
+            *out = i32::from(d)
+                .checked_mul(n)
+                .and_then(|exponent| i8::try_from(exponent).ok())
+                .expect("dimension exponent overflow");
+        }
+        Self::new(math::powi(self.value, n), dims)
+    }
+
+    /// Returns the reciprocal of this quantity.
+    pub fn recip(self) -> Self {
+        self.powi(-1)
+    }
+
+    /// Returns the square root of this quantity.
+    ///
+    /// Returns an error if any exponent is odd.
+    #[cfg(feature = "alloc")]
+    pub fn try_sqrt(self) -> Result<Self, QuantityError> {
+        if self.dims.iter().any(|d| d % 2 != 0) {
+            return Err(QuantityError::UnsupportedOperation(format!(
+                "Cannot take the square root of {}",
+                format_dims(&self.dims)
+            )));
+        }
+        Ok(Self::new(math::sqrt(self.value), self.dims.map(|d| d / 2)))
+    }
+
+    /// Returns the absolute value of this quantity.
+    pub fn abs(self) -> Self {
+        Self::new(math::abs(self.value), self.dims)
+    }
+
+    /// Adds two dynamic quantities, returning an error if dimensions differ.
+    #[cfg(feature = "alloc")]
+    pub fn checked_add(self, rhs: DynQuantity) -> Result<DynQuantity, QuantityError> {
+        self.can_operate_with(&rhs)?;
+        Ok(Self::new(self.value + rhs.value, self.dims))
+    }
+
+    /// Subtracts two dynamic quantities, returning an error if dimensions differ.
+    #[cfg(feature = "alloc")]
+    pub fn checked_sub(self, rhs: DynQuantity) -> Result<DynQuantity, QuantityError> {
+        self.can_operate_with(&rhs)?;
+        Ok(Self::new(self.value - rhs.value, self.dims))
+    }
+
+    /// Converts this quantity into a static quantity type.
+    ///
+    /// Equivalent to `Q::try_from(self)`.
+    #[cfg(feature = "alloc")]
+    pub fn to_static<Q>(self) -> Result<Q, QuantityError>
+    where
+        Q: TryFrom<DynQuantity, Error = QuantityError>,
+    {
+        Q::try_from(self)
+    }
+
+    /// Returns the names of the built-in dimensions matching this quantity's dimension.
+    ///
+    /// Several dimensions may share a signature (e.g., `Frequency` and `Activity`).
+    #[cfg(feature = "std")]
+    pub fn matching_dimensions(&self) -> Vec<&'static str> {
+        unit_expr::matching_dimensions(&self.dims)
+    }
+
+    /// Checks that this quantity has the expected dimension vector.
+    ///
+    /// `name` is the name of the target dimension, used in the error message.
+    #[cfg(feature = "alloc")]
+    pub fn expect_dims(&self, name: &str, dims: DimensionVector) -> Result<(), QuantityError> {
+        if self.dims == dims {
+            Ok(())
+        } else {
+            Err(QuantityError::ConversionError(format!(
+                "{} has dimension {}, expected {} ({})",
+                self,
+                format_dims(&self.dims),
+                name,
+                format_dims(&dims)
+            )))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn can_operate_with(&self, other: &DynQuantity) -> Result<(), QuantityError> {
+        if self.is_compatible(other) {
+            Ok(())
+        } else {
+            Err(QuantityError::UnsupportedOperation(format!(
+                "Cannot operate on different dimensions: {} and {}",
+                format_dims(&self.dims),
+                format_dims(&other.dims)
+            )))
+        }
+    }
+}
+
+/// Formats a dimension vector using coherent SI symbols (e.g., "m·s⁻²").
+#[cfg(feature = "alloc")]
+pub fn format_dims(dims: &DimensionVector) -> String {
+    let mut out = String::new();
+    write_dims(&mut out, dims).expect("writing to a String cannot fail");
+    out
+}
+
+/// Writes a dimension vector using coherent SI symbols without allocating.
+pub(crate) fn write_dims(out: &mut impl fmt::Write, dims: &DimensionVector) -> fmt::Result {
+    let mut first = true;
+    for (d, symbol) in dims.iter().zip(BASE_SYMBOLS).filter(|(d, _)| **d != 0) {
+        if !first {
+            out.write_char('·')?;
+        }
+        first = false;
+        out.write_str(symbol)?;
+        if *d != 1 {
+            write_superscript(out, *d)?;
+        }
+    }
+    if first {
+        out.write_char('1')?;
+    }
+    Ok(())
+}
+
+/// Writes an integer exponent using Unicode superscript digits.
+pub(crate) fn write_superscript(out: &mut impl fmt::Write, n: i8) -> fmt::Result {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    if n < 0 {
+        out.write_char('⁻')?;
+    }
+    let n = n.unsigned_abs();
+    if n >= 100 {
+        out.write_char(DIGITS[usize::from(n / 100)])?;
+    }
+    if n >= 10 {
+        out.write_char(DIGITS[usize::from(n / 10 % 10)])?;
+    }
+    out.write_char(DIGITS[usize::from(n % 10)])
+}
+
+impl fmt::Display for DynQuantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_dimensionless() {
+            write!(f, "{}", self.value)
+        } else {
+            write!(f, "{} ", self.value)?;
+            write_dims(f, &self.dims)
+        }
+    }
+}
+
+/// Parses strings such as "9.81 m/s^2" or "5 W/(m²·K)".
+#[cfg(feature = "std")]
+impl core::str::FromStr for DynQuantity {
+    type Err = QuantityParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value_str, unit_str) = parse_value_and_unit(s.trim(), "DynQuantity")?;
+        let value: f64 = value_str
+            .parse()
+            .map_err(|_| QuantityParseError::new("DynQuantity", s))?;
+        let expr =
+            UnitExpr::parse(unit_str).map_err(|_| QuantityParseError::new("DynQuantity", s))?;
+        Ok(expr.quantity(value))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::ops::Add for DynQuantity {
+    type Output = Result<DynQuantity, QuantityError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::ops::Sub for DynQuantity {
+    type Output = Result<DynQuantity, QuantityError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+    }
+}
+
+/// # Panics
+///
+/// Panics if a resulting exponent does not fit in an `i8`.
+impl Mul for DynQuantity {
+    type Output = DynQuantity;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut dims = self.dims;
+        for (out, d) in dims.iter_mut().zip(rhs.dims) {
+            *out = out.checked_add(d).expect("dimension exponent overflow");
+        }
+        DynQuantity::new(self.value * rhs.value, dims)
+    }
+}
+
+/// # Panics
+///
+/// Panics if a resulting exponent does not fit in an `i8`.
+impl Div for DynQuantity {
+    type Output = DynQuantity;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let mut dims = self.dims;
+        for (out, d) in dims.iter_mut().zip(rhs.dims) {
+            *out = out.checked_sub(d).expect("dimension exponent overflow");
+        }
+        DynQuantity::new(self.value / rhs.value, dims)
+    }
+}
+
+impl Mul<f64> for DynQuantity {
+    type Output = DynQuantity;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        DynQuantity::new(self.value * rhs, self.dims)
+    }
+}
+
+impl Mul<DynQuantity> for f64 {
+    type Output = DynQuantity;
+
+    fn mul(self, rhs: DynQuantity) -> Self::Output {
+        DynQuantity::new(self * rhs.value, rhs.dims)
+    }
+}
+
+impl Div<f64> for DynQuantity {
+    type Output = DynQuantity;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        DynQuantity::new(self.value / rhs, self.dims)
+    }
+}
+
+impl Div<DynQuantity> for f64 {
+    type Output = DynQuantity;
+
+    fn div(self, rhs: DynQuantity) -> Self::Output {
+        DynQuantity::dimensionless(self) / rhs
+    }
+}
+
+impl Neg for DynQuantity {
+    type Output = DynQuantity;
+
+    fn neg(self) -> Self::Output {
+        DynQuantity::new(-self.value, self.dims)
+    }
+}
+
+impl From<f64> for DynQuantity {
+    fn from(value: f64) -> Self {
+        DynQuantity::dimensionless(value)
+    }
+}
+
+impl<T: Scalar> From<Dimensionless<T>> for DynQuantity {
+    fn from(quantity: Dimensionless<T>) -> Self {
+        DynQuantity::dimensionless(quantity.to_primary().to_f64())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<DynQuantity> for Dimensionless {
+    type Error = QuantityError;
+
+    fn try_from(quantity: DynQuantity) -> Result<Self, Self::Error> {
+        quantity.expect_dims("Dimensionless", DIMENSIONLESS)?;
+        Ok(Dimensionless::new(quantity.value, DimensionlessUnit::Each))
+    }
+}
+
+/// Temperatures are converted using the Kelvin scale.
+impl From<Temperature> for DynQuantity {
+    fn from(temperature: Temperature) -> Self {
+        DynQuantity::new(temperature.to_kelvin_scale(), [0, 0, 0, 0, 1, 0, 0])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<DynQuantity> for Temperature {
+    type Error = QuantityError;
+
+    fn try_from(quantity: DynQuantity) -> Result<Self, Self::Error> {
+        quantity.expect_dims("Temperature", [0, 0, 0, 0, 1, 0, 0])?;
+        Ok(Temperature::kelvin(quantity.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::energy::{Energy, Power, PowerRamp};
+    use crate::mass::Mass;
+    use crate::motion::{Acceleration, Force, Velocity};
+    use crate::space::{Area, Length};
+    use crate::time::Time;
+
+    #[test]
+    fn test_mul_div_dims() {
+        let l = DynQuantity::from(Length::meters(10.0));
+        let t = DynQuantity::from(Time::seconds(2.0));
+        let v = l / t;
+        assert_eq!(v.dims(), [1, 0, -1, 0, 0, 0, 0]);
+        assert_eq!(v.value(), 5.0);
+
+        let velocity: Velocity = v.try_into().unwrap();
+        assert_eq!(velocity.to_meters_per_second(), 5.0);
+    }
+
+    #[test]
+    fn test_non_coherent_primary_units() {
+        // Mass is stored in grams, Energy in watt-hours
+        let m = DynQuantity::from(Mass::grams(500.0));
+        assert_eq!(m.value(), 0.5);
+
+        let e = DynQuantity::from(Energy::watt_hours(1.0));
+        assert!((e.value() - 3600.0).abs() < 1e-9);
+
+        let ramp = DynQuantity::from(PowerRamp::watts_per_hour(3600.0));
+        assert!((ramp.value() - 1.0).abs() < 1e-12);
+        let back: PowerRamp = ramp.try_into().unwrap();
+        assert!((back.to_watts_per_hour() - 3600.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_round_trip_through_static_types() {
+        let f =
+            DynQuantity::from(Mass::kilograms(2.0)) * Acceleration::meters_per_second_squared(3.0);
+        let force: Force = f.try_into().unwrap();
+        assert!((force.to_newtons() - 6.0).abs() < 1e-12);
+
+        let p = Energy::joules(100.0) / DynQuantity::from(Time::seconds(4.0));
+        assert!((p.to_static::<Power>().unwrap().to_watts() - 25.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_try_from_wrong_dimension() {
+        let v = DynQuantity::from(Length::meters(1.0)) / Time::seconds(1.0);
+        let result: Result<Length, _> = v.try_into();
+        assert!(matches!(result, Err(QuantityError::ConversionError(_))));
+    }
+
+    #[test]
+    fn test_checked_add_sub() {
+        let a = DynQuantity::from(Length::meters(1.0));
+        let b = DynQuantity::from(Length::kilometers(1.0));
+        assert_eq!((a + b).unwrap().value(), 1001.0);
+        assert_eq!((b - a).unwrap().value(), 999.0);
+
+        let t = DynQuantity::from(Time::seconds(1.0));
+        assert!((a + t).is_err());
+        assert!(a.checked_sub(t).is_err());
+    }
+
+    #[test]
+    fn test_powi_and_sqrt() {
+        let l = DynQuantity::from(Length::meters(3.0));
+        let area: Area = l.powi(2).try_into().unwrap();
+        assert_eq!(area.to_square_meters(), 9.0);
+
+        let side = DynQuantity::from(area).try_sqrt().unwrap();
+        assert_eq!(side.dims(), [1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(side.value(), 3.0);
+
+        assert!(l.try_sqrt().is_err());
+        assert_eq!(l.recip().dims(), [-1, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension exponent overflow")]
+    fn test_mul_exponent_overflow() {
+        let l = DynQuantity::new(1.0, [100, 0, 0, 0, 0, 0, 0]);
+        let _ = l * l;
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension exponent overflow")]
+    fn test_div_exponent_overflow() {
+        let l = DynQuantity::new(1.0, [100, 0, 0, 0, 0, 0, 0]);
+        let _ = l / l.powi(-1);
+    }
+
+    #[test]
+    fn test_dimensionless_result() {
+        let ratio = DynQuantity::from(Length::meters(6.0)) / Length::meters(2.0);
+        assert!(ratio.is_dimensionless());
+        let d: Dimensionless = ratio.try_into().unwrap();
+        assert_eq!(d.to_each(), 3.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_str() {
+        let q: DynQuantity = "2 W/(m²·K)".parse().unwrap();
+        assert_eq!(q.dims(), [0, 1, -3, 0, -1, 0, 0]);
+        assert_eq!(q.value(), 2.0);
+        assert!(q.matching_dimensions().is_empty());
+
+        let f: DynQuantity = "3 kN".parse().unwrap();
+        assert_eq!(f.value(), 3000.0);
+        assert_eq!(f.matching_dimensions(), vec!["Force"]);
+
+        assert!("3 furlongs".parse::<DynQuantity>().is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let a = DynQuantity::from(Acceleration::meters_per_second_squared(9.81));
+        assert_eq!(a.to_string(), "9.81 m·s⁻²");
+        assert_eq!(DynQuantity::dimensionless(2.0).to_string(), "2");
+        assert_eq!(format_dims(&[2, 1, -3, -1, 0, 0, 0]), "m²·kg·s⁻³·A⁻¹");
+    }
+}
@@ -1,6 +1,9 @@
 //! Error types for quantity operations.
 
-use std::fmt;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
 
 /// Errors that can occur when working with quantities.
 #[derive(Debug, Clone, PartialEq)]
@@ -31,8 +34,8 @@ impl fmt::Display for QuantityError {
     }
 }
 
-impl std::error::Error for QuantityError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for QuantityError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             QuantityError::ParseError(e) => Some(e),
             _ => None,
@@ -47,30 +50,139 @@ impl From<QuantityParseError> for QuantityError {
 }
 
 /// Error parsing a quantity from a string.
+///
+/// Besides the input, it records what went wrong, where, and for unknown
+/// units the closest known spellings. More fields may be added, so build one
+/// with [`QuantityParseError::new`] and [`QuantityParseError::with_kind`]
+/// rather than a struct literal:
+///
+/// ```rust
+/// use rquants::prelude::*;
+///
+/// let err = "10 kms".parse::<Length>().unwrap_err();
+/// assert_eq!(err.kind, ParseErrorKind::UnknownUnit);
+/// assert_eq!(&err.input[err.span.clone()], "kms");
+/// assert_eq!(err.suggestions, ["km"]);
+/// assert_eq!(
+///     err.to_string(),
+///     "Unable to parse Length: '10 kms': unknown unit 'kms' (did you mean 'km'?)"
+/// );
+///
+/// let err = "5 kg".parse::<Length>().unwrap_err();
+/// assert!(err.to_string().ends_with("'kg' is a Mass unit, expected Length"));
+/// ```
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct QuantityParseError {
     /// The dimension being parsed (e.g., "Length", "Mass").
     pub dimension: String,
-    /// The input string that failed to parse.
+    /// The input string that failed to parse, with surrounding whitespace
+    /// trimmed.
     pub input: String,
+    /// What is wrong with the input.
+    pub kind: ParseErrorKind,
+    /// The byte range of the offending part of `input`, relative to the
+    /// trimmed string stored there rather than the string passed to the
+    /// parser.
+    pub span: Range<usize>,
+    /// Known units close to an unknown or ambiguous one, closest first.
+    pub suggestions: Vec<String>,
+}
+
+/// What is wrong with a string that failed to parse as a quantity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input is malformed in some other way.
+    Invalid,
+    /// The input does not start with a number.
+    MissingNumber,
+    /// The number is not valid, such as `1.2.3`.
+    BadNumber,
+    /// The number is not followed by a unit.
+    MissingUnit,
+    /// The unit is not known.
+    UnknownUnit,
+    /// The unit could denote more than one unit of the dimension.
+    AmbiguousUnit,
+    /// The unit belongs to another dimension.
+    WrongDimension {
+        /// The name of the dimension the unit belongs to.
+        found: String,
+    },
 }
 
 impl fmt::Display for QuantityParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Unable to parse {}: '{}'", self.dimension, self.input)
+        write!(f, "Unable to parse {}: '{}'", self.dimension, self.input)?;
+        let token = self.input.get(self.span.clone()).unwrap_or_default();
+        match &self.kind {
+            ParseErrorKind::Invalid => return Ok(()),
+            ParseErrorKind::MissingNumber => f.write_str(": missing number")?,
+            ParseErrorKind::BadNumber => write!(f, ": invalid number '{token}'")?,
+            ParseErrorKind::MissingUnit => f.write_str(": missing unit")?,
+            ParseErrorKind::UnknownUnit => write!(f, ": unknown unit '{token}'")?,
+            ParseErrorKind::AmbiguousUnit => write!(f, ": ambiguous unit '{token}'")?,
+            ParseErrorKind::WrongDimension { found } => {
+                let article = match found.chars().next() {
+                    Some('A' | 'E' | 'I' | 'O' | 'U') => "an",
+                    _ => "a",
+                };
+                write!(
+                    f,
+                    ": '{token}' is {article} {found} unit, expected {}",
+                    self.dimension
+                )?;
+            }
+        }
+        if let Some((last, rest)) = self.suggestions.split_last() {
+            f.write_str(" (did you mean ")?;
+            for (i, suggestion) in rest.iter().enumerate() {
+                let separator = if i == 0 { "" } else { ", " };
+                write!(f, "{separator}'{suggestion}'")?;
+            }
+            if !rest.is_empty() {
+                f.write_str(" or ")?;
+            }
+            write!(f, "'{last}'?)")?;
+        }
+        Ok(())
     }
 }
 
-impl std::error::Error for QuantityParseError {}
+impl core::error::Error for QuantityParseError {}
 
 impl QuantityParseError {
     /// Creates a new parse error.
+    ///
+    /// The input is stored trimmed. Its kind is [`ParseErrorKind::Invalid`]
+    /// and its span the whole trimmed input.
     pub fn new(dimension: impl Into<String>, input: impl Into<String>) -> Self {
+        let mut input: String = input.into();
+        input.truncate(input.trim_end().len());
+        input.drain(..input.len() - input.trim_start().len());
         Self {
             dimension: dimension.into(),
-            input: input.into(),
+            span: 0..input.len(),
+            input,
+            kind: ParseErrorKind::Invalid,
+            suggestions: Vec::new(),
         }
     }
+
+    /// Sets what is wrong with the input and the byte range where, relative
+    /// to the trimmed input.
+    pub fn with_kind(mut self, kind: ParseErrorKind, span: Range<usize>) -> Self {
+        self.kind = kind;
+        self.span = span;
+        self
+    }
+
+    /// Sets the units suggested in place of the offending one.
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -81,6 +193,44 @@ mod tests {
     fn test_parse_error_display() {
         let err = QuantityParseError::new("Length", "invalid");
         assert_eq!(err.to_string(), "Unable to parse Length: 'invalid'");
+        assert_eq!(err.kind, ParseErrorKind::Invalid);
+        assert_eq!(err.span, 0..7);
+
+        let err = QuantityParseError::new("Length", "  5 kms ");
+        assert_eq!(err.input, "5 kms");
+        assert_eq!(err.span, 0..5);
+    }
+
+    #[test]
+    fn test_parse_error_kinds_display() {
+        let err =
+            QuantityParseError::new("Length", "1.2.3 m").with_kind(ParseErrorKind::BadNumber, 0..5);
+        assert_eq!(
+            err.to_string(),
+            "Unable to parse Length: '1.2.3 m': invalid number '1.2.3'"
+        );
+
+        let err = QuantityParseError::new("Power", "5 mw")
+            .with_kind(ParseErrorKind::AmbiguousUnit, 2..4)
+            .with_suggestions(vec!["mW".into(), "MW".into()]);
+        assert_eq!(
+            err.to_string(),
+            "Unable to parse Power: '5 mw': ambiguous unit 'mw' (did you mean 'mW' or 'MW'?)"
+        );
+
+        let err = QuantityParseError::new("Length", "5 m")
+            .with_kind(ParseErrorKind::UnknownUnit, 2..3)
+            .with_suggestions(vec!["mi".into(), "mm".into(), "nm".into()]);
+        assert!(err
+            .to_string()
+            .ends_with("(did you mean 'mi', 'mm' or 'nm'?)"));
+
+        let found = String::from("Energy");
+        let err = QuantityParseError::new("Mass", "3 kJ")
+            .with_kind(ParseErrorKind::WrongDimension { found }, 2..4);
+        assert!(err
+            .to_string()
+            .ends_with(": 'kJ' is an Energy unit, expected Mass"));
     }
 
     #[test]
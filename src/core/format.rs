@@ -0,0 +1,807 @@
+//! Configurable formatting of quantities.
+//!
+//! `Display` for a quantity writes its value and unit symbol, and honors the
+//! standard format flags: precision (`{:.2}`), the plus sign (`{:+}`), and
+//! width, fill and alignment (`{:>12}`, right-aligned by default).
+//! [`Quantity::display`] returns a [`QuantityFormatter`] with further
+//! options: significant figures, scientific or engineering notation, the
+//! spacing recommended by the SI brochure, ASCII-only symbols, long unit
+//! names and locale-specific separators. None of them allocate.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::prelude::*;
+//!
+//! let length = Length::meters(1234.5678);
+//! assert_eq!(format!("{:.1}", length), "1234.6 m");
+//! assert_eq!(format!("{:>10.0}|", length), "    1235 m|");
+//! assert_eq!(format!("{}", length.display().sig_figs(3)), "1230 m");
+//! assert_eq!(format!("{}", length.display().engineering()), "1.2345678e3 m");
+//! assert_eq!(
+//!     format!("{}", length.display().sig_figs(3).long_names()),
+//!     "1230 meters"
+//! );
+//!
+//! let resistance = ElectricalResistance::kilohms(4.7);
+//! assert_eq!(format!("{}", resistance.display().ascii()), "4.7 kohm");
+//! ```
+
+use crate::core::locale::{write_localized, NumberFormat};
+use crate::core::unit_match::Matcher;
+use crate::core::{Quantity, Scalar, UnitOfMeasure};
+use core::fmt::{self, Write};
+use core::marker::PhantomData;
+
+/// How the value of a quantity is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Notation {
+    /// Positional notation, such as `12300`.
+    #[default]
+    Decimal,
+    /// Scientific notation, such as `1.23e4`.
+    Scientific,
+    /// Scientific notation with an exponent that is a multiple of three,
+    /// such as `12.3e3`.
+    Engineering,
+}
+
+/// What separates the value from the unit symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Spacing {
+    Space,
+    Si,
+    Compact,
+}
+
+/// How many digits of the value to write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Digits {
+    Decimals(usize),
+    Significant(usize),
+}
+
+/// The most significant digits written in scientific or engineering
+/// notation, or when rounding to significant figures.
+const MAX_DIGITS: usize = 40;
+
+const NARROW_NO_BREAK_SPACE: char = '\u{202F}';
+
+/// A quantity with formatting options, created by [`Quantity::display`].
+///
+/// A precision given in the format string, as in `{:.2}`, takes precedence
+/// over [`precision`](Self::precision) and [`sig_figs`](Self::sig_figs).
+/// The plus sign, width, fill and alignment flags are honored as well.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::prelude::*;
+///
+/// let capacitance = Capacitance::farads(0.000_047);
+/// assert_eq!(format!("{}", capacitance.display().engineering()), "47e-6 F");
+/// assert_eq!(format!("{:.2}", capacitance.display().scientific()), "4.70e-5 F");
+///
+/// let angle = Angle::degrees(90.0);
+/// assert_eq!(format!("{}", angle.display().si_spacing()), "90°");
+/// assert_eq!(format!("{}", Length::meters(1.0).display().long_names()), "1 meter");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct QuantityFormatter<Q, T = f64> {
+    quantity: Q,
+    digits: Option<Digits>,
+    notation: Notation,
+    spacing: Spacing,
+    ascii: bool,
+    long_names: bool,
+    trim_zeros: bool,
+    number_format: NumberFormat,
+    scalar: PhantomData<T>,
+}
+
+impl<T: Scalar, Q: Quantity<T>> QuantityFormatter<Q, T> {
+    /// Creates a formatter writing the quantity like its `Display`.
+    pub fn new(quantity: Q) -> Self {
+        Self {
+            quantity,
+            digits: None,
+            notation: Notation::Decimal,
+            spacing: Spacing::Space,
+            ascii: false,
+            long_names: false,
+            trim_zeros: false,
+            number_format: NumberFormat::PLAIN,
+            scalar: PhantomData,
+        }
+    }
+
+    /// Writes `decimals` digits after the decimal point (of the mantissa in
+    /// scientific or engineering notation).
+    pub fn precision(mut self, decimals: usize) -> Self {
+        self.digits = Some(Digits::Decimals(decimals));
+        self
+    }
+
+    /// Rounds the value to `figures` significant figures, keeping trailing
+    /// zeros, so `1.5` to three figures is `1.50`.
+    ///
+    /// `figures` is clamped to between 1 and 40.
+    pub fn sig_figs(mut self, figures: usize) -> Self {
+        self.digits = Some(Digits::Significant(figures.clamp(1, MAX_DIGITS)));
+        self
+    }
+
+    /// Sets the notation of the value.
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Writes the value in scientific notation, such as `1.23e4`.
+    pub fn scientific(self) -> Self {
+        self.notation(Notation::Scientific)
+    }
+
+    /// Writes the value in engineering notation, such as `12.3e3`.
+    pub fn engineering(self) -> Self {
+        self.notation(Notation::Engineering)
+    }
+
+    /// Separates value and symbol with a narrow no-break space (U+202F), as
+    /// the SI brochure recommends, and writes the degree, minute and second
+    /// of arc without one.
+    pub fn si_spacing(mut self) -> Self {
+        self.spacing = Spacing::Si;
+        self
+    }
+
+    /// Writes the symbol directly after the value, as in `20°C`.
+    pub fn compact(mut self) -> Self {
+        self.spacing = Spacing::Compact;
+        self
+    }
+
+    /// Writes only ASCII characters, spelling out symbols such as `µ` as
+    /// `u`, `°` as `deg`, `Ω` as `ohm` and `m²` as `m^2`.
+    pub fn ascii(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+
+    /// Writes the long unit name instead of the symbol, in the singular for
+    /// a value of exactly one.
+    pub fn long_names(mut self) -> Self {
+        self.long_names = true;
+        self
+    }
+
+    /// Drops trailing zeros after the decimal point, so `1.50` reads `1.5`
+    /// and `3.00` reads `3`.
+    pub fn trim_zeros(mut self) -> Self {
+        self.trim_zeros = true;
+        self
+    }
+
+    /// Writes the decimal separator and groups the digits of the integer
+    /// part as `format` specifies, so `1234.5 m` reads `1.234,5 m` in
+    /// [`NumberFormat::DE_DE`].
+    pub fn number_format(mut self, format: NumberFormat) -> Self {
+        self.number_format = format;
+        self
+    }
+
+    fn write_to<W: Write>(&self, out: &mut W, digits: Option<Digits>, plus: bool) -> fmt::Result {
+        if self.ascii {
+            self.write_parts(
+                &mut Ascii {
+                    out,
+                    superscript: false,
+                },
+                digits,
+                plus,
+            )
+        } else {
+            self.write_parts(out, digits, plus)
+        }
+    }
+
+    fn write_parts<W: Write>(
+        &self,
+        out: &mut W,
+        digits: Option<Digits>,
+        plus: bool,
+    ) -> fmt::Result {
+        let value = self.quantity.value();
+        let unit = self.quantity.unit();
+        write_localized(out, self.number_format, |mut out| {
+            if self.trim_zeros {
+                let mut trimmed = TrimZeros {
+                    out: &mut out,
+                    fraction: false,
+                    point: false,
+                    zeros: 0,
+                };
+                self.write_value(&mut trimmed, value, digits, plus)
+            } else {
+                self.write_value(&mut out, value, digits, plus)
+            }
+        })?;
+        if self.long_names {
+            out.write_char(match self.spacing {
+                Spacing::Si => NARROW_NO_BREAK_SPACE,
+                _ => ' ',
+            })?;
+            return if value.to_f64().abs() == 1.0 {
+                write!(out, "{}", unit.name().singular())
+            } else {
+                write!(out, "{}", unit.name())
+            };
+        }
+        let symbol = unit.symbol();
+        match self.spacing {
+            Spacing::Space => out.write_char(' ')?,
+            Spacing::Si if !is_attached_symbol(symbol) => out.write_char(NARROW_NO_BREAK_SPACE)?,
+            _ => {}
+        }
+        out.write_str(symbol)
+    }
+
+    fn write_value<W: Write>(
+        &self,
+        out: &mut W,
+        value: T,
+        digits: Option<Digits>,
+        plus: bool,
+    ) -> fmt::Result {
+        if !value.to_f64().is_finite() {
+            return write_sign_aware(
+                out,
+                plus,
+                format_args!("{}", value),
+                format_args!("{:+}", value),
+            );
+        }
+        match (self.notation, digits) {
+            (Notation::Decimal, None) => write_sign_aware(
+                out,
+                plus,
+                format_args!("{}", value),
+                format_args!("{:+}", value),
+            ),
+            (Notation::Decimal, Some(Digits::Decimals(decimals))) => write_sign_aware(
+                out,
+                plus,
+                format_args!("{:.*}", decimals, value),
+                format_args!("{:+.*}", decimals, value),
+            ),
+            (Notation::Decimal, Some(Digits::Significant(figures))) => {
+                let number = DecimalDigits::new(value, Some(figures - 1))?;
+                number.write(out, plus, number.exponent + 1)
+            }
+            (Notation::Scientific, digits) => {
+                let value = Exp(value);
+                match digits {
+                    None => write_sign_aware(
+                        out,
+                        plus,
+                        format_args!("{:e}", value),
+                        format_args!("{:+e}", value),
+                    ),
+                    Some(digits) => {
+                        let decimals = match digits {
+                            Digits::Decimals(decimals) => decimals.min(MAX_DIGITS - 1),
+                            Digits::Significant(figures) => figures - 1,
+                        };
+                        write_sign_aware(
+                            out,
+                            plus,
+                            format_args!("{:.*e}", decimals, value),
+                            format_args!("{:+.*e}", decimals, value),
+                        )
+                    }
+                }
+            }
+            (Notation::Engineering, digits) => {
+                let number = match digits {
+                    None => DecimalDigits::new(value, None)?,
+                    Some(Digits::Significant(figures)) => {
+                        DecimalDigits::new(value, Some(figures - 1))?
+                    }
+                    Some(Digits::Decimals(decimals)) => {
+                        // Shifting the point by `shift` places needs as many
+                        // more digits; rounding may carry into the next
+                        // power of ten and change the shift.
+                        let decimals = decimals.min(MAX_DIGITS - 3);
+                        let exponent = DecimalDigits::new(value, None)?.exponent;
+                        let shift = exponent.rem_euclid(3) as usize;
+                        let number = DecimalDigits::new(value, Some(decimals + shift))?;
+                        if number.exponent == exponent {
+                            number
+                        } else {
+                            let shift = number.exponent.rem_euclid(3) as usize;
+                            DecimalDigits::new(value, Some(decimals + shift))?
+                        }
+                    }
+                };
+                let shift = number.exponent.rem_euclid(3);
+                number.write(out, plus, shift + 1)?;
+                write!(out, "e{}", number.exponent - shift)
+            }
+        }
+    }
+}
+
+impl<T: Scalar, Q: Quantity<T>> fmt::Display for QuantityFormatter<Q, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = f.precision().map(Digits::Decimals).or(self.digits);
+        let plus = f.sign_plus();
+        write_padded(f, |mut out| self.write_to(&mut out, digits, plus))
+    }
+}
+
+/// Calls `write` with `f`, padded to the width of `f` with its fill and
+/// alignment, right-aligned by default.
+pub(crate) fn write_padded(
+    f: &mut fmt::Formatter<'_>,
+    write: impl Fn(&mut dyn Write) -> fmt::Result,
+) -> fmt::Result {
+    let Some(width) = f.width() else {
+        return write(f);
+    };
+    let mut count = CharCount(0);
+    write(&mut count)?;
+    let padding = width.saturating_sub(count.0);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    write(f)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Returns true for the degree, minute and second of arc, which are
+/// written without a space after the number.
+pub(crate) fn is_attached_symbol(symbol: &str) -> bool {
+    matches!(symbol, "°" | "′" | "″")
+}
+
+/// Returns true if `text` is the ASCII spelling of `symbol` written by
+/// [`QuantityFormatter::ascii`], such as `deg` for `°` or `um` for `µm`.
+pub(crate) fn is_ascii_spelling(symbol: &str, text: &str, ignore_case: bool) -> bool {
+    let mut matcher = Matcher {
+        rest: text,
+        ignore_case,
+    };
+    let mut ascii = Ascii {
+        out: &mut matcher,
+        superscript: false,
+    };
+    ascii.write_str(symbol).is_ok() && matcher.rest.is_empty()
+}
+
+fn write_sign_aware<W: Write>(
+    out: &mut W,
+    plus: bool,
+    unsigned: fmt::Arguments<'_>,
+    signed: fmt::Arguments<'_>,
+) -> fmt::Result {
+    out.write_fmt(if plus { signed } else { unsigned })
+}
+
+// Formats a scalar with `Scalar::fmt_exp`.
+struct Exp<T>(T);
+
+impl<T: Scalar> fmt::LowerExp for Exp<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_exp(f)
+    }
+}
+
+/// The decimal digits of a finite value, `d.ddd × 10^exponent`.
+struct DecimalDigits {
+    negative: bool,
+    digits: [u8; MAX_DIGITS],
+    len: usize,
+    exponent: i32,
+}
+
+impl DecimalDigits {
+    /// Rounds `value` to `decimals` digits after the first, or to the
+    /// shortest digits that read back as `value`.
+    fn new<T: Scalar>(value: T, decimals: Option<usize>) -> Result<Self, fmt::Error> {
+        let mut buffer = Buffer {
+            bytes: [0; MAX_DIGITS + 16],
+            len: 0,
+        };
+        match decimals {
+            Some(decimals) => write!(buffer, "{:.*e}", decimals, Exp(value))?,
+            None => write!(buffer, "{:e}", Exp(value))?,
+        }
+        let text = buffer.as_str();
+        let (mantissa, exponent) = text.split_once('e').ok_or(fmt::Error)?;
+        let (negative, mantissa) = match mantissa.strip_prefix('-') {
+            Some(mantissa) => (true, mantissa),
+            None => (false, mantissa),
+        };
+        let mut number = Self {
+            negative,
+            digits: [0; MAX_DIGITS],
+            len: 0,
+            exponent: exponent.parse().map_err(|_| fmt::Error)?,
+        };
+        for digit in mantissa.bytes().filter(u8::is_ascii_digit) {
+            *number.digits.get_mut(number.len).ok_or(fmt::Error)? = digit;
+            number.len += 1;
+        }
+        Ok(number)
+    }
+
+    /// Writes the digits in positional notation with `point` digits before
+    /// the decimal point, padding with zeros as needed.
+    fn write<W: Write>(&self, out: &mut W, plus: bool, point: i32) -> fmt::Result {
+        if self.negative {
+            out.write_char('-')?;
+        } else if plus {
+            out.write_char('+')?;
+        }
+        let digits = &self.digits[..self.len];
+        if point <= 0 {
+            out.write_str("0.")?;
+            for _ in point..0 {
+                out.write_char('0')?;
+            }
+            return digits.iter().try_for_each(|&d| out.write_char(d as char));
+        }
+        let point = point as usize;
+        for i in 0..point {
+            out.write_char(digits.get(i).map_or('0', |&d| d as char))?;
+        }
+        if digits.len() > point {
+            out.write_char('.')?;
+            for &d in &digits[point..] {
+                out.write_char(d as char)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A fixed-size string buffer.
+struct Buffer {
+    bytes: [u8; MAX_DIGITS + 16],
+    len: usize,
+}
+
+impl Buffer {
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or("")
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Holds back zeros after a decimal point until a non-zero digit follows,
+/// dropping them (and the point) at the end of the number or before an
+/// exponent.
+struct TrimZeros<'a, W> {
+    out: &'a mut W,
+    fraction: bool,
+    point: bool,
+    zeros: usize,
+}
+
+impl<W: Write> Write for TrimZeros<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match c {
+            '.' => {
+                self.fraction = true;
+                self.point = true;
+                Ok(())
+            }
+            '0' if self.fraction => {
+                self.zeros += 1;
+                Ok(())
+            }
+            '1'..='9' if self.fraction => {
+                if self.point {
+                    self.out.write_char('.')?;
+                    self.point = false;
+                }
+                for _ in 0..self.zeros {
+                    self.out.write_char('0')?;
+                }
+                self.zeros = 0;
+                self.out.write_char(c)
+            }
+            _ => {
+                if !c.is_ascii_digit() {
+                    self.fraction = false;
+                    self.point = false;
+                    self.zeros = 0;
+                }
+                self.out.write_char(c)
+            }
+        }
+    }
+}
+
+/// Counts the characters written, to compute the padding.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Replaces non-ASCII characters with ASCII spellings.
+struct Ascii<'a, W> {
+    out: &'a mut W,
+    superscript: bool,
+}
+
+impl<W: Write> Write for Ascii<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        let superscript = match c {
+            '⁻' => Some('-'),
+            '⁰' => Some('0'),
+            '¹' => Some('1'),
+            '²' => Some('2'),
+            '³' => Some('3'),
+            '⁴'..='⁹' => char::from_digit(c as u32 - '⁰' as u32, 10),
+            _ => None,
+        };
+        if let Some(plain) = superscript {
+            // "m⁻²" becomes "m^-2"
+            if !self.superscript {
+                self.out.write_char('^')?;
+            }
+            self.superscript = true;
+            return self.out.write_char(plain);
+        }
+        self.superscript = false;
+        if c.is_ascii() {
+            return self.out.write_char(c);
+        }
+        self.out.write_str(match c {
+            'µ' | 'μ' => "u",
+            '°' => "deg",
+            '·' | '×' => "*",
+            'Ω' => "ohm",
+            'Å' => "angstrom",
+            '☉' => "sun",
+            '′' => "'",
+            '″' => "\"",
+            '−' => "-",
+            '\u{A0}' | '\u{2009}' | NARROW_NO_BREAK_SPACE => " ",
+            _ => "?",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Dimensionless, DimensionlessUnit};
+    use crate::electro::ElectricalResistance;
+    use crate::space::{Angle, Area, Length, LengthUnit, Volume};
+    use crate::thermal::Temperature;
+    use crate::time::Time;
+
+    #[test]
+    fn test_display_is_unchanged_by_default() {
+        assert_eq!(format!("{}", Length::meters(5.0)), "5 m");
+        assert_eq!(format!("{}", Length::meters(0.1)), "0.1 m");
+        assert_eq!(
+            format!("{}", Length::<f32>::new(0.1, LengthUnit::Meters)),
+            "0.1 m"
+        );
+        assert_eq!(format!("{}", Length::meters(5.0).display()), "5 m");
+        assert_eq!(format!("{}", Temperature::celsius(100.0)), "100°C");
+        assert_eq!(format!("{}", Dimensionless::percent(5.0)), "5 %");
+    }
+
+    #[test]
+    fn test_format_flags() {
+        let length = Length::meters(2.71625);
+        assert_eq!(format!("{:.2}", length), "2.72 m");
+        assert_eq!(format!("{:+.1}", length), "+2.7 m");
+        assert_eq!(format!("{:+}", -length), "-2.71625 m");
+        assert_eq!(format!("{:>10.1}", length), "     2.7 m");
+        assert_eq!(format!("{:<8.1}|", length), "2.7 m   |");
+        assert_eq!(format!("{:*^9.1}", length), "**2.7 m**");
+        assert_eq!(format!("{:4}", length), "2.71625 m");
+        assert_eq!(format!("{:.1}", Temperature::fahrenheit(72.25)), "72.2°F");
+        assert_eq!(format!("{:6.0}", Temperature::kelvin(300.4)), " 300 K");
+        assert_eq!(
+            format!("{:.1}", Dimensionless::new(12.34, DimensionlessUnit::Dozen)),
+            "12.3 dz"
+        );
+    }
+
+    #[test]
+    fn test_significant_figures() {
+        let sig = |value, figures| format!("{}", Length::meters(value).display().sig_figs(figures));
+        assert_eq!(sig(1234.5678, 3), "1230 m");
+        assert_eq!(sig(1.5, 3), "1.50 m");
+        assert_eq!(sig(0.000_123_45, 2), "0.00012 m");
+        assert_eq!(sig(-9.996, 3), "-10.0 m");
+        assert_eq!(sig(0.0, 2), "0.0 m");
+        assert_eq!(sig(42.0, 0), "40 m");
+        // A precision in the format string wins
+        assert_eq!(
+            format!("{:.1}", Length::meters(2.0).display().sig_figs(4)),
+            "2.0 m"
+        );
+        assert_eq!(
+            format!("{}", Length::meters(2.0).display().precision(2)),
+            "2.00 m"
+        );
+    }
+
+    #[test]
+    fn test_scientific_and_engineering() {
+        let length = Length::meters(12345.0);
+        assert_eq!(format!("{}", length.display().scientific()), "1.2345e4 m");
+        assert_eq!(format!("{:.2}", length.display().scientific()), "1.23e4 m");
+        assert_eq!(
+            format!("{}", length.display().scientific().sig_figs(2)),
+            "1.2e4 m"
+        );
+        assert_eq!(
+            format!("{:+}", length.display().scientific()),
+            "+1.2345e4 m"
+        );
+
+        let eng = |value: f64| format!("{}", Length::meters(value).display().engineering());
+        assert_eq!(eng(12345.0), "12.345e3 m");
+        assert_eq!(eng(0.000_47), "470e-6 m");
+        assert_eq!(eng(-1.5), "-1.5e0 m");
+        assert_eq!(eng(0.0), "0e0 m");
+        let length = Length::meters(12345.0).display().engineering();
+        assert_eq!(format!("{}", length.sig_figs(1)), "10e3 m");
+        assert_eq!(format!("{:.1}", length), "12.3e3 m");
+        // Rounding carries into the next group of three
+        assert_eq!(
+            format!("{:.1}", Length::meters(999.96).display().engineering()),
+            "1.0e3 m"
+        );
+    }
+
+    #[test]
+    fn test_spacing_and_ascii() {
+        let area = Area::square_meters(2.5);
+        assert_eq!(format!("{}", area.display().si_spacing()), "2.5\u{202F}m²");
+        assert_eq!(format!("{}", area.display().ascii()), "2.5 m^2");
+        assert_eq!(
+            format!("{}", area.display().si_spacing().ascii()),
+            "2.5 m^2"
+        );
+        assert_eq!(
+            format!("{}", Angle::degrees(30.0).display().si_spacing()),
+            "30°"
+        );
+        assert_eq!(
+            format!("{}", Angle::degrees(30.0).display().ascii()),
+            "30 deg"
+        );
+        assert_eq!(format!("{}", Temperature::celsius(20.0).display()), "20 °C");
+        assert_eq!(
+            format!("{}", Temperature::celsius(20.0).display().ascii()),
+            "20 degC"
+        );
+        assert_eq!(format!("{}", Length::meters(5.0).display().compact()), "5m");
+        assert_eq!(
+            format!("{}", Time::microseconds(3.0).display().ascii()),
+            "3 us"
+        );
+        assert_eq!(
+            format!("{}", ElectricalResistance::ohms(10.0).display().ascii()),
+            "10 ohm"
+        );
+        // Width counts characters, not bytes
+        assert_eq!(
+            format!("{:>7}", area.display().si_spacing()),
+            " 2.5\u{202F}m²"
+        );
+    }
+
+    #[test]
+    fn test_long_names() {
+        let name = |q: Length| format!("{}", q.display().long_names());
+        assert_eq!(name(Length::meters(1.0)), "1 meter");
+        assert_eq!(name(Length::meters(-1.0)), "-1 meter");
+        assert_eq!(name(Length::feet(2.0)), "2 feet");
+        assert_eq!(name(Length::inches(1.0)), "1 inch");
+        assert_eq!(
+            format!("{:.1}", Length::feet(1.0).display().long_names()),
+            "1.0 foot"
+        );
+        assert_eq!(
+            format!("{}", Volume::us_gallons(1.0).display().long_names()),
+            "1 US gallon"
+        );
+        assert_eq!(
+            format!("{}", Temperature::celsius(20.0).display().long_names()),
+            "20 degrees Celsius"
+        );
+        assert_eq!(
+            format!("{}", Dimensionless::dozen(3.0).display().long_names()),
+            "3 dozen"
+        );
+    }
+
+    #[test]
+    fn test_number_format() {
+        let length = Length::meters(1234567.891);
+        let de = length.display().number_format(NumberFormat::DE_DE);
+        assert_eq!(format!("{:.2}", de), "1.234.567,89 m");
+        assert_eq!(format!("{:+.0}", de), "+1.234.568 m");
+        assert_eq!(format!("{}", de.scientific().sig_figs(3)), "1,23e6 m");
+        assert_eq!(format!("{:>16.1}", de), "   1.234.567,9 m");
+        assert_eq!(
+            format!("{:.1}", length.display().number_format(NumberFormat::EN_IN)),
+            "12,34,567.9 m"
+        );
+        let fr = Length::meters(1500.0)
+            .display()
+            .number_format(NumberFormat::FR_FR);
+        assert_eq!(format!("{:.2}", fr.trim_zeros()), "1\u{202F}500 m");
+        assert_eq!(format!("{}", fr.precision(1).ascii()), "1 500,0 m");
+        assert_eq!(
+            format!(
+                "{}",
+                Length::meters(-0.25)
+                    .display()
+                    .number_format(NumberFormat::DE_DE)
+            ),
+            "-0,25 m"
+        );
+    }
+
+    #[test]
+    fn test_non_finite() {
+        assert_eq!(
+            format!("{}", Length::meters(f64::NAN).display().sig_figs(3)),
+            "NaN m"
+        );
+        assert_eq!(
+            format!("{}", Length::meters(f64::INFINITY).display().engineering()),
+            "inf m"
+        );
+    }
+
+    #[test]
+    fn test_f32_digits() {
+        let length = Length::<f32>::new(0.1, LengthUnit::Meters);
+        assert_eq!(format!("{}", length.display().scientific()), "1e-1 m");
+        assert_eq!(format!("{}", length.display().engineering()), "100e-3 m");
+    }
+}
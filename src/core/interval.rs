@@ -0,0 +1,643 @@
+//! Interval arithmetic on quantities.
+//!
+//! [`Interval`] holds a closed range `[lower, upper]` that is guaranteed to
+//! contain the true value. Arithmetic on intervals produces the tightest
+//! interval containing every possible result, which makes it suitable for
+//! worst-case tolerance stack-ups.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::prelude::*;
+//!
+//! let load = Interval::around(Force::newtons(1000.0), Force::newtons(50.0));
+//! let contact = Interval::new(Area::square_millimeters(95.0), Area::square_millimeters(105.0))
+//!     .unwrap();
+//!
+//! let stress: Interval<Pressure> = load / contact;
+//! assert!((stress.lower().to_megapascals() - 950.0 / 105.0).abs() < 1e-9);
+//! assert!((stress.upper().to_megapascals() - 1050.0 / 95.0).abs() < 1e-9);
+//! ```
+//!
+//! Each operand is treated as varying independently, so an expression that
+//! uses the same interval more than once (e.g. `x - x`) yields a wider
+//! result than the true range.
+//!
+//! Bounds are computed on values in the primary unit and rounded outward
+//! after every operation and unit conversion: a lower bound that is not
+//! exact moves down by an ulp and an upper bound up, so rounding never
+//! shrinks an interval past the exact result.
+
+#[cfg(feature = "alloc")]
+use super::error::QuantityError;
+use super::quantity::Quantity;
+use super::quantity_range::QuantityRange;
+use super::rational::{two_product, MAX_SPLIT};
+use super::unit::UnitOfMeasure;
+#[cfg(feature = "alloc")]
+use alloc::format;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A closed interval of quantities, `lower <= x <= upper`.
+///
+/// Both bounds are stored in the same unit. Bounds may be infinite, which
+/// happens when dividing by an interval that contains zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval<Q: Quantity> {
+    lower: Q,
+    upper: Q,
+}
+
+impl<Q: Quantity> Interval<Q> {
+    /// Creates an interval from its bounds.
+    ///
+    /// The upper bound is converted to the unit of the lower bound. Returns a
+    /// `RangeError` if `lower > upper` or either bound is NaN.
+    #[cfg(feature = "alloc")]
+    pub fn new(lower: Q, upper: Q) -> Result<Self, QuantityError> {
+        let (lo, hi) = (lower.to_primary(), upper.to_primary());
+        if lo.is_nan() || hi.is_nan() || lo > hi {
+            return Err(QuantityError::RangeError(format!(
+                "Interval lower bound {lower} must not exceed the upper bound {upper}"
+            )));
+        }
+        Ok(Self::from_primary_bounds((lo, lo), (hi, hi), lower.unit()))
+    }
+
+    /// Creates a degenerate interval containing a single value.
+    pub fn point(value: Q) -> Self {
+        Self {
+            lower: value,
+            upper: value,
+        }
+    }
+
+    /// Creates the interval `center ± tolerance`.
+    ///
+    /// The tolerance is a difference, so only the factor of its unit applies,
+    /// not an offset: ±0.5 °C is ±0.5 K.
+    pub fn around(center: Q, tolerance: Q) -> Self {
+        let unit = tolerance.unit();
+        let tolerance = if unit.is_linear() {
+            tolerance.to_primary().abs()
+        } else {
+            mul_bound(tolerance.value().abs(), unit.conversion_factor()).1
+        };
+        let primary = center.to_primary();
+        Self::from_primary_bounds(
+            sub_bound(primary, tolerance),
+            add_bound(primary, tolerance),
+            center.unit(),
+        )
+    }
+
+    /// Creates an interval from the lower and upper bounds of a lower and an
+    /// upper primary value, in `unit`.
+    fn from_primary_bounds((lo, _): (f64, f64), (_, hi): (f64, f64), unit: Q::Unit) -> Self {
+        Self {
+            lower: bound(lo, unit, false),
+            upper: bound(hi, unit, true),
+        }
+    }
+
+    /// Creates an interval from raw bounds in `unit`; callers ensure `lo <= hi`.
+    fn from_bounds(lo: f64, hi: f64, unit: Q::Unit) -> Self {
+        Self {
+            lower: Q::new(lo, unit),
+            upper: Q::new(hi, unit),
+        }
+    }
+
+    /// Returns the bounds as raw values in the interval's unit.
+    fn bounds(&self) -> (f64, f64) {
+        (self.lower.value(), self.upper.value())
+    }
+
+    /// Returns the bounds as values in the primary unit.
+    fn primary_bounds(&self) -> (f64, f64) {
+        (self.lower.to_primary(), self.upper.to_primary())
+    }
+
+    /// Returns the lower bound.
+    pub fn lower(&self) -> Q {
+        self.lower
+    }
+
+    /// Returns the upper bound.
+    pub fn upper(&self) -> Q {
+        self.upper
+    }
+
+    /// Returns the midpoint of the interval.
+    pub fn midpoint(&self) -> Q {
+        self.lower.map(|lo| lo + (self.upper.value() - lo) / 2.0)
+    }
+
+    /// Returns the width of the interval (upper - lower).
+    pub fn width(&self) -> Q {
+        self.upper.map(|hi| hi - self.lower.value())
+    }
+
+    /// Returns true if both bounds are finite.
+    pub fn is_bounded(&self) -> bool {
+        self.lower.value().is_finite() && self.upper.value().is_finite()
+    }
+
+    /// Returns true if the quantity lies within the interval, bounds included.
+    pub fn contains(&self, q: &Q) -> bool {
+        let (lo, hi) = self.primary_bounds();
+        let v = q.to_primary();
+        v >= lo && v <= hi
+    }
+
+    /// Returns true if zero lies within the interval.
+    pub fn contains_zero(&self) -> bool {
+        let (lo, hi) = self.bounds();
+        lo <= 0.0 && hi >= 0.0
+    }
+
+    /// Returns the overlap of two intervals, or `None` if they are disjoint.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let ((a_lo, a_hi), (b_lo, b_hi)) = (self.primary_bounds(), other.primary_bounds());
+        let (lo, hi) = (a_lo.max(b_lo), a_hi.min(b_hi));
+        (lo <= hi).then(|| Self::from_primary_bounds((lo, lo), (hi, hi), self.lower.unit()))
+    }
+
+    /// Returns the smallest interval containing both intervals.
+    pub fn hull(&self, other: &Self) -> Self {
+        let ((a_lo, a_hi), (b_lo, b_hi)) = (self.primary_bounds(), other.primary_bounds());
+        let (lo, hi) = (a_lo.min(b_lo), a_hi.max(b_hi));
+        Self::from_primary_bounds((lo, lo), (hi, hi), self.lower.unit())
+    }
+
+    /// Returns the interval of absolute values.
+    pub fn abs(&self) -> Self {
+        let (lo, hi) = self.bounds();
+        let unit = self.lower.unit();
+        if lo >= 0.0 {
+            *self
+        } else if hi <= 0.0 {
+            Self::from_bounds(-hi, -lo, unit)
+        } else {
+            Self::from_bounds(0.0, hi.max(-lo), unit)
+        }
+    }
+
+    /// Converts both bounds to the given unit.
+    pub fn in_unit(&self, unit: Q::Unit) -> Self {
+        let (lo, hi) = self.primary_bounds();
+        Self::from_primary_bounds((lo, lo), (hi, hi), unit)
+    }
+}
+
+/// Returns the quantity in `unit` closest to `primary`, a value in the
+/// primary unit, that is not above it (`up` false) or not below it (`up`
+/// true).
+fn bound<Q: Quantity>(primary: f64, unit: Q::Unit, up: bool) -> Q {
+    let mut bound = Q::new(unit.convert_from_primary(primary), unit);
+    // Converting to `unit` and back can round either way; step outward
+    // until the bound holds.
+    while (up && bound.to_primary() < primary) || (!up && bound.to_primary() > primary) {
+        let value = bound.value();
+        bound = Q::new(
+            if up {
+                value.next_up()
+            } else {
+                value.next_down()
+            },
+            unit,
+        );
+    }
+    bound
+}
+
+/// Rounds an exact result outward, given `value`, the result rounded to
+/// nearest, and `error`, the exact result minus `value` (NaN if unknown).
+/// Returns the result rounded down and up.
+fn outward(value: f64, error: f64) -> (f64, f64) {
+    if error == 0.0 {
+        (value, value)
+    } else if error > 0.0 {
+        (value, value.next_up())
+    } else if error < 0.0 {
+        (value.next_down(), value)
+    } else {
+        (value.next_down(), value.next_up())
+    }
+}
+
+/// `a + b` rounded down and up.
+fn add_bound(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    if !a.is_finite() || !b.is_finite() {
+        return (sum, sum);
+    }
+    if !sum.is_finite() {
+        return outward(sum, f64::NAN);
+    }
+    // Knuth's two-sum gives the rounding error exactly
+    let b_part = sum - a;
+    let error = (a - (sum - b_part)) + (b - b_part);
+    outward(sum, error)
+}
+
+/// `a - b` rounded down and up.
+fn sub_bound(a: f64, b: f64) -> (f64, f64) {
+    add_bound(a, -b)
+}
+
+/// Smallest product magnitude whose rounding error Dekker's algorithm gives
+/// exactly; below it the partial products may underflow.
+const MIN_EXACT_PRODUCT: f64 = f64::MIN_POSITIVE * 9_007_199_254_740_992.0;
+
+/// `a · b` rounded down and up, taking `0 · ∞` as `0` so that a zero factor
+/// keeps an unbounded interval from producing NaN.
+fn mul_bound(a: f64, b: f64) -> (f64, f64) {
+    if a == 0.0 || b == 0.0 {
+        return (0.0, 0.0);
+    }
+    let product = a * b;
+    if !a.is_finite() || !b.is_finite() {
+        return (product, product);
+    }
+    let exact_error = a.abs() < MAX_SPLIT
+        && b.abs() < MAX_SPLIT
+        && product.is_finite()
+        && product.abs() >= MIN_EXACT_PRODUCT;
+    let error = if exact_error {
+        two_product(a, b).1
+    } else {
+        f64::NAN
+    };
+    outward(product, error)
+}
+
+/// `1 / b` rounded down and up, for a non-zero `b`.
+fn recip_bound(b: f64) -> (f64, f64) {
+    let quotient = 1.0 / b;
+    if !b.is_finite() {
+        return (quotient, quotient);
+    }
+    let error = if b.abs() < MAX_SPLIT && quotient.abs() >= MIN_EXACT_PRODUCT {
+        // `1 - quotient · b` is exact, and has the sign of the error times `b`
+        let (product, product_error) = two_product(quotient, b);
+        ((1.0 - product) - product_error) / b
+    } else {
+        f64::NAN
+    };
+    outward(quotient, error)
+}
+
+/// Product of two raw intervals, rounded outward.
+fn mul_bounds((a_lo, a_hi): (f64, f64), (b_lo, b_hi): (f64, f64)) -> (f64, f64) {
+    let products = [
+        mul_bound(a_lo, b_lo),
+        mul_bound(a_lo, b_hi),
+        mul_bound(a_hi, b_lo),
+        mul_bound(a_hi, b_hi),
+    ];
+    let lo = products.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+    let hi = products
+        .iter()
+        .map(|p| p.1)
+        .fold(f64::NEG_INFINITY, f64::max);
+    (lo, hi)
+}
+
+/// Quotient of two raw intervals, rounded outward.
+///
+/// A divisor with zero as one bound yields a half-unbounded reciprocal; a
+/// divisor with zero in its interior (or equal to zero) yields an unbounded
+/// one, so the result is the whole line unless the dividend is exactly zero.
+fn div_bounds(a: (f64, f64), (b_lo, b_hi): (f64, f64)) -> (f64, f64) {
+    let reciprocal = if b_lo > 0.0 || b_hi < 0.0 {
+        (recip_bound(b_hi).0, recip_bound(b_lo).1)
+    } else if b_lo == 0.0 && b_hi > 0.0 {
+        (recip_bound(b_hi).0, f64::INFINITY)
+    } else if b_hi == 0.0 && b_lo < 0.0 {
+        (f64::NEG_INFINITY, recip_bound(b_lo).1)
+    } else {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    };
+    mul_bounds(a, reciprocal)
+}
+
+/// Bounds of the factor that turns a product or quotient of primary values
+/// into a value in the primary unit of the result. The factor is computed in
+/// floating point, so unless it is exactly one it is widened by an ulp.
+fn scale_bounds<Q: Quantity>(scale: Q) -> (f64, f64) {
+    let scale = scale.to_primary();
+    if scale == 1.0 {
+        (scale, scale)
+    } else {
+        (scale.next_down(), scale.next_up())
+    }
+}
+
+/// Returns a quantity of one primary unit, in that unit if it is known.
+fn one<Q: Quantity>(unit: Q::Unit) -> Q
+where
+    Q::Unit: 'static,
+{
+    match Q::Unit::all_units().iter().find(|unit| unit.is_primary()) {
+        Some(primary_unit) => Q::new(1.0, *primary_unit),
+        None => Q::new(unit.convert_from_primary(1.0), unit),
+    }
+}
+
+impl<Q: Quantity> From<QuantityRange<Q>> for Interval<Q> {
+    fn from(range: QuantityRange<Q>) -> Self {
+        let (lo, hi) = (range.lower().to_primary(), range.upper().to_primary());
+        Self::from_primary_bounds((lo, lo), (hi, hi), range.lower().unit())
+    }
+}
+
+impl<Q: Quantity> fmt::Display for Interval<Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (lo, hi) = self.bounds();
+        let symbol = self.lower.unit().symbol();
+        match f.precision() {
+            Some(precision) => write!(f, "[{lo:.precision$}, {hi:.precision$}] {symbol}"),
+            None => write!(f, "[{lo}, {hi}] {symbol}"),
+        }
+    }
+}
+
+impl<Q: Quantity> Add for Interval<Q> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let ((a_lo, a_hi), (b_lo, b_hi)) = (self.primary_bounds(), rhs.primary_bounds());
+        Self::from_primary_bounds(
+            add_bound(a_lo, b_lo),
+            add_bound(a_hi, b_hi),
+            self.lower.unit(),
+        )
+    }
+}
+
+impl<Q: Quantity> Sub for Interval<Q> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let ((a_lo, a_hi), (b_lo, b_hi)) = (self.primary_bounds(), rhs.primary_bounds());
+        Self::from_primary_bounds(
+            sub_bound(a_lo, b_hi),
+            sub_bound(a_hi, b_lo),
+            self.lower.unit(),
+        )
+    }
+}
+
+impl<Q: Quantity> Neg for Interval<Q> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            lower: self.upper.negate(),
+            upper: self.lower.negate(),
+        }
+    }
+}
+
+impl<Q: Quantity> Mul<f64> for Interval<Q> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        let (lo, hi) = mul_bounds(self.primary_bounds(), (rhs, rhs));
+        Self::from_primary_bounds((lo, lo), (hi, hi), self.lower.unit())
+    }
+}
+
+impl<Q: Quantity> Mul<Interval<Q>> for f64 {
+    type Output = Interval<Q>;
+
+    fn mul(self, rhs: Interval<Q>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<Q: Quantity> Div<f64> for Interval<Q> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        let (lo, hi) = div_bounds(self.primary_bounds(), (rhs, rhs));
+        Self::from_primary_bounds((lo, lo), (hi, hi), self.lower.unit())
+    }
+}
+
+// Cross-quantity operators apply interval arithmetic to the bounds in
+// primary units, then use the scalar operator on one primary unit of each
+// operand to find the output unit and the scale of its primary unit.
+
+impl<A, B, C> Mul<Interval<B>> for Interval<A>
+where
+    A: Quantity + Mul<B, Output = C>,
+    B: Quantity,
+    C: Quantity,
+    A::Unit: 'static,
+    B::Unit: 'static,
+{
+    type Output = Interval<C>;
+
+    fn mul(self, rhs: Interval<B>) -> Self::Output {
+        let scale = one::<A>(self.lower.unit()) * one::<B>(rhs.lower.unit());
+        let bounds = mul_bounds(self.primary_bounds(), rhs.primary_bounds());
+        let (lo, hi) = mul_bounds(bounds, scale_bounds(scale));
+        Interval::from_primary_bounds((lo, lo), (hi, hi), scale.unit())
+    }
+}
+
+impl<A, B, C> Div<Interval<B>> for Interval<A>
+where
+    A: Quantity + Div<B, Output = C>,
+    B: Quantity,
+    C: Quantity,
+    A::Unit: 'static,
+    B::Unit: 'static,
+{
+    type Output = Interval<C>;
+
+    fn div(self, rhs: Interval<B>) -> Self::Output {
+        let scale = one::<A>(self.lower.unit()) / one::<B>(rhs.lower.unit());
+        let bounds = div_bounds(self.primary_bounds(), rhs.primary_bounds());
+        let (lo, hi) = mul_bounds(bounds, scale_bounds(scale));
+        Interval::from_primary_bounds((lo, lo), (hi, hi), scale.unit())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::motion::{Force, Pressure};
+    use crate::space::{Area, Length, LengthUnit};
+    use crate::time::Time;
+
+    fn close(left: f64, right: f64) -> bool {
+        (left - right).abs() < 1e-9
+    }
+
+    fn meters(lo: f64, hi: f64) -> Interval<Length> {
+        Interval::new(Length::meters(lo), Length::meters(hi)).unwrap()
+    }
+
+    #[test]
+    fn test_construction() {
+        let i = Interval::new(Length::meters(1.0), Length::centimeters(250.0)).unwrap();
+        assert_eq!(i.upper().unit(), LengthUnit::Meters);
+        assert!(close(i.upper().to_meters(), 2.5));
+        assert!(close(i.width().to_meters(), 1.5));
+        assert!(close(i.midpoint().to_meters(), 1.75));
+
+        assert!(Interval::new(Length::meters(2.0), Length::meters(1.0)).is_err());
+        assert!(Interval::new(Length::meters(f64::NAN), Length::meters(1.0)).is_err());
+
+        let t = Interval::around(Length::millimeters(10.0), Length::millimeters(-0.1));
+        assert!(close(t.lower().to_millimeters(), 9.9));
+        assert!(close(t.upper().to_millimeters(), 10.1));
+        assert!(Interval::point(Length::meters(3.0)).width().value() == 0.0);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = meters(0.0, 2.0);
+        let b = meters(1.0, 3.0);
+        assert!(a.contains(&Length::centimeters(200.0)));
+        assert!(!a.contains(&Length::meters(2.5)));
+        assert_eq!(a.intersection(&b), Some(meters(1.0, 2.0)));
+        assert_eq!(a.intersection(&meters(5.0, 6.0)), None);
+        assert_eq!(a.hull(&meters(5.0, 6.0)), meters(0.0, 6.0));
+        assert_eq!(meters(-3.0, 1.0).abs(), meters(0.0, 3.0));
+        assert_eq!(meters(-3.0, -1.0).abs(), meters(1.0, 3.0));
+    }
+
+    #[test]
+    fn test_addition_and_subtraction() {
+        let a = meters(1.0, 2.0);
+        let b = Interval::new(Length::centimeters(10.0), Length::centimeters(30.0)).unwrap();
+        let sum = a + b;
+        assert!(close(sum.lower().to_meters(), 1.1));
+        assert!(close(sum.upper().to_meters(), 2.3));
+        let difference = a - b;
+        assert!(close(difference.lower().to_meters(), 0.7));
+        assert!(close(difference.upper().to_meters(), 1.9));
+        assert_eq!(-a, meters(-2.0, -1.0));
+        // The dependency problem: x - x is not zero
+        assert_eq!(a - a, meters(-1.0, 1.0));
+    }
+
+    #[test]
+    fn test_multiplication_with_sign_changes() {
+        assert_eq!(meters(-1.0, 2.0) * -3.0, meters(-6.0, 3.0));
+        assert_eq!(2.0 * meters(-1.0, 2.0), meters(-2.0, 4.0));
+
+        let area: Interval<Area> = meters(-2.0, 3.0) * meters(-4.0, 1.0);
+        assert!(close(area.lower().to_square_meters(), -12.0));
+        assert!(close(area.upper().to_square_meters(), 8.0));
+
+        let area: Interval<Area> = meters(-2.0, -1.0) * meters(3.0, 4.0);
+        assert!(close(area.lower().to_square_meters(), -8.0));
+        assert!(close(area.upper().to_square_meters(), -3.0));
+    }
+
+    #[test]
+    fn test_cross_quantity_units() {
+        let a = Interval::new(Length::centimeters(10.0), Length::centimeters(20.0)).unwrap();
+        let area: Interval<Area> = a * meters(1.0, 2.0);
+        assert!(close(area.lower().to_square_meters(), 0.1));
+        assert!(close(area.upper().to_square_meters(), 0.4));
+
+        let force = Interval::around(Force::newtons(100.0), Force::newtons(10.0));
+        let area = Interval::new(Area::square_meters(2.0), Area::square_meters(4.0)).unwrap();
+        let pressure: Interval<Pressure> = force / area;
+        assert!(close(pressure.lower().to_pascals(), 22.5));
+        assert!(close(pressure.upper().to_pascals(), 55.0));
+    }
+
+    #[test]
+    fn test_outward_rounding() {
+        // 0.1 + 0.2 rounds up to 0.30000000000000004; the exact sum lies
+        // between that and the next float down
+        let sum = Interval::point(Length::meters(0.1)) + Interval::point(Length::meters(0.2));
+        assert_eq!(sum.lower().to_meters(), 0.3);
+        assert_eq!(sum.upper().to_meters(), 0.1 + 0.2);
+
+        // Exact results stay exact
+        assert_eq!(meters(1.0, 2.0) * 0.5, meters(0.5, 1.0));
+        assert_eq!(meters(1.0, 3.0) / 4.0, meters(0.25, 0.75));
+
+        let third = meters(1.0, 1.0) / 3.0;
+        assert!(third.lower().to_meters() < third.upper().to_meters());
+        assert_eq!(
+            third.upper().to_meters().next_down(),
+            third.lower().to_meters()
+        );
+
+        // Converted bounds still enclose the original values
+        let original = meters(0.1, 0.7);
+        let inches = original.in_unit(LengthUnit::Inches);
+        assert_eq!(inches.lower().unit(), LengthUnit::Inches);
+        assert!(inches.lower().to_primary() <= 0.1);
+        assert!(inches.upper().to_primary() >= 0.7);
+    }
+
+    #[test]
+    fn test_cross_quantity_nonlinear_units() {
+        let gauge = Interval::new(Pressure::psig(29.0), Pressure::psig(31.0)).unwrap();
+        let force: Interval<Force> = gauge * Interval::point(Area::square_meters(1.0));
+        assert!(force.lower().to_newtons() <= Pressure::psig(29.0).to_pascals());
+        assert!(force.upper().to_newtons() >= Pressure::psig(31.0).to_pascals());
+        assert!(close(
+            force.lower().to_newtons(),
+            Pressure::psig(29.0).to_pascals()
+        ));
+
+        let around = Interval::around(Pressure::psig(30.0), Pressure::psig(1.0));
+        assert!(close(
+            around.upper().to_pascals(),
+            Pressure::psig(31.0).to_pascals()
+        ));
+    }
+
+    #[test]
+    fn test_division_by_interval_containing_zero() {
+        let t = |lo, hi| Interval::new(Time::seconds(lo), Time::seconds(hi)).unwrap();
+
+        // Zero as the lower bound: unbounded above
+        let v = meters(1.0, 2.0) / t(0.0, 4.0);
+        assert!(close(v.lower().to_meters_per_second(), 0.25));
+        assert_eq!(v.upper().value(), f64::INFINITY);
+        assert!(!v.is_bounded());
+
+        // Zero as the upper bound: unbounded below
+        let v = meters(1.0, 2.0) / t(-4.0, 0.0);
+        assert_eq!(v.lower().value(), f64::NEG_INFINITY);
+        assert!(close(v.upper().to_meters_per_second(), -0.25));
+
+        // Zero in the interior: the whole line
+        let v = meters(1.0, 2.0) / t(-1.0, 1.0);
+        assert_eq!(v.lower().value(), f64::NEG_INFINITY);
+        assert_eq!(v.upper().value(), f64::INFINITY);
+
+        // A zero dividend stays zero
+        let v = meters(0.0, 0.0) / t(-1.0, 1.0);
+        assert_eq!((v.lower().value(), v.upper().value()), (0.0, 0.0));
+
+        let scaled = meters(1.0, 2.0) / 0.0;
+        assert!(!scaled.is_bounded());
+        assert_eq!(meters(1.0, 2.0) / -2.0, meters(-1.0, -0.5));
+    }
+
+    #[test]
+    fn test_from_range_and_display() {
+        let range = QuantityRange::new(Length::meters(1.0), Length::meters(2.0)).unwrap();
+        let i: Interval<Length> = range.into();
+        assert_eq!(i, meters(1.0, 2.0));
+        assert_eq!(i.to_string(), "[1, 2] m");
+        assert_eq!(
+            format!("{:.1}", i.in_unit(LengthUnit::Centimeters)),
+            "[100.0, 200.0] cm"
+        );
+    }
+}
@@ -0,0 +1,511 @@
+//! Logarithmic levels and gains in decibels and nepers.
+//!
+//! A [`Level`] expresses a quantity as a logarithm of its ratio to a
+//! [`LevelReference`], such as dBm (relative to 1 mW) or dB SPL (relative to
+//! 20 µPa). A [`Gain`] is a level difference: the logarithm of a plain
+//! ratio. The usual level arithmetic applies:
+//!
+//! - level ± gain = level
+//! - level − level = gain
+//! - gain ± gain = gain
+//! - incoherent sources combine with [`Level::add_incoherent`], which adds
+//!   their powers rather than their levels
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::prelude::*;
+//!
+//! let transmitter = Level::from_quantity(Power::watts(2.0), LevelReference::MILLIWATT);
+//! assert!((transmitter.to_decibels() - 33.0103).abs() < 1e-4);
+//!
+//! let received = transmitter + Gain::decibels(12.0) - Gain::decibels(110.0);
+//! assert!((received.to_decibels() + 64.9897).abs() < 1e-4);
+//! assert!((received.to_quantity().to_watts() - 3.17e-10).abs() < 1e-12);
+//!
+//! // Two uncorrelated 60 dB SPL sources give 63 dB SPL, not 120
+//! let source = Level::db_spl(60.0);
+//! assert!((source.add_incoherent(source).to_decibels() - 63.0103).abs() < 1e-4);
+//! ```
+
+use super::dimensionless::{Dimensionless, DimensionlessUnit};
+use super::quantity::Quantity;
+use super::unit::UnitOfMeasure;
+use crate::electro::{ElectricPotential, ElectricPotentialUnit};
+use crate::energy::{Power, PowerUnit};
+use crate::math;
+use crate::motion::{Pressure, PressureUnit};
+use core::cmp::Ordering;
+use core::f64::consts::LN_10;
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Decibels per neper: `20 / ln(10)`.
+const DECIBELS_PER_NEPER: f64 = 20.0 / LN_10;
+
+/// Quantities that can be expressed as a level in decibels.
+pub trait LevelQuantity: Quantity {
+    /// Decibels per decade of the quantity: 10 for power quantities and 20
+    /// for root-power quantities such as voltage and sound pressure, whose
+    /// square is proportional to power.
+    const DECIBELS_PER_DECADE: f64;
+}
+
+impl LevelQuantity for Power {
+    const DECIBELS_PER_DECADE: f64 = 10.0;
+}
+
+impl LevelQuantity for ElectricPotential {
+    const DECIBELS_PER_DECADE: f64 = 20.0;
+}
+
+impl LevelQuantity for Pressure {
+    const DECIBELS_PER_DECADE: f64 = 20.0;
+}
+
+/// The reference quantity of a [`Level`] and the symbol of its unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelReference<Q: LevelQuantity> {
+    quantity: Q,
+    symbol: &'static str,
+}
+
+impl<Q: LevelQuantity> LevelReference<Q> {
+    /// Creates a reference; `symbol` is displayed after the decibel value
+    /// (e.g., "dBm").
+    pub const fn new(quantity: Q, symbol: &'static str) -> Self {
+        Self { quantity, symbol }
+    }
+
+    /// Returns the reference quantity, which is 0 dB.
+    pub fn quantity(&self) -> Q {
+        self.quantity
+    }
+
+    /// Returns the symbol of levels relative to this reference.
+    pub fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+}
+
+impl LevelReference<Power> {
+    /// 1 mW (dBm).
+    pub const MILLIWATT: Self = Self::new(Power::new_const(1.0, PowerUnit::Milliwatts), "dBm");
+    /// 1 W (dBW).
+    pub const WATT: Self = Self::new(Power::new_const(1.0, PowerUnit::Watts), "dBW");
+}
+
+impl LevelReference<ElectricPotential> {
+    /// 1 V (dBV).
+    pub const VOLT: Self = Self::new(
+        ElectricPotential::new_const(1.0, ElectricPotentialUnit::Volts),
+        "dBV",
+    );
+    /// 1 µV (dBµV).
+    pub const MICROVOLT: Self = Self::new(
+        ElectricPotential::new_const(1.0, ElectricPotentialUnit::Microvolts),
+        "dBµV",
+    );
+}
+
+impl LevelReference<Pressure> {
+    /// 20 µPa, the threshold of human hearing in air (dB SPL).
+    pub const SOUND_PRESSURE: Self =
+        Self::new(Pressure::new_const(20e-6, PressureUnit::Pascals), "dB SPL");
+}
+
+/// A quantity expressed in decibels relative to a reference.
+#[derive(Debug, Clone, Copy)]
+pub struct Level<Q: LevelQuantity> {
+    decibels: f64,
+    reference: LevelReference<Q>,
+}
+
+impl<Q: LevelQuantity> Level<Q> {
+    /// Creates a level of `decibels` relative to `reference`.
+    pub fn new(decibels: f64, reference: LevelReference<Q>) -> Self {
+        Self {
+            decibels,
+            reference,
+        }
+    }
+
+    /// Creates a level of `nepers` relative to `reference`.
+    pub fn from_nepers(nepers: f64, reference: LevelReference<Q>) -> Self {
+        Self::new(nepers * DECIBELS_PER_NEPER, reference)
+    }
+
+    /// Expresses `quantity` as a level relative to `reference`.
+    ///
+    /// Zero gives negative infinity and negative quantities give NaN.
+    pub fn from_quantity(quantity: Q, reference: LevelReference<Q>) -> Self {
+        let ratio = quantity.to_primary() / reference.quantity.to_primary();
+        Self::new(Q::DECIBELS_PER_DECADE * math::log10(ratio), reference)
+    }
+
+    /// Returns the level in decibels.
+    pub fn to_decibels(&self) -> f64 {
+        self.decibels
+    }
+
+    /// Returns the level in nepers.
+    pub fn to_nepers(&self) -> f64 {
+        self.decibels / DECIBELS_PER_NEPER
+    }
+
+    /// Returns the reference of this level.
+    pub fn reference(&self) -> LevelReference<Q> {
+        self.reference
+    }
+
+    /// Converts this level back to a quantity, in the unit of the reference.
+    pub fn to_quantity(&self) -> Q {
+        let ratio = math::powf(10.0, self.decibels / Q::DECIBELS_PER_DECADE);
+        let reference = self.reference.quantity;
+        let unit = reference.unit();
+        Q::new(
+            unit.convert_from_primary(reference.to_primary() * ratio),
+            unit,
+        )
+    }
+
+    /// Expresses the same quantity relative to another reference
+    /// (e.g., dBm to dBW).
+    pub fn in_reference(&self, reference: LevelReference<Q>) -> Self {
+        let shift = Level::from_quantity(self.reference.quantity, reference).decibels;
+        Self::new(self.decibels + shift, reference)
+    }
+
+    /// Combines the levels of two incoherent (uncorrelated) sources by
+    /// adding their powers. The result keeps this level's reference.
+    pub fn add_incoherent(self, other: Self) -> Self {
+        let other = other.in_reference(self.reference);
+        let power = |decibels: f64| math::powf(10.0, decibels / 10.0);
+        let total = power(self.decibels) + power(other.decibels);
+        Self::new(10.0 * math::log10(total), self.reference)
+    }
+
+    /// Combines the levels of any number of incoherent sources, returning
+    /// `None` if there are none.
+    pub fn sum_incoherent<I: IntoIterator<Item = Self>>(levels: I) -> Option<Self> {
+        levels.into_iter().reduce(Self::add_incoherent)
+    }
+}
+
+impl Level<Power> {
+    /// Creates a power level in dBm.
+    pub fn dbm(decibels: f64) -> Self {
+        Self::new(decibels, LevelReference::MILLIWATT)
+    }
+
+    /// Creates a power level in dBW.
+    pub fn dbw(decibels: f64) -> Self {
+        Self::new(decibels, LevelReference::WATT)
+    }
+}
+
+impl Level<ElectricPotential> {
+    /// Creates a voltage level in dBV.
+    pub fn dbv(decibels: f64) -> Self {
+        Self::new(decibels, LevelReference::VOLT)
+    }
+
+    /// Creates a voltage level in dBµV.
+    pub fn dbuv(decibels: f64) -> Self {
+        Self::new(decibels, LevelReference::MICROVOLT)
+    }
+}
+
+impl Level<Pressure> {
+    /// Creates a sound pressure level in dB SPL.
+    pub fn db_spl(decibels: f64) -> Self {
+        Self::new(decibels, LevelReference::SOUND_PRESSURE)
+    }
+}
+
+/// Levels are equal if they describe the same quantity, whatever their
+/// references.
+impl<Q: LevelQuantity> PartialEq for Level<Q> {
+    fn eq(&self, other: &Self) -> bool {
+        self.decibels == other.in_reference(self.reference).decibels
+    }
+}
+
+impl<Q: LevelQuantity> PartialOrd for Level<Q> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.decibels
+            .partial_cmp(&other.in_reference(self.reference).decibels)
+    }
+}
+
+impl<Q: LevelQuantity> fmt::Display for Level<Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.decibels, f)?;
+        write!(f, " {}", self.reference.symbol)
+    }
+}
+
+impl<Q: LevelQuantity> Add<Gain> for Level<Q> {
+    type Output = Level<Q>;
+
+    fn add(self, rhs: Gain) -> Self::Output {
+        Self::new(self.decibels + rhs.decibels, self.reference)
+    }
+}
+
+impl<Q: LevelQuantity> Add<Level<Q>> for Gain {
+    type Output = Level<Q>;
+
+    fn add(self, rhs: Level<Q>) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl<Q: LevelQuantity> Sub<Gain> for Level<Q> {
+    type Output = Level<Q>;
+
+    fn sub(self, rhs: Gain) -> Self::Output {
+        Self::new(self.decibels - rhs.decibels, self.reference)
+    }
+}
+
+impl<Q: LevelQuantity> AddAssign<Gain> for Level<Q> {
+    fn add_assign(&mut self, rhs: Gain) {
+        *self = *self + rhs;
+    }
+}
+
+impl<Q: LevelQuantity> SubAssign<Gain> for Level<Q> {
+    fn sub_assign(&mut self, rhs: Gain) {
+        *self = *self - rhs;
+    }
+}
+
+/// The difference between two levels is a gain.
+impl<Q: LevelQuantity> Sub for Level<Q> {
+    type Output = Gain;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Gain::decibels(self.decibels - rhs.in_reference(self.reference).decibels)
+    }
+}
+
+/// A ratio expressed in decibels, such as the gain of an amplifier or the
+/// loss of a cable.
+///
+/// Gains add where the ratios they describe multiply. A power ratio `r` is
+/// `10·log₁₀(r)` dB and an amplitude ratio is `20·log₁₀(r)` dB; one neper is
+/// an amplitude ratio of `e`, about 8.686 dB.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::prelude::*;
+///
+/// let gain = Gain::from_power_ratio(Dimensionless::each(2.0));
+/// assert!((gain.to_decibels() - 3.0103).abs() < 1e-4);
+/// assert!(((gain + gain).to_power_ratio().to_each() - 4.0).abs() < 1e-12);
+/// assert!((Gain::nepers(1.0).to_decibels() - 8.6859).abs() < 1e-4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Gain {
+    decibels: f64,
+}
+
+impl Gain {
+    /// Creates a gain in decibels.
+    pub fn decibels(decibels: f64) -> Self {
+        Self { decibels }
+    }
+
+    /// Creates a gain in nepers.
+    pub fn nepers(nepers: f64) -> Self {
+        Self::decibels(nepers * DECIBELS_PER_NEPER)
+    }
+
+    /// Creates the gain of a power ratio.
+    pub fn from_power_ratio(ratio: Dimensionless) -> Self {
+        Self::decibels(10.0 * math::log10(ratio.to_each()))
+    }
+
+    /// Creates the gain of an amplitude (root-power) ratio.
+    pub fn from_amplitude_ratio(ratio: Dimensionless) -> Self {
+        Self::decibels(20.0 * math::log10(ratio.to_each()))
+    }
+
+    /// Returns the gain in decibels.
+    pub fn to_decibels(&self) -> f64 {
+        self.decibels
+    }
+
+    /// Returns the gain in nepers.
+    pub fn to_nepers(&self) -> f64 {
+        self.decibels / DECIBELS_PER_NEPER
+    }
+
+    /// Returns the power ratio of this gain.
+    pub fn to_power_ratio(&self) -> Dimensionless {
+        Dimensionless::new(
+            math::powf(10.0, self.decibels / 10.0),
+            DimensionlessUnit::Each,
+        )
+    }
+
+    /// Returns the amplitude ratio of this gain.
+    pub fn to_amplitude_ratio(&self) -> Dimensionless {
+        Dimensionless::new(
+            math::powf(10.0, self.decibels / 20.0),
+            DimensionlessUnit::Each,
+        )
+    }
+}
+
+impl fmt::Display for Gain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.decibels, f)?;
+        f.write_str(" dB")
+    }
+}
+
+impl Add for Gain {
+    type Output = Gain;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Gain::decibels(self.decibels + rhs.decibels)
+    }
+}
+
+impl Sub for Gain {
+    type Output = Gain;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Gain::decibels(self.decibels - rhs.decibels)
+    }
+}
+
+impl AddAssign for Gain {
+    fn add_assign(&mut self, rhs: Self) {
+        self.decibels += rhs.decibels;
+    }
+}
+
+impl SubAssign for Gain {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.decibels -= rhs.decibels;
+    }
+}
+
+impl Mul<f64> for Gain {
+    type Output = Gain;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Gain::decibels(self.decibels * rhs)
+    }
+}
+
+impl Neg for Gain {
+    type Output = Gain;
+
+    fn neg(self) -> Self::Output {
+        Gain::decibels(-self.decibels)
+    }
+}
+
+impl core::iter::Sum for Gain {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Gain::decibels(0.0), Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(left: f64, right: f64) -> bool {
+        (left - right).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_power_levels() {
+        let level = Level::from_quantity(Power::watts(1.0), LevelReference::MILLIWATT);
+        assert!(close(level.to_decibels(), 30.0));
+        assert!(close(
+            level.in_reference(LevelReference::WATT).to_decibels(),
+            0.0
+        ));
+        assert_eq!(level, Level::dbw(0.0));
+        assert!(close(Level::dbm(-30.0).to_quantity().to_milliwatts(), 1e-3));
+        assert_eq!(
+            Level::dbm(-30.0).to_quantity().unit(),
+            PowerUnit::Milliwatts
+        );
+    }
+
+    #[test]
+    fn test_field_levels() {
+        // Root-power quantities use 20 dB per decade
+        let level =
+            Level::from_quantity(ElectricPotential::millivolts(100.0), LevelReference::VOLT);
+        assert!(close(level.to_decibels(), -20.0));
+        assert!(close(
+            level.in_reference(LevelReference::MICROVOLT).to_decibels(),
+            100.0
+        ));
+        assert!(close(Level::dbuv(60.0).to_quantity().to_millivolts(), 1.0));
+
+        let spl = Level::from_quantity(Pressure::pascals(1.0), LevelReference::SOUND_PRESSURE);
+        assert!((spl.to_decibels() - 93.9794).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_level_arithmetic() {
+        let mut level = Level::dbm(10.0) + Gain::decibels(20.0);
+        assert!(close(level.to_decibels(), 30.0));
+        level -= Gain::decibels(3.0);
+        assert!(close(level.to_decibels(), 27.0));
+
+        let difference = Level::dbm(30.0) - Level::dbw(-10.0);
+        assert!(close(difference.to_decibels(), 10.0));
+        assert!(Level::dbm(31.0) > Level::dbw(0.0));
+    }
+
+    #[test]
+    fn test_incoherent_sum() {
+        let total = Level::sum_incoherent([Level::db_spl(80.0); 10]).unwrap();
+        assert!(close(total.to_decibels(), 90.0));
+        assert!(Level::<Pressure>::sum_incoherent([]).is_none());
+
+        // Mixed references are combined as powers
+        let total = Level::dbm(30.0).add_incoherent(Level::dbw(0.0));
+        assert!((total.to_decibels() - 33.0103).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_gains() {
+        let gain = Gain::from_amplitude_ratio(Dimensionless::each(10.0));
+        assert!(close(gain.to_decibels(), 20.0));
+        assert!(close(gain.to_power_ratio().to_each(), 100.0));
+        assert!(close(
+            Gain::nepers(1.0).to_amplitude_ratio().to_each(),
+            core::f64::consts::E
+        ));
+        assert!(close(Gain::decibels(20.0).to_nepers(), LN_10));
+
+        let chain: Gain = [
+            Gain::decibels(20.0),
+            -Gain::decibels(3.0),
+            Gain::decibels(1.5) * 2.0,
+        ]
+        .into_iter()
+        .sum();
+        assert!(close(chain.to_decibels(), 20.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Level::dbm(-72.5).to_string(), "-72.5 dBm");
+        assert_eq!(format!("{:.1}", Level::db_spl(93.979)), "94.0 dB SPL");
+        assert_eq!(Gain::decibels(3.0).to_string(), "3 dB");
+        assert_eq!(Level::dbuv(40.0).to_nepers(), 40.0 / DECIBELS_PER_NEPER);
+    }
+}
@@ -0,0 +1,402 @@
+//! Locale-specific decimal and grouping separators.
+//!
+//! A [`NumberFormat`] says which character separates the integer part of a
+//! number from its fraction and how the digits of the integer part are
+//! grouped, so that one and a quarter thousand reads `1,234.5` in the US,
+//! `1.234,5` in Germany and `1 234,5` in France. It applies when writing
+//! quantities with [`QuantityFormatter::number_format`], money with
+//! [`Money::to_formatted_string_with`] and when parsing with
+//! [`Dimension::parse_localized`].
+//!
+//! [`QuantityFormatter::number_format`]: crate::core::QuantityFormatter::number_format
+//! [`Money::to_formatted_string_with`]: crate::market::Money::to_formatted_string_with
+//! [`Dimension::parse_localized`]: crate::core::Dimension::parse_localized
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::prelude::*;
+//! use rquants::space::length::LengthDimension;
+//!
+//! let length = LengthDimension::parse_localized("1.234,5 km", NumberFormat::DE_DE).unwrap();
+//! assert_eq!(length, Length::kilometers(1234.5));
+//! assert_eq!(
+//!     length.display().number_format(NumberFormat::EN_IN).to_string(),
+//!     "1,234.5 km"
+//! );
+//! assert_eq!(
+//!     Length::meters(12_345_678.0).display().number_format(NumberFormat::EN_IN).to_string(),
+//!     "1,23,45,678 m"
+//! );
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+const NARROW_NO_BREAK_SPACE: char = '\u{202F}';
+
+/// How the digits of the integer part of a number are grouped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Grouping {
+    /// Groups of three digits, such as `12,345,678`.
+    #[default]
+    Thousands,
+    /// The last three digits, then groups of two, such as `1,23,45,678`,
+    /// as in India.
+    Indian,
+}
+
+/// The decimal separator and digit grouping of numbers.
+///
+/// The default, [`NumberFormat::PLAIN`], writes numbers as Rust does, with
+/// a decimal point and no grouping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberFormat {
+    decimal: char,
+    group: Option<char>,
+    grouping: Grouping,
+}
+
+impl NumberFormat {
+    /// A decimal point and no grouping: `1234.5`.
+    pub const PLAIN: Self = Self::new('.');
+
+    /// United States English: `1,234.5`.
+    pub const EN_US: Self = Self::new('.').group(',');
+
+    /// German: `1.234,5`.
+    pub const DE_DE: Self = Self::new(',').group('.');
+
+    /// French, grouping with a narrow no-break space (U+202F): `1 234,5`.
+    pub const FR_FR: Self = Self::new(',').group(NARROW_NO_BREAK_SPACE);
+
+    /// Indian English, grouping in lakhs and crores: `12,34,567.5`.
+    pub const EN_IN: Self = Self::new('.').group(',').grouping(Grouping::Indian);
+
+    /// Creates a format with the given decimal separator and no grouping.
+    pub const fn new(decimal: char) -> Self {
+        Self {
+            decimal,
+            group: None,
+            grouping: Grouping::Thousands,
+        }
+    }
+
+    /// Groups the digits of the integer part, separated by `separator`.
+    pub const fn group(mut self, separator: char) -> Self {
+        self.group = Some(separator);
+        self
+    }
+
+    /// Sets how the digits of the integer part are grouped.
+    pub const fn grouping(mut self, grouping: Grouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Returns the preset for a locale tag: `en-US`, `de-DE`, `fr-FR` or
+    /// `en-IN`, ignoring case and accepting `_` for `-`.
+    pub fn for_locale(tag: &str) -> Option<Self> {
+        let presets = [
+            ("en-US", Self::EN_US),
+            ("de-DE", Self::DE_DE),
+            ("fr-FR", Self::FR_FR),
+            ("en-IN", Self::EN_IN),
+        ];
+        presets
+            .into_iter()
+            .find(|(name, _)| {
+                name.len() == tag.len()
+                    && name.chars().zip(tag.chars()).all(|(expected, c)| {
+                        expected.eq_ignore_ascii_case(&c) || (expected == '-' && c == '_')
+                    })
+            })
+            .map(|(_, format)| format)
+    }
+
+    /// Returns the decimal separator.
+    pub const fn decimal_separator(&self) -> char {
+        self.decimal
+    }
+
+    /// Returns the group separator, if digits are grouped.
+    pub const fn group_separator(&self) -> Option<char> {
+        self.group
+    }
+
+    /// Parses a number written in this format.
+    ///
+    /// Group separators are optional, but where present they must be in
+    /// the right places, so `1.234,5` is a number in German but `1.23,5`
+    /// is not. Any space is accepted in place of a space separator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::core::NumberFormat;
+    ///
+    /// assert_eq!(NumberFormat::DE_DE.parse_number("-1.234,5"), Some(-1234.5));
+    /// assert_eq!(NumberFormat::DE_DE.parse_number("1234,5"), Some(1234.5));
+    /// assert_eq!(NumberFormat::FR_FR.parse_number("1 234 567"), Some(1234567.0));
+    /// assert_eq!(NumberFormat::EN_IN.parse_number("12,34,567.5"), Some(1234567.5));
+    /// assert_eq!(NumberFormat::EN_US.parse_number("1,5"), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_number(&self, text: &str) -> Option<f64> {
+        let (mantissa, exponent) = match text.find(['e', 'E']) {
+            Some(index) => text.split_at(index),
+            None => (text, ""),
+        };
+        let (integer, fraction) = match mantissa.split_once(self.decimal) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (mantissa, None),
+        };
+        let digits = integer.trim_start_matches(['+', '-']);
+        let sign = &integer[..integer.len() - digits.len()];
+        if !self.is_grouped_correctly(digits) {
+            return None;
+        }
+
+        let mut plain = String::with_capacity(text.len());
+        plain.push_str(sign);
+        plain.extend(digits.chars().filter(|&c| !self.is_group_separator(c)));
+        if let Some(fraction) = fraction {
+            plain.push('.');
+            plain.push_str(fraction);
+        }
+        plain.push_str(exponent);
+        plain.parse().ok()
+    }
+
+    /// Returns the length in bytes of the number at the start of `s`.
+    ///
+    /// The number may contain a sign, an exponent, the decimal separator
+    /// and group separators followed by a digit; whether it is well formed
+    /// is left to [`NumberFormat::parse_number`].
+    #[cfg(feature = "alloc")]
+    pub(crate) fn number_len(&self, s: &str) -> usize {
+        let mut chars = s.char_indices().peekable();
+        while let Some(&(index, c)) = chars.peek() {
+            chars.next();
+            let part_of_number = c.is_ascii_digit()
+                || matches!(c, '+' | '-' | 'e' | 'E')
+                || c == self.decimal
+                || (self.is_group_separator(c)
+                    && chars.peek().is_some_and(|(_, next)| next.is_ascii_digit()));
+            if !part_of_number {
+                return index;
+            }
+        }
+        s.len()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_group_separator(&self, c: char) -> bool {
+        self.group
+            .is_some_and(|group| c == group || (group.is_whitespace() && c.is_whitespace()))
+    }
+
+    /// Returns true if `digits` has no group separators, or has them all
+    /// where this format puts them.
+    #[cfg(feature = "alloc")]
+    fn is_grouped_correctly(&self, digits: &str) -> bool {
+        let count = digits.split(|c| self.is_group_separator(c)).count();
+        if count == 1 {
+            return true;
+        }
+        let inner = match self.grouping {
+            Grouping::Thousands => 3,
+            Grouping::Indian => 2,
+        };
+        digits
+            .split(|c| self.is_group_separator(c))
+            .enumerate()
+            .all(|(i, group)| {
+                let len = group.len();
+                let len_ok = if i + 1 == count {
+                    len == 3
+                } else if i == 0 {
+                    (1..=inner).contains(&len)
+                } else {
+                    len == inner
+                };
+                len_ok && group.bytes().all(|b| b.is_ascii_digit())
+            })
+    }
+
+    /// Returns true if a group separator goes before the last `remaining`
+    /// digits of the integer part.
+    fn separates(&self, remaining: usize) -> bool {
+        match self.grouping {
+            Grouping::Thousands => remaining > 0 && remaining.is_multiple_of(3),
+            Grouping::Indian => {
+                remaining == 3 || (remaining > 3 && (remaining - 3).is_multiple_of(2))
+            }
+        }
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::PLAIN
+    }
+}
+
+/// Calls `write` with `out`, rewriting the plain number it writes in
+/// `format`.
+pub(crate) fn write_localized(
+    out: &mut dyn Write,
+    format: NumberFormat,
+    write: impl Fn(&mut dyn Write) -> fmt::Result,
+) -> fmt::Result {
+    if format == NumberFormat::PLAIN {
+        return write(out);
+    }
+    let mut count = IntegerDigits {
+        count: 0,
+        done: false,
+    };
+    write(&mut count)?;
+    write(&mut Localize {
+        out,
+        format,
+        remaining: count.count,
+        integer: true,
+    })
+}
+
+/// Counts the digits of the integer part of a plain number.
+struct IntegerDigits {
+    count: usize,
+    done: bool,
+}
+
+impl Write for IntegerDigits {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if c.is_ascii_digit() && !self.done {
+                self.count += 1;
+            } else if !matches!(c, '+' | '-') {
+                self.done = true;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Replaces the decimal point of a plain number and groups the digits of
+/// its integer part.
+struct Localize<'a> {
+    out: &'a mut dyn Write,
+    format: NumberFormat,
+    remaining: usize,
+    integer: bool,
+}
+
+impl Write for Localize<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match c {
+            '0'..='9' if self.integer => {
+                self.out.write_char(c)?;
+                self.remaining = self.remaining.saturating_sub(1);
+                match self.format.group {
+                    Some(group) if self.format.separates(self.remaining) => {
+                        self.out.write_char(group)
+                    }
+                    _ => Ok(()),
+                }
+            }
+            '.' => {
+                self.integer = false;
+                self.out.write_char(self.format.decimal)
+            }
+            '+' | '-' | '0'..='9' => self.out.write_char(c),
+            _ => {
+                self.integer = false;
+                self.out.write_char(c)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn localized(format: NumberFormat, text: &str) -> String {
+        let mut out = String::new();
+        write_localized(&mut out, format, |out| out.write_str(text)).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_write_localized() {
+        assert_eq!(localized(NumberFormat::PLAIN, "1234.5"), "1234.5");
+        assert_eq!(localized(NumberFormat::EN_US, "1234567.25"), "1,234,567.25");
+        assert_eq!(localized(NumberFormat::DE_DE, "-1234.5"), "-1.234,5");
+        assert_eq!(localized(NumberFormat::FR_FR, "1234.5"), "1\u{202F}234,5");
+        assert_eq!(localized(NumberFormat::EN_IN, "123456789"), "12,34,56,789");
+        assert_eq!(localized(NumberFormat::EN_IN, "1234"), "1,234");
+        assert_eq!(localized(NumberFormat::EN_US, "123"), "123");
+        assert_eq!(localized(NumberFormat::EN_US, "0.000123"), "0.000123");
+        assert_eq!(localized(NumberFormat::DE_DE, "1.2345e4"), "1,2345e4");
+        assert_eq!(localized(NumberFormat::DE_DE, "12345e-10"), "12.345e-10");
+        assert_eq!(localized(NumberFormat::EN_US, "NaN"), "NaN");
+    }
+
+    #[test]
+    fn test_parse_number() {
+        let de = NumberFormat::DE_DE;
+        assert_eq!(de.parse_number("1,5"), Some(1.5));
+        assert_eq!(de.parse_number("1.234,5"), Some(1234.5));
+        assert_eq!(de.parse_number("+1.234.567"), Some(1_234_567.0));
+        assert_eq!(de.parse_number("1,5e3"), Some(1500.0));
+        assert_eq!(de.parse_number("1.23,5"), None);
+        assert_eq!(de.parse_number("1,234.5"), None);
+        assert_eq!(de.parse_number("1,2,3"), None);
+
+        let fr = NumberFormat::FR_FR;
+        assert_eq!(fr.parse_number("1\u{202F}234,5"), Some(1234.5));
+        assert_eq!(fr.parse_number("1\u{A0}234"), Some(1234.0));
+
+        let en_in = NumberFormat::EN_IN;
+        assert_eq!(en_in.parse_number("1,23,45,678"), Some(12_345_678.0));
+        assert_eq!(en_in.parse_number("12,345"), Some(12_345.0));
+        assert_eq!(en_in.parse_number("12,345,678"), None);
+
+        assert_eq!(
+            NumberFormat::EN_US.parse_number("12,345,678.9"),
+            Some(12_345_678.9)
+        );
+        assert_eq!(NumberFormat::PLAIN.parse_number("-2.5E-3"), Some(-2.5e-3));
+        assert_eq!(NumberFormat::PLAIN.parse_number("1.2.3"), None);
+        assert_eq!(NumberFormat::PLAIN.parse_number("1,234"), None);
+    }
+
+    #[test]
+    fn test_number_len() {
+        assert_eq!(NumberFormat::PLAIN.number_len("1.5 km"), 3);
+        assert_eq!(NumberFormat::DE_DE.number_len("1.234,5kg"), 7);
+        assert_eq!(NumberFormat::FR_FR.number_len("1 234,5 km"), 7);
+        assert_eq!(NumberFormat::FR_FR.number_len("10 m"), 2);
+        assert_eq!(NumberFormat::EN_US.number_len("1,000,"), 5);
+        assert_eq!(NumberFormat::EN_US.number_len("m"), 0);
+    }
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(NumberFormat::for_locale("de-DE"), Some(NumberFormat::DE_DE));
+        assert_eq!(NumberFormat::for_locale("en_in"), Some(NumberFormat::EN_IN));
+        assert_eq!(NumberFormat::for_locale("xx-XX"), None);
+        assert_eq!(NumberFormat::default(), NumberFormat::PLAIN);
+        assert_eq!(NumberFormat::FR_FR.decimal_separator(), ',');
+        assert_eq!(NumberFormat::PLAIN.group_separator(), None);
+        let swiss = NumberFormat::new('.').group('\'');
+        assert_eq!(swiss.group_separator(), Some('\''));
+    }
+}
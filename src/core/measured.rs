@@ -0,0 +1,494 @@
+//! Quantities with a standard uncertainty.
+//!
+//! [`Measured`] pairs a quantity with its standard uncertainty and propagates
+//! it through arithmetic using first-order (GUM) propagation, assuming the
+//! operands are uncorrelated.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::prelude::*;
+//!
+//! let distance = Measured::new(Length::meters(100.0), Length::meters(0.5));
+//! let time = Measured::new(Time::seconds(20.0), Time::seconds(0.1));
+//!
+//! let speed: Measured<Velocity> = distance / time;
+//! assert!((speed.value().to_meters_per_second() - 5.0).abs() < 1e-12);
+//! // Relative uncertainties add in quadrature: √(0.5² + 0.5²) % ≈ 0.707 %
+//! assert!((speed.relative_uncertainty() - 0.0070711).abs() < 1e-6);
+//! ```
+
+#[cfg(feature = "alloc")]
+use super::dimension::parse_value_and_unit;
+#[cfg(feature = "alloc")]
+use super::error::{ParseErrorKind, QuantityParseError};
+use super::quantity::Quantity;
+use super::unit::UnitOfMeasure;
+use crate::math;
+#[cfg(feature = "alloc")]
+use alloc::format;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+
+/// A quantity with a standard uncertainty.
+///
+/// The uncertainty is stored as an absolute quantity in the unit of the
+/// value and is always non-negative. It is a difference, so converting it
+/// between units only scales it: ±0.5 °C is ±0.5 K, not ±273.65 K.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measured<Q: Quantity> {
+    value: Q,
+    uncertainty: f64,
+}
+
+impl<Q: Quantity> Measured<Q> {
+    /// Creates a measured quantity with an absolute standard uncertainty.
+    pub fn new(value: Q, uncertainty: Q) -> Self {
+        Self {
+            value,
+            uncertainty: scale(uncertainty.value(), uncertainty.unit(), value.unit()).abs(),
+        }
+    }
+
+    /// Creates a measured quantity with a relative standard uncertainty
+    /// (e.g., `0.01` for 1 %).
+    pub fn with_relative(value: Q, relative: f64) -> Self {
+        Self {
+            value,
+            uncertainty: (value.value() * relative).abs(),
+        }
+    }
+
+    /// Creates a measured quantity without uncertainty.
+    pub fn exact(value: Q) -> Self {
+        Self {
+            value,
+            uncertainty: 0.0,
+        }
+    }
+
+    /// Returns the best estimate.
+    pub fn value(&self) -> Q {
+        self.value
+    }
+
+    /// Returns the absolute standard uncertainty in the unit of the value.
+    ///
+    /// The result is a quantity in that unit, so for units with an offset
+    /// such as °C it should only be read back in the same unit.
+    pub fn uncertainty(&self) -> Q {
+        Q::new(self.uncertainty, self.value.unit())
+    }
+
+    /// Returns the uncertainty relative to the magnitude of the value.
+    ///
+    /// Returns infinity for a zero value with a non-zero uncertainty.
+    pub fn relative_uncertainty(&self) -> f64 {
+        if self.uncertainty == 0.0 {
+            0.0
+        } else {
+            self.uncertainty / self.value.value().abs()
+        }
+    }
+
+    /// Returns the interval `value ± k·uncertainty` as `(lower, upper)`.
+    ///
+    /// `k` is the coverage factor, e.g. `2.0` for roughly 95 % coverage.
+    pub fn bounds(&self, k: f64) -> (Q, Q) {
+        let unit = self.value.unit();
+        let spread = k.abs() * self.uncertainty;
+        (
+            Q::new(self.value.value() - spread, unit),
+            Q::new(self.value.value() + spread, unit),
+        )
+    }
+
+    /// Converts the value and uncertainty to the given unit.
+    pub fn in_unit(&self, unit: Q::Unit) -> Self {
+        Self {
+            value: self.value.in_unit(unit),
+            uncertainty: self.uncertainty_in(unit),
+        }
+    }
+
+    /// Returns the uncertainty as a number in `unit`.
+    fn uncertainty_in(&self, unit: Q::Unit) -> f64 {
+        scale(self.uncertainty, self.value.unit(), unit)
+    }
+
+    /// Combines two uncorrelated uncertainty terms in the given unit.
+    fn quadrature(unit: Q::Unit, a: Q, b: Q) -> f64 {
+        math::hypot(a.to(unit), b.to(unit))
+    }
+}
+
+/// Converts a difference of `amount` from one unit to another, scaling it
+/// by the ratio of the unit factors and ignoring any offset.
+fn scale<U: UnitOfMeasure>(amount: f64, from: U, to: U) -> f64 {
+    if from == to {
+        return amount;
+    }
+    match (from.exact_factor(), to.exact_factor()) {
+        (Some(from), Some(to)) => match from.checked_div(to) {
+            Some(ratio) => ratio.apply(amount),
+            None => amount * from.to_f64() / to.to_f64(),
+        },
+        _ => amount * from.conversion_factor() / to.conversion_factor(),
+    }
+}
+
+impl<Q: Quantity> From<Q> for Measured<Q> {
+    fn from(value: Q) -> Self {
+        Self::exact(value)
+    }
+}
+
+impl<Q: Quantity> fmt::Display for Measured<Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = self.value.unit().symbol();
+        match f.precision() {
+            Some(precision) => write!(
+                f,
+                "{:.*} ± {:.*} {}",
+                precision,
+                self.value.value(),
+                precision,
+                self.uncertainty,
+                symbol
+            ),
+            None => write!(
+                f,
+                "{} ± {} {}",
+                self.value.value(),
+                self.uncertainty,
+                symbol
+            ),
+        }
+    }
+}
+
+impl<Q> Add for Measured<Q>
+where
+    Q: Quantity + Add<Output = Q>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let value = self.value + rhs.value;
+        let unit = value.unit();
+        let uncertainty = math::hypot(self.uncertainty_in(unit), rhs.uncertainty_in(unit));
+        Self { value, uncertainty }
+    }
+}
+
+impl<Q> Sub for Measured<Q>
+where
+    Q: Quantity + Sub<Output = Q>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let value = self.value - rhs.value;
+        let unit = value.unit();
+        let uncertainty = math::hypot(self.uncertainty_in(unit), rhs.uncertainty_in(unit));
+        Self { value, uncertainty }
+    }
+}
+
+impl<Q: Quantity> Neg for Measured<Q> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            value: self.value.negate(),
+            uncertainty: self.uncertainty,
+        }
+    }
+}
+
+impl<Q: Quantity> Mul<f64> for Measured<Q> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self {
+            value: self.value.map(|v| v * rhs),
+            uncertainty: (self.uncertainty * rhs).abs(),
+        }
+    }
+}
+
+impl<Q: Quantity> Mul<Measured<Q>> for f64 {
+    type Output = Measured<Q>;
+
+    fn mul(self, rhs: Measured<Q>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<Q: Quantity> Div<f64> for Measured<Q> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self {
+            value: self.value.map(|v| v / rhs),
+            uncertainty: (self.uncertainty / rhs).abs(),
+        }
+    }
+}
+
+/// `σ(a·b)² = (b·σa)² + (a·σb)²`
+impl<A, B, C> Mul<Measured<B>> for Measured<A>
+where
+    A: Quantity + Mul<B, Output = C>,
+    B: Quantity,
+    C: Quantity,
+{
+    type Output = Measured<C>;
+
+    fn mul(self, rhs: Measured<B>) -> Self::Output {
+        let value = self.value * rhs.value;
+        let uncertainty = Measured::<C>::quadrature(
+            value.unit(),
+            self.uncertainty() * rhs.value,
+            self.value * rhs.uncertainty(),
+        );
+        Measured { value, uncertainty }
+    }
+}
+
+/// `σ(a/b)² = (σa/b)² + (a/b · σb/b)²`
+impl<A, B, C> Div<Measured<B>> for Measured<A>
+where
+    A: Quantity + Div<B, Output = C>,
+    B: Quantity,
+    C: Quantity,
+{
+    type Output = Measured<C>;
+
+    fn div(self, rhs: Measured<B>) -> Self::Output {
+        let value = self.value / rhs.value;
+        let rhs_relative = rhs.uncertainty / rhs.value.value();
+        let uncertainty = Measured::<C>::quadrature(
+            value.unit(),
+            self.uncertainty() / rhs.value,
+            value.map(|v| v * rhs_relative),
+        );
+        Measured { value, uncertainty }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Q> FromStr for Measured<Q>
+where
+    Q: Quantity + FromStr<Err = QuantityParseError>,
+{
+    type Err = QuantityParseError;
+
+    /// Parses `"10.0 ± 0.2 m"`, `"10.0 m ± 2 cm"`, `"(10.0 ± 0.2) m"` or
+    /// `"10.0 m ± 2%"`; `+/-` may be used instead of `±`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measured(s, "Measured", Q::from_str)
+    }
+}
+
+/// Parses the forms accepted by [`Measured::from_str`] with `parse` for the
+/// quantity parts.
+#[cfg(feature = "alloc")]
+///
+/// Errors from `parse` are returned as they are; errors in the overall form
+/// are reported for `dimension`.
+pub(crate) fn parse_measured<Q: Quantity>(
+    s: &str,
+    dimension: &str,
+    parse: impl Fn(&str) -> Result<Q, QuantityParseError>,
+) -> Result<Measured<Q>, QuantityParseError> {
+    let s = s.trim();
+    let error = || QuantityParseError::new(dimension, s);
+    let (left, right) = s
+        .split_once('±')
+        .or_else(|| s.split_once("+/-"))
+        .ok_or_else(error)?;
+    let (left, right) = (left.trim(), right.trim());
+
+    // "(10.0 ± 0.2) m"
+    if let Some(value) = left.strip_prefix('(') {
+        let (uncertainty, unit) = right.split_once(')').ok_or_else(error)?;
+        let value = parse(&format!("{} {}", value.trim(), unit.trim()))?;
+        let uncertainty = parse(&format!("{} {}", uncertainty.trim(), unit.trim()))?;
+        return Ok(Measured::new(value, uncertainty));
+    }
+
+    // "10.0 m ± 2%"
+    if let Some(percent) = right.strip_suffix('%') {
+        let percent = percent.trim();
+        let relative: f64 = percent.parse().map_err(|_| {
+            let start = percent.as_ptr() as usize - s.as_ptr() as usize;
+            error().with_kind(ParseErrorKind::BadNumber, start..start + percent.len())
+        })?;
+        return Ok(Measured::with_relative(parse(left)?, relative / 100.0));
+    }
+
+    let uncertainty = parse(right)?;
+    // "10.0 ± 0.2 m" takes the unit of the uncertainty
+    let value = match left.parse::<f64>() {
+        Ok(value) => Q::new(value, uncertainty.unit()),
+        Err(_) => {
+            parse_value_and_unit(left, dimension)?;
+            parse(left)?
+        }
+    };
+    Ok(Measured::new(value, uncertainty))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Dimension;
+    use crate::energy::{Energy, Power};
+    use crate::motion::Velocity;
+    use crate::space::length::LengthDimension;
+    use crate::space::{Area, Length, LengthUnit};
+    use crate::time::Time;
+
+    fn close(left: f64, right: f64) -> bool {
+        (left - right).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_construction() {
+        let m = Measured::new(Length::meters(10.0), Length::centimeters(20.0));
+        assert!(close(m.uncertainty().to_meters(), 0.2));
+        assert!(close(m.relative_uncertainty(), 0.02));
+
+        let r = Measured::with_relative(Length::meters(-50.0), 0.1);
+        assert!(close(r.uncertainty().to_meters(), 5.0));
+
+        let exact: Measured<Length> = Length::meters(1.0).into();
+        assert_eq!(exact.relative_uncertainty(), 0.0);
+
+        let (lower, upper) = m.bounds(2.0);
+        assert!(close(lower.to_meters(), 9.6));
+        assert!(close(upper.to_meters(), 10.4));
+    }
+
+    #[test]
+    fn test_sum_and_difference_add_in_quadrature() {
+        let a = Measured::new(Length::meters(10.0), Length::meters(0.3));
+        let b = Measured::new(Length::centimeters(500.0), Length::centimeters(40.0));
+
+        let sum = a + b;
+        assert!(close(sum.value().to_meters(), 15.0));
+        assert!(close(sum.uncertainty().to_meters(), 0.5));
+
+        let difference = a - b;
+        assert!(close(difference.value().to_meters(), 5.0));
+        assert!(close(difference.uncertainty().to_meters(), 0.5));
+        assert!(close((-difference).uncertainty().to_meters(), 0.5));
+    }
+
+    #[test]
+    fn test_scaling() {
+        let m = Measured::new(Length::meters(2.0), Length::meters(0.1));
+        let scaled = m * -3.0;
+        assert!(close(scaled.value().to_meters(), -6.0));
+        assert!(close(scaled.uncertainty().to_meters(), 0.3));
+        assert!(close((2.0 * m).uncertainty().to_meters(), 0.2));
+        assert!(close((m / 4.0).uncertainty().to_meters(), 0.025));
+    }
+
+    #[test]
+    fn test_cross_quantity_propagation() {
+        let power = Measured::new(Power::watts(100.0), Power::watts(3.0));
+        let time = Measured::new(Time::seconds(10.0), Time::seconds(0.4));
+        let energy: Measured<Energy> = power * time;
+        assert!(close(energy.value().to_joules(), 1000.0));
+        // √((10·3)² + (100·0.4)²) = 50 J
+        assert!(close(energy.uncertainty().to_joules(), 50.0));
+
+        let side = Measured::new(Length::meters(0.0), Length::meters(0.1));
+        let area: Measured<Area> = side * Measured::new(Length::meters(2.0), Length::meters(0.0));
+        assert!(close(area.uncertainty().to_square_meters(), 0.2));
+
+        let speed: Measured<Velocity> = Measured::new(Length::meters(30.0), Length::meters(0.3))
+            / Measured::new(Time::seconds(10.0), Time::seconds(0.4));
+        assert!(close(speed.value().to_meters_per_second(), 3.0));
+        // 3 m/s · √(0.01² + 0.04²)
+        assert!(close(
+            speed.uncertainty().to_meters_per_second(),
+            3.0 * 0.01f64.hypot(0.04)
+        ));
+    }
+
+    #[test]
+    fn test_unit_conversion_scales_uncertainty() {
+        use crate::motion::{Pressure, PressureUnit};
+        use crate::thermal::{Temperature, TemperatureScale};
+
+        let t = Measured::new(Temperature::celsius(20.0), Temperature::celsius(0.5));
+        let kelvin = t.in_unit(TemperatureScale::Kelvin);
+        assert!(close(kelvin.value().value(), 293.15));
+        assert!(close(kelvin.uncertainty().value(), 0.5));
+        let fahrenheit = t.in_unit(TemperatureScale::Fahrenheit);
+        assert!(close(fahrenheit.uncertainty().value(), 0.9));
+
+        let p = Measured::new(Pressure::psig(30.0), Pressure::psig(1.0));
+        let kpa = p.in_unit(PressureUnit::Kilopascals);
+        assert!(close(
+            kpa.uncertainty().to_kilopascals(),
+            6.894_757_293_168_361
+        ));
+
+        let kpa = Pressure::kilopascals(6.894_757_293_168_361);
+        let sum = p + Measured::new(Pressure::kilopascals(100.0), kpa);
+        let sigma = sum.uncertainty_in(PressureUnit::PoundsPerSquareInchGauge);
+        assert!(close(sigma, core::f64::consts::SQRT_2));
+    }
+
+    #[test]
+    fn test_display() {
+        let m = Measured::new(Length::meters(10.0), Length::meters(0.2));
+        assert_eq!(m.to_string(), "10 ± 0.2 m");
+        assert_eq!(format!("{m:.1}"), "10.0 ± 0.2 m");
+        assert_eq!(
+            format!("{:.0}", m.in_unit(LengthUnit::Centimeters)),
+            "1000 ± 20 cm"
+        );
+    }
+
+    #[test]
+    fn test_parse_forms() {
+        for s in [
+            "10.0 ± 0.2 m",
+            "10.0 +/- 0.2 m",
+            "(10.0 ± 0.2) m",
+            "10 m ± 20 cm",
+            "10.0 m ± 2%",
+        ] {
+            let m: Measured<Length> = s.parse().unwrap();
+            assert!(close(m.value().to_meters(), 10.0), "{s}");
+            assert!(close(m.uncertainty().to_meters(), 0.2), "{s}");
+        }
+
+        let m = LengthDimension::parse_measured("1.5 ± 0.1 km").unwrap();
+        assert!(close(m.value().to_kilometers(), 1.5));
+        assert_eq!(m.value().unit(), LengthUnit::Kilometers);
+
+        assert!("10 m".parse::<Measured<Length>>().is_err());
+        assert!("10 ± 0.2 s".parse::<Measured<Length>>().is_err());
+        let err = LengthDimension::parse_measured("ten ± 1 m").unwrap_err();
+        assert_eq!(err.dimension, "Length");
+
+        // Errors in the parts keep their kind, span and suggestions
+        let err = LengthDimension::parse_measured("10 ± 0.2 kmz").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::UnknownUnit);
+        assert_eq!(err.input, "0.2 kmz");
+        assert_eq!(err.span, 4..7);
+        assert!(!err.suggestions.is_empty());
+
+        let err = "10 m ± x%".parse::<Measured<Length>>().unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::BadNumber);
+        assert_eq!(err.span, 8..9);
+    }
+}
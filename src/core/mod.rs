@@ -5,19 +5,63 @@
 //! - [`Quantity`] - The core trait for all measurable quantities
 //! - [`UnitOfMeasure`] - Trait for units of measurement
 //! - [`Dimension`] - Trait for dimension metadata and parsing
+//! - [`Scalar`] - Numeric types that can store a quantity's value
 //! - [`Dimensionless`] - Quantities without physical dimension
+//! - [`DynQuantity`] - Quantities with a runtime-checked dimension
+//! - [`Measured`] - Quantities with a standard uncertainty
+//! - [`Interval`] - Guaranteed bounds with interval arithmetic
+//! - [`CompositeUnit`] - Quantities written in descending units, such as "5 ft 11 in"
+//! - [`UnitPolicy`] - Which units [`Quantity::to_best_unit_with`] may choose
+//! - [`QuantityFormatter`] - Precision, significant figures, notation and long unit names
+//! - [`NumberFormat`] - Locale-specific decimal and grouping separators
+//! - [`OrderedQuantity`] - Quantities with a total order, usable as map keys
+//! - [`Level`] - Quantities in decibels relative to a reference, and [`Gain`]s
+//! - [`affine::Point`] - Points on a scale, with quantities as the deltas between them
 //! - [`quantity_range::QuantityRange`] - Ranges of quantities
 //! - [`ratio::Ratio`] - Ratios between quantities
+//! - [`Rational`] - Exact conversion factors
+//! - [`UnitMatching`] - Finding units by alias, long name, ASCII spelling or regardless of case
+//! - [`unit_expr::UnitExpr`] - Compound unit expressions such as "kg·m/s²"
 
+pub mod affine;
+pub mod best_unit;
+pub mod composite;
 pub mod dimension;
 pub mod dimensionless;
+pub mod dynamic;
+#[cfg(feature = "alloc")]
 pub mod error;
+pub mod format;
+pub mod interval;
+pub mod level;
+pub mod locale;
+pub mod measured;
+pub mod ordered;
 pub mod quantity;
 pub mod quantity_range;
 pub mod ratio;
+pub mod rational;
+pub mod scalar;
 pub mod unit;
+#[cfg(feature = "std")]
+pub mod unit_expr;
+pub mod unit_match;
 
-pub use dimension::Dimension;
+pub use best_unit::UnitPolicy;
+pub use composite::{CompositeDisplay, CompositeUnit, Rounding};
+pub use dimension::{BaseDimensions, Dimension};
 pub use dimensionless::{Dimensionless, DimensionlessConversions, DimensionlessUnit};
+pub use dynamic::DynQuantity;
+pub use format::{Notation, QuantityFormatter};
+pub use interval::Interval;
+pub use level::{Gain, Level, LevelQuantity, LevelReference};
+pub use locale::{Grouping, NumberFormat};
+pub use measured::Measured;
+#[cfg(feature = "alloc")]
+pub use ordered::sort_quantities;
+pub use ordered::{max_quantity, min_quantity, OrderedQuantity};
 pub use quantity::Quantity;
-pub use unit::UnitOfMeasure;
+pub use rational::{ConversionFactor, Rational};
+pub use scalar::Scalar;
+pub use unit::{UnitName, UnitOfMeasure};
+pub use unit_match::{UnitMatchError, UnitMatching};
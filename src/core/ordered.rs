@@ -0,0 +1,230 @@
+//! Totally ordered, hashable quantities.
+//!
+//! Quantities only implement `PartialOrd` and `PartialEq`, because their
+//! values may be NaN. [`OrderedQuantity`] wraps a quantity with the total
+//! order of [`Quantity::compare`], so it implements `Ord`, `Eq` and `Hash`
+//! and can be sorted or used as a `BTreeMap` or `HashMap` key.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::prelude::*;
+//! use std::collections::BTreeMap;
+//!
+//! let mut runs = BTreeMap::new();
+//! runs.insert(Length::kilometers(5.0).total(), "5k");
+//! runs.insert(Length::miles(1.0).total(), "mile");
+//! runs.insert(Length::meters(400.0).total(), "lap");
+//!
+//! let names: Vec<_> = runs.values().copied().collect();
+//! assert_eq!(names, ["lap", "mile", "5k"]);
+//! ```
+
+use crate::core::{Quantity, Scalar};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+/// A quantity ordered and hashed by the total order of its primary value.
+///
+/// Two ordered quantities are equal when their primary values are
+/// identical under [`Scalar::total_cmp`]: `1 km` equals `1000 m`, but unlike
+/// `PartialEq`, `-0 m` is less than `0 m` and NaN equals itself.
+///
+/// Create one with [`OrderedQuantity::new`] or [`Quantity::total`]; it
+/// dereferences to the wrapped quantity.
+#[derive(Debug, Clone, Copy)]
+pub struct OrderedQuantity<Q, T = f64> {
+    quantity: Q,
+    scalar: PhantomData<T>,
+}
+
+impl<T: Scalar, Q: Quantity<T>> OrderedQuantity<Q, T> {
+    /// Wraps a quantity.
+    pub fn new(quantity: Q) -> Self {
+        Self {
+            quantity,
+            scalar: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped quantity.
+    pub fn into_inner(self) -> Q {
+        self.quantity
+    }
+}
+
+impl<T: Scalar, Q: Quantity<T>> Deref for OrderedQuantity<Q, T> {
+    type Target = Q;
+
+    fn deref(&self) -> &Q {
+        &self.quantity
+    }
+}
+
+impl<T: Scalar, Q: Quantity<T>> PartialEq for OrderedQuantity<Q, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Scalar, Q: Quantity<T>> Eq for OrderedQuantity<Q, T> {}
+
+impl<T: Scalar, Q: Quantity<T>> PartialOrd for OrderedQuantity<Q, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Scalar, Q: Quantity<T>> Ord for OrderedQuantity<Q, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.quantity.compare(&other.quantity)
+    }
+}
+
+impl<T: Scalar, Q: Quantity<T>> Hash for OrderedQuantity<Q, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Values equal under `total_cmp` have identical bits.
+        self.quantity.to_primary().to_f64().to_bits().hash(state);
+    }
+}
+
+impl<T: Scalar, Q: Quantity<T>> fmt::Display for OrderedQuantity<Q, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.quantity, f)
+    }
+}
+
+/// Sorts quantities in ascending order of [`Quantity::compare`].
+///
+/// The sort is stable, so equal quantities in different units keep their
+/// relative order. Without the `alloc` feature, use
+/// `quantities.sort_unstable_by(Quantity::compare)`.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::core::sort_quantities;
+/// use rquants::prelude::*;
+///
+/// let mut lengths = [Length::feet(4.0), Length::meters(1.0), Length::inches(30.0)];
+/// sort_quantities(&mut lengths);
+/// assert_eq!(lengths[0], Length::inches(30.0));
+/// assert_eq!(lengths[2], Length::feet(4.0));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn sort_quantities<T: Scalar, Q: Quantity<T>>(quantities: &mut [Q]) {
+    quantities.sort_by(Quantity::compare);
+}
+
+/// Returns the largest quantity under [`Quantity::compare`], or `None` if
+/// there are none.
+///
+/// If several quantities are equally large, the last one is returned.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::core::max_quantity;
+/// use rquants::prelude::*;
+///
+/// let masses = [Mass::kilograms(1.0), Mass::pounds(3.0), Mass::grams(900.0)];
+/// assert_eq!(max_quantity(masses), Some(Mass::pounds(3.0)));
+/// ```
+pub fn max_quantity<T, Q, I>(quantities: I) -> Option<Q>
+where
+    T: Scalar,
+    Q: Quantity<T>,
+    I: IntoIterator<Item = Q>,
+{
+    quantities.into_iter().max_by(Quantity::compare)
+}
+
+/// Returns the smallest quantity under [`Quantity::compare`], or `None` if
+/// there are none.
+///
+/// If several quantities are equally small, the first one is returned.
+pub fn min_quantity<T, Q, I>(quantities: I) -> Option<Q>
+where
+    T: Scalar,
+    Q: Quantity<T>,
+    I: IntoIterator<Item = Q>,
+{
+    quantities.into_iter().min_by(Quantity::compare)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::space::{Length, LengthUnit};
+    use std::collections::{BTreeSet, HashSet};
+
+    #[test]
+    fn test_ord_across_units() {
+        let km = Length::kilometers(1.0).total();
+        let m = Length::meters(1000.0).total();
+        assert_eq!(km, m);
+        assert_eq!(km.cmp(&m), Ordering::Equal);
+        assert!(Length::feet(3.0).total() < m);
+        assert_eq!(km.unit(), LengthUnit::Kilometers);
+        assert_eq!(km.into_inner(), Length::kilometers(1.0));
+    }
+
+    #[test]
+    fn test_nan_and_signed_zero() {
+        let nan = Length::meters(f64::NAN).total();
+        assert_eq!(nan, nan);
+        assert!(nan > Length::meters(f64::INFINITY).total());
+        assert!(Length::meters(-0.0).total() < Length::meters(0.0).total());
+    }
+
+    #[test]
+    fn test_collections() {
+        let set: HashSet<_> = [
+            Length::kilometers(1.0),
+            Length::meters(1000.0),
+            Length::feet(1.0),
+        ]
+        .into_iter()
+        .map(OrderedQuantity::new)
+        .collect();
+        assert_eq!(set.len(), 2);
+
+        let set: BTreeSet<_> = [Length::meters(2.0), Length::inches(1.0), Length::feet(1.0)]
+            .into_iter()
+            .map(|length| length.total())
+            .collect();
+        let sorted: Vec<_> = set.into_iter().map(OrderedQuantity::into_inner).collect();
+        assert_eq!(
+            sorted,
+            [Length::inches(1.0), Length::feet(1.0), Length::meters(2.0)]
+        );
+    }
+
+    #[test]
+    fn test_sort_is_stable() {
+        let mut lengths = [
+            Length::meters(1000.0),
+            Length::feet(1.0),
+            Length::kilometers(1.0),
+        ];
+        sort_quantities(&mut lengths);
+        assert_eq!(lengths[0].unit(), LengthUnit::Feet);
+        assert_eq!(lengths[1].unit(), LengthUnit::Meters);
+        assert_eq!(lengths[2].unit(), LengthUnit::Kilometers);
+    }
+
+    #[test]
+    fn test_max_min() {
+        let lengths = [Length::feet(4.0), Length::meters(1.0), Length::inches(30.0)];
+        assert_eq!(max_quantity(lengths), Some(Length::feet(4.0)));
+        assert_eq!(min_quantity(lengths), Some(Length::inches(30.0)));
+        assert_eq!(max_quantity(Vec::<Length>::new()), None);
+        assert_eq!(
+            max_quantity(lengths.iter().copied().map(|l| l * 2.0)),
+            Some(Length::feet(8.0))
+        );
+    }
+}
@@ -1,8 +1,12 @@
 //! Core quantity trait and implementations.
 
+use super::best_unit::{self, UnitPolicy};
+use super::format::QuantityFormatter;
+use super::ordered::OrderedQuantity;
+use super::scalar::Scalar;
 use super::unit::UnitOfMeasure;
-use std::cmp::Ordering;
-use std::fmt::{Debug, Display};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display};
 
 /// Core trait for all measurable quantities.
 ///
@@ -11,6 +15,11 @@ use std::fmt::{Debug, Display};
 ///
 /// # Type Parameters
 ///
+/// `T` is the [`Scalar`] type that stores the value and defaults to `f64`, so
+/// `Q: Quantity` means a quantity stored as `f64`. Generated quantities
+/// implement `Quantity<T>` for every scalar type (e.g. `Length<f32>:
+/// Quantity<f32>`).
+///
 /// Implementing types should be self-referential (e.g., `Length: Quantity<Unit = LengthUnit>`)
 /// to ensure type safety when performing operations.
 ///
@@ -69,22 +78,24 @@ use std::fmt::{Debug, Display};
 ///     }
 /// }
 /// ```
-pub trait Quantity: Clone + Copy + Debug + Display + PartialEq + PartialOrd {
+pub trait Quantity<T: Scalar = f64>:
+    Clone + Copy + Debug + Display + PartialEq + PartialOrd
+{
     /// The unit type associated with this quantity.
     type Unit: UnitOfMeasure;
 
     /// Creates a new quantity with the given value and unit.
-    fn new(value: f64, unit: Self::Unit) -> Self;
+    fn new(value: T, unit: Self::Unit) -> Self;
 
     /// Returns the numeric value of this quantity.
-    fn value(&self) -> f64;
+    fn value(&self) -> T;
 
     /// Returns the unit of this quantity.
     fn unit(&self) -> Self::Unit;
 
     /// Returns the value converted to the primary unit.
-    fn to_primary(&self) -> f64 {
-        self.unit().convert_to_primary(self.value())
+    fn to_primary(&self) -> T {
+        self.unit().scalar_to_primary(self.value())
     }
 
     /// Converts this quantity to a value in the specified unit.
@@ -98,11 +109,11 @@ pub trait Quantity: Clone + Copy + Debug + Display + PartialEq + PartialOrd {
     /// let length = Length::meters(1000.0);
     /// assert_eq!(length.to(LengthUnit::Kilometers), 1.0);
     /// ```
-    fn to(&self, target_unit: Self::Unit) -> f64 {
+    fn to(&self, target_unit: Self::Unit) -> T {
         if self.unit() == target_unit {
             self.value()
         } else {
-            self.unit().convert_to(self.value(), &target_unit)
+            self.unit().scalar_convert_to(self.value(), &target_unit)
         }
     }
 
@@ -155,7 +166,7 @@ pub trait Quantity: Clone + Copy + Debug + Display + PartialEq + PartialOrd {
     /// Applies a function to the underlying value, returning a new quantity.
     fn map<F>(&self, f: F) -> Self
     where
-        F: FnOnce(f64) -> f64,
+        F: FnOnce(T) -> T,
     {
         Self::new(f(self.value()), self.unit())
     }
@@ -163,13 +174,98 @@ pub trait Quantity: Clone + Copy + Debug + Display + PartialEq + PartialOrd {
     /// Compares this quantity to another using a total ordering.
     ///
     /// Quantities are compared by their values in the primary unit. NaN values
-    /// are ordered according to [`f64::total_cmp`].
+    /// are ordered according to [`Scalar::total_cmp`].
     fn compare(&self, other: &Self) -> Ordering {
         let self_primary = self.to_primary();
         let other_primary = other.to_primary();
         self_primary.total_cmp(&other_primary)
     }
 
+    /// Wraps this quantity in an [`OrderedQuantity`], which implements `Ord`,
+    /// `Eq` and `Hash` using [`compare`](Self::compare).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    ///
+    /// let mut lengths = vec![Length::feet(4.0), Length::meters(1.0)];
+    /// lengths.sort_by_key(|length| length.total());
+    /// assert_eq!(lengths[0], Length::meters(1.0));
+    /// ```
+    fn total(&self) -> OrderedQuantity<Self, T> {
+        OrderedQuantity::new(*self)
+    }
+
+    /// Returns a [`QuantityFormatter`] for writing this quantity with
+    /// significant figures, engineering notation, long unit names and other
+    /// options.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    ///
+    /// let length = Length::kilometers(1.23456);
+    /// assert_eq!(format!("{}", length.display().sig_figs(3).long_names()), "1.23 kilometers");
+    /// ```
+    fn display(&self) -> QuantityFormatter<Self, T> {
+        QuantityFormatter::new(*self)
+    }
+
+    /// Returns this quantity in its most readable unit: the largest unit of
+    /// its unit's [family](UnitOfMeasure::family) in which its magnitude is
+    /// at least one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    ///
+    /// let size = Information::bytes(3_221_225_472.0).to_best_unit();
+    /// assert_eq!(size.unit(), InformationUnit::Gibibytes);
+    /// assert_eq!(Length::feet(7920.0).to_best_unit(), Length::miles(1.5));
+    /// ```
+    fn to_best_unit(&self) -> Self
+    where
+        Self::Unit: 'static,
+    {
+        self.to_best_unit_with(UnitPolicy::default())
+    }
+
+    /// Returns this quantity in the most readable unit allowed by `policy`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    ///
+    /// let height = Length::centimeters(180.0);
+    /// let imperial = height.to_best_unit_with(UnitPolicy::only(LengthUnit::IMPERIAL));
+    /// assert_eq!(imperial.unit(), LengthUnit::Feet);
+    /// ```
+    fn to_best_unit_with(&self, policy: UnitPolicy<Self::Unit>) -> Self {
+        self.in_unit(best_unit::best_unit(self, policy))
+    }
+
+    /// Formats this quantity in its [best unit](Self::to_best_unit), rounded
+    /// to three significant figures without trailing zeros.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    ///
+    /// assert_eq!(Time::seconds(93784.0).display_auto().to_string(), "1.09 d");
+    /// assert_eq!(Information::bytes(3_221_225_472.0).display_auto().to_string(), "3 GiB");
+    /// ```
+    fn display_auto(&self) -> QuantityFormatter<Self, T>
+    where
+        Self::Unit: 'static,
+    {
+        self.to_best_unit().display().sig_figs(3).trim_zeros()
+    }
+
     /// Returns the maximum of this quantity and another.
     fn max(&self, other: Self) -> Self {
         if self.compare(&other) == Ordering::Greater {
@@ -189,12 +285,12 @@ pub trait Quantity: Clone + Copy + Debug + Display + PartialEq + PartialOrd {
     }
 
     /// Returns a tuple of (value, symbol).
-    fn to_tuple(&self) -> (f64, &'static str) {
+    fn to_tuple(&self) -> (T, &'static str) {
         (self.value(), self.unit().symbol())
     }
 
     /// Returns a tuple of (value, symbol) in the specified unit.
-    fn to_tuple_in(&self, unit: Self::Unit) -> (f64, &'static str) {
+    fn to_tuple_in(&self, unit: Self::Unit) -> (T, &'static str) {
         (self.to(unit), unit.symbol())
     }
 }
@@ -244,8 +340,8 @@ mod tests {
         Kilo,
     }
 
-    impl std::fmt::Display for TestUnit {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    impl core::fmt::Display for TestUnit {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
             write!(f, "{}", self.symbol())
         }
     }
@@ -266,8 +362,8 @@ mod tests {
         }
     }
 
-    impl std::fmt::Display for TestQuantity {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    impl core::fmt::Display for TestQuantity {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
             write!(f, "{} {}", self.value, self.unit.symbol())
         }
     }
@@ -4,12 +4,14 @@
 //! iteration, and range manipulation.
 
 use crate::core::{Quantity, UnitOfMeasure};
-use std::fmt;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::fmt;
 
 /// Error type for quantity range operations.
 #[derive(Debug, Clone, PartialEq)]
 pub struct QuantityRangeError {
-    message: String,
+    message: &'static str,
 }
 
 impl fmt::Display for QuantityRangeError {
@@ -18,7 +20,7 @@ impl fmt::Display for QuantityRangeError {
     }
 }
 
-impl std::error::Error for QuantityRangeError {}
+impl core::error::Error for QuantityRangeError {}
 
 /// A range of quantities from a lower bound to an upper bound.
 ///
@@ -52,8 +54,7 @@ impl<Q: Quantity> QuantityRange<Q> {
     pub fn new(lower: Q, upper: Q) -> Result<Self, QuantityRangeError> {
         if lower.to_primary() >= upper.to_primary() {
             return Err(QuantityRangeError {
-                message: "QuantityRange upper bound must be strictly greater than the lower bound"
-                    .to_string(),
+                message: "QuantityRange upper bound must be strictly greater than the lower bound",
             });
         }
         Ok(Self { lower, upper })
@@ -204,6 +205,7 @@ impl<Q: Quantity> QuantityRange<Q> {
     /// Divides the range into n equal parts.
     ///
     /// Returns a Vec of QuantityRanges.
+    #[cfg(feature = "alloc")]
     pub fn divide(&self, n: usize) -> Vec<QuantityRange<Q>> {
         if n == 0 {
             return vec![];
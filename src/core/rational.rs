@@ -0,0 +1,410 @@
+//! Exact rational numbers for unit conversion factors.
+//!
+//! Most units are defined as an exact multiple of another unit: the inch is
+//! exactly 0.0254 m, the pound exactly 0.45359237 kg and the thermochemical
+//! calorie exactly 4.184 J. Written as `f64` those factors are already
+//! rounded, and chaining them (`FEET_TO_METERS / 12.0`) rounds again, so
+//! converting inches to centimeters through meters can be off by an ulp.
+//!
+//! A [`Rational`] keeps such a factor exact. Converting between two units
+//! with exact factors divides one rational by the other first and only then
+//! applies the combined ratio to the value.
+
+use crate::core::Scalar;
+use core::fmt;
+use core::ops::{Div, Mul};
+
+/// Largest integer below which every integer is exactly representable as `f64`.
+const MAX_EXACT_F64_INTEGER: u128 = 1 << f64::MANTISSA_DIGITS;
+
+/// A non-negative rational number in lowest terms.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::core::Rational;
+///
+/// let inch = Rational::new(254, 10_000);
+/// assert_eq!(inch, Rational::new(127, 5000));
+///
+/// let foot = inch * Rational::integer(12);
+/// assert_eq!(foot, Rational::new(381, 1250));
+/// assert_eq!(foot.to_f64(), 0.3048);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: u128,
+    denominator: u128,
+}
+
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+impl Rational {
+    /// The rational number one.
+    pub const ONE: Rational = Rational {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Creates a rational number, reducing it to lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub const fn new(numerator: u128, denominator: u128) -> Self {
+        assert!(denominator != 0, "rational denominator must not be zero");
+        let divisor = gcd(numerator, denominator);
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    /// Creates a rational number equal to an integer.
+    pub const fn integer(value: u128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    /// Creates the rational number `10^exponent`, such as an SI prefix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `|exponent| > 38`, which does not fit in a `u128`.
+    pub const fn pow10(exponent: i32) -> Self {
+        assert!(exponent.abs() <= 38, "power of ten out of range");
+        let mut power: u128 = 1;
+        let mut i = 0;
+        while i < exponent.abs() {
+            power *= 10;
+            i += 1;
+        }
+        if exponent < 0 {
+            Self::new(1, power)
+        } else {
+            Self::integer(power)
+        }
+    }
+
+    /// Returns the numerator in lowest terms.
+    pub const fn numerator(&self) -> u128 {
+        self.numerator
+    }
+
+    /// Returns the denominator in lowest terms.
+    pub const fn denominator(&self) -> u128 {
+        self.denominator
+    }
+
+    /// Multiplies two rationals, returning `None` on overflow.
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cross-reduce first so that products of factors in lowest terms
+        // only overflow when the result itself does not fit.
+        let a = gcd(self.numerator, rhs.denominator);
+        let b = gcd(rhs.numerator, self.denominator);
+        let (a, b) = (if a == 0 { 1 } else { a }, if b == 0 { 1 } else { b });
+        let numerator = match (self.numerator / a).checked_mul(rhs.numerator / b) {
+            Some(n) => n,
+            None => return None,
+        };
+        let denominator = match (self.denominator / b).checked_mul(rhs.denominator / a) {
+            Some(d) => d,
+            None => return None,
+        };
+        Some(Self {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Divides two rationals, returning `None` on overflow or division by zero.
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.numerator == 0 {
+            return None;
+        }
+        self.checked_mul(Self {
+            numerator: rhs.denominator,
+            denominator: rhs.numerator,
+        })
+    }
+
+    /// Multiplies two rationals; usable in constants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    #[allow(clippy::should_implement_trait)]
+    pub const fn mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(product) => product,
+            None => panic!("rational multiplication overflowed"),
+        }
+    }
+
+    /// Divides two rationals; usable in constants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows or `rhs` is zero.
+    #[allow(clippy::should_implement_trait)]
+    pub const fn div(self, rhs: Self) -> Self {
+        match self.checked_div(rhs) {
+            Some(quotient) => quotient,
+            None => panic!("rational division overflowed"),
+        }
+    }
+
+    /// Returns the reciprocal, or `None` for zero.
+    pub const fn recip(self) -> Option<Self> {
+        Self::ONE.checked_div(self)
+    }
+
+    /// Returns the nearest `f64`.
+    ///
+    /// The result is correctly rounded when both numerator and denominator
+    /// are exactly representable, which is the case for every factor in
+    /// this crate.
+    pub const fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    /// Multiplies `value` by this rational.
+    ///
+    /// The product with the numerator is computed exactly and the quotient
+    /// by the denominator is corrected for its rounding error, so the result
+    /// is the correctly rounded value of `value * n / d` rather than `value`
+    /// times an already rounded factor.
+    pub fn apply<T: Scalar>(self, value: T) -> T {
+        T::from_f64(self.apply_f64(value.to_f64()))
+    }
+
+    /// Multiplies an `f64` by this rational like [`apply`](Self::apply);
+    /// usable in constants.
+    pub const fn apply_f64(self, x: f64) -> f64 {
+        if self.denominator == 1 {
+            return x * self.numerator as f64;
+        }
+        if self.numerator > MAX_EXACT_F64_INTEGER
+            || self.denominator > MAX_EXACT_F64_INTEGER
+            || !(x > -MAX_SPLIT && x < MAX_SPLIT)
+        {
+            return x * self.to_f64();
+        }
+        let (n, d) = (self.numerator as f64, self.denominator as f64);
+        let (product, error) = two_product(x, n);
+        if !product.is_finite() || product == 0.0 {
+            return x * self.to_f64();
+        }
+        // `product + error` is exactly `x * n`, and `remainder` is exactly
+        // `product - quotient * d`.
+        let quotient = product / d;
+        let (scaled, scaled_error) = two_product(quotient, d);
+        let remainder = (product - scaled) - scaled_error;
+        quotient + (remainder + error) / d
+    }
+}
+
+/// Largest magnitude that [`split`] can take without overflowing.
+pub(crate) const MAX_SPLIT: f64 = 1e290;
+
+/// Splits `a` into a high part with at most 26 significant bits and an
+/// exact low part (Veltkamp's algorithm).
+const fn split(a: f64) -> (f64, f64) {
+    let t = 134_217_729.0 * a;
+    let high = t - (t - a);
+    (high, a - high)
+}
+
+/// Returns `a * b` rounded and its exact rounding error (Dekker's
+/// algorithm), which a fused multiply-add would give directly but not in a
+/// constant.
+pub(crate) const fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = ((a_high * b_high - product) + a_high * b_low + a_low * b_high) + a_low * b_low;
+    (product, error)
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Rational {
+        Rational::mul(self, rhs)
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: Rational) -> Rational {
+        Rational::div(self, rhs)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// A value usable as the `factor` of a unit in [`quantity!`](crate::quantity).
+///
+/// Factors written as `f64` are exact only when they are whole numbers
+/// (such as `1.0`, `60.0` or `1e3`); write a [`Rational`] for any other
+/// factor that is defined exactly.
+pub trait ConversionFactor: Copy {
+    /// Returns the factor as the nearest `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Returns the factor as an exact rational, if it is one.
+    fn to_rational(self) -> Option<Rational>;
+}
+
+impl ConversionFactor for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn to_rational(self) -> Option<Rational> {
+        ConstFactor(self).to_rational()
+    }
+}
+
+impl ConversionFactor for Rational {
+    fn to_f64(self) -> f64 {
+        Rational::to_f64(self)
+    }
+
+    fn to_rational(self) -> Option<Rational> {
+        Some(self)
+    }
+}
+
+/// A unit's `factor`, read in a constant by [`quantity!`](crate::quantity)
+/// whether it is an `f64` or a [`Rational`]. Not part of the public API.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct ConstFactor<F>(pub F);
+
+impl ConstFactor<f64> {
+    /// Returns the factor.
+    pub const fn to_f64(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the factor as a rational if it is a whole number.
+    pub const fn to_rational(self) -> Option<Rational> {
+        let value = self.0;
+        if value >= 1.0 && value <= MAX_EXACT_F64_INTEGER as f64 && value as u128 as f64 == value {
+            Some(Rational::integer(value as u128))
+        } else {
+            None
+        }
+    }
+}
+
+impl ConstFactor<Rational> {
+    /// Returns the factor as the nearest `f64`.
+    pub const fn to_f64(self) -> f64 {
+        self.0.to_f64()
+    }
+
+    /// Returns the factor.
+    pub const fn to_rational(self) -> Option<Rational> {
+        Some(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_reduces() {
+        let r = Rational::new(254, 10_000);
+        assert_eq!(r.numerator(), 127);
+        assert_eq!(r.denominator(), 5000);
+        assert_eq!(Rational::new(6, 3), Rational::integer(2));
+    }
+
+    #[test]
+    fn test_pow10() {
+        assert_eq!(Rational::pow10(0), Rational::ONE);
+        assert_eq!(Rational::pow10(3), Rational::integer(1000));
+        assert_eq!(Rational::pow10(-2), Rational::new(1, 100));
+        assert_eq!(Rational::pow10(-30).denominator(), 10u128.pow(30));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let inch = Rational::new(127, 5000);
+        let foot = inch * Rational::integer(12);
+        assert_eq!(foot, Rational::new(381, 1250));
+        assert_eq!(foot / inch, Rational::integer(12));
+        assert_eq!(inch.recip(), Some(Rational::new(5000, 127)));
+        assert_eq!(Rational::integer(0).recip(), None);
+        assert_eq!(
+            Rational::integer(u128::MAX).checked_mul(Rational::integer(2)),
+            None
+        );
+        // Cross-reduction avoids spurious overflow
+        let big = Rational::new(1, u128::MAX);
+        assert_eq!(
+            big.checked_mul(Rational::integer(u128::MAX)),
+            Some(Rational::ONE)
+        );
+    }
+
+    #[test]
+    fn test_const_evaluation() {
+        const FOOT: Rational = Rational::new(127, 5000).mul(Rational::integer(12));
+        const PER_HOUR: Rational = Rational::ONE.div(Rational::integer(3600));
+        assert_eq!(FOOT.to_f64(), 0.3048);
+        assert_eq!(PER_HOUR, Rational::new(1, 3600));
+    }
+
+    #[test]
+    fn test_apply_is_correctly_rounded() {
+        let inch = Rational::new(127, 5000);
+        assert_eq!(inch.apply(1.0), 0.0254);
+        assert_eq!(inch.apply(12.0), 0.3048);
+        let third = Rational::new(1, 3);
+        assert_eq!(third.apply(1.0), 1.0 / 3.0);
+        assert_eq!(third.apply(3.0), 1.0);
+        assert_eq!(third.apply(1.0f32), 1.0f32 / 3.0);
+        assert_eq!(Rational::integer(1000).apply(2.5), 2500.0);
+        assert!(Rational::new(3, 2).apply(f64::MAX).is_infinite());
+    }
+
+    #[test]
+    fn test_conversion_factor() {
+        assert_eq!(ConversionFactor::to_rational(1.0), Some(Rational::ONE));
+        assert_eq!(
+            ConversionFactor::to_rational(3600.0),
+            Some(Rational::integer(3600))
+        );
+        assert_eq!(ConversionFactor::to_rational(0.3048), None);
+        assert_eq!(ConversionFactor::to_rational(1e23), None);
+        assert_eq!(ConversionFactor::to_f64(Rational::new(1, 4)), 0.25);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Rational::new(127, 5000)), "127/5000");
+        assert_eq!(format!("{}", Rational::integer(12)), "12");
+    }
+}
@@ -0,0 +1,198 @@
+//! Numeric storage types for quantities.
+//!
+//! Every quantity generated by [`quantity!`](crate::quantity), as well as
+//! [`Dimensionless`](crate::core::Dimensionless), is generic over the type
+//! that stores its value, with `f64` as the default. Named constructors such
+//! as `Length::meters` and the DSL traits always produce `f64` quantities, so
+//! a float literal never leaves the scalar type to inference. Quantities with
+//! another scalar type are created with [`Quantity::new`](crate::Quantity::new)
+//! or converted with `cast`; the `to_*` methods, arithmetic and
+//! cross-quantity operators work for any scalar type:
+//!
+//! ```rust
+//! use rquants::prelude::*;
+//!
+//! let distance = Length::<f32>::new(2.0, LengthUnit::Kilometers);
+//! let time = Time::seconds(100.0).cast::<f32>();
+//!
+//! let speed: Velocity<f32> = distance / time;
+//! assert_eq!(speed.to_meters_per_second(), 20.0_f32);
+//! assert_eq!(std::mem::size_of::<Velocity<f32>>(), 8);
+//! ```
+//!
+//! A quantity stores a single value of its scalar type next to its unit, so
+//! an `f32` quantity takes half the memory of an `f64` one.
+//!
+//! Unit conversion factors are defined as `f64` or exact
+//! [`Rational`](crate::core::Rational)s. Unit conversions are computed in
+//! `f64` and rounded once to the storage type with [`Scalar::from_f64`], so
+//! a unit only defines its `f64` conversions. Parsing, the
+//! [`Dimension`](crate::Dimension) metadata and runtime-dimensioned
+//! arithmetic through [`DynQuantity`](crate::core::DynQuantity) always work
+//! in `f64`; cross-quantity operators compute in the storage type.
+
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::math;
+
+/// A numeric type that can store the value of a quantity.
+///
+/// Implemented for `f64` and `f32`. Other types (e.g. fixed-point numbers)
+/// can implement it to be used as quantity storage.
+pub trait Scalar:
+    Copy
+    + Debug
+    + Display
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+    + 'static
+{
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Converts from `f64`, rounding if necessary.
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts to `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+
+    /// Returns the smallest integer greater than or equal to the value.
+    fn ceil(self) -> Self;
+
+    /// Returns the largest integer less than or equal to the value.
+    fn floor(self) -> Self;
+
+    /// Returns the nearest integer, rounding half-way cases away from zero.
+    fn round(self) -> Self;
+
+    /// Returns a total ordering between two values, including NaN.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Formats the value in scientific notation like [`fmt::LowerExp`],
+    /// honoring the precision and sign flags of `f`.
+    ///
+    /// The default formats the value converted to `f64`.
+    fn fmt_exp(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.to_f64(), f)
+    }
+}
+
+macro_rules! float_scalar {
+    ($($float:ident),+) => {
+        $(
+            impl Scalar for $float {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+
+                fn from_f64(value: f64) -> Self {
+                    value as $float
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn abs(self) -> Self {
+                    math::abs(self)
+                }
+
+                fn ceil(self) -> Self {
+                    math::ceil(self)
+                }
+
+                fn floor(self) -> Self {
+                    math::floor(self)
+                }
+
+                fn round(self) -> Self {
+                    math::round(self)
+                }
+
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    $float::total_cmp(self, other)
+                }
+
+                fn fmt_exp(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::LowerExp::fmt(self, f)
+                }
+            }
+        )+
+    };
+}
+
+float_scalar!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f32_round_trip() {
+        assert_eq!(f32::from_f64(0.1), 0.1_f32);
+        assert_eq!(<f32 as Scalar>::to_f64(0.5), 0.5);
+        assert_eq!(<f32 as Scalar>::ZERO + <f32 as Scalar>::ONE, 1.0);
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(Scalar::round(-2.5_f32), -3.0);
+        assert_eq!(Scalar::ceil(1.2_f64), 2.0);
+        assert_eq!(Scalar::floor(-1.2_f32), -2.0);
+        assert_eq!(Scalar::total_cmp(&f64::NAN, &1.0), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_f32_quantities() {
+        use crate::core::{Dimensionless, DimensionlessUnit, DynQuantity, Quantity};
+        use crate::energy::{Energy, Power};
+        use crate::space::{Length, LengthUnit};
+        use crate::time::{Time, TimeUnit};
+
+        let a = Length::<f32>::new(1.5, LengthUnit::Meters);
+        let b = Length::<f32>::new(50.0, LengthUnit::Centimeters);
+        assert_eq!((a + b).to_meters(), 2.0_f32);
+        assert_eq!((a - b).to_centimeters(), 100.0_f32);
+        assert_eq!((a * 2.0).value(), 3.0_f32);
+        assert_eq!((2.0_f32 * a).value(), 3.0_f32);
+        assert_eq!(a / b, 3.0_f32);
+        assert!(b < a);
+        assert_eq!([a, b].iter().sum::<Length<f32>>().to_meters(), 2.0_f32);
+
+        let power = Power::kilowatts(2.0).cast::<f32>();
+        let energy: Energy<f32> = power * Time::<f32>::new(30.0, TimeUnit::Minutes);
+        assert_eq!(energy.to_kilowatt_hours(), 1.0_f32);
+
+        let ratio = Dimensionless::<f32>::new(50.0, DimensionlessUnit::Percent);
+        assert_eq!(ratio.to_each(), 0.5_f32);
+        assert_eq!(DynQuantity::from(a).value(), 1.5);
+        assert_eq!(a.cast::<f64>(), Length::meters(1.5));
+    }
+
+    #[test]
+    fn test_storage_size() {
+        use crate::space::Length;
+
+        // A quantity stores one scalar and its unit, so `f32` halves it
+        assert_eq!(core::mem::size_of::<Length<f32>>(), 8);
+        assert_eq!(core::mem::size_of::<Length>(), 16);
+    }
+}
@@ -1,12 +1,25 @@
 //! Unit of measure trait and supporting types.
 
-use std::fmt::{Debug, Display};
-use std::hash::Hash;
+use super::rational::Rational;
+use super::scalar::Scalar;
+use core::fmt::{self, Debug, Display, Write};
+use core::hash::Hash;
 
 /// Trait for units of measurement.
 ///
 /// A unit of measure defines a standard for measuring a particular quantity.
 /// Each unit has a symbol and a conversion factor relative to the primary unit.
+/// Units whose zero does not coincide with the primary unit's zero (such as
+/// temperature scales) also have a [conversion offset](Self::conversion_offset),
+/// and units that are not proportional to the primary unit at all (such as
+/// wire gauges or fuel consumption in miles per gallon) override the
+/// [`convert_to_primary`](Self::convert_to_primary) and
+/// [`convert_from_primary`](Self::convert_from_primary) pair.
+///
+/// Units defined as an exact multiple of the primary unit, like the inch
+/// (exactly 0.0254 m), can also report their [exact factor](Self::exact_factor)
+/// as a [`Rational`]; conversions between two such units then combine the
+/// factors exactly and round only once.
 ///
 /// # Example
 ///
@@ -50,6 +63,22 @@ pub trait UnitOfMeasure: Debug + Clone + Copy + PartialEq + Eq + Hash + Display
     /// Returns the symbol for this unit (e.g., "m", "kg", "s").
     fn symbol(&self) -> &'static str;
 
+    /// Returns the long name of this unit, such as "kilometers per hour".
+    ///
+    /// The default uses the symbol for both the singular and the plural.
+    fn name(&self) -> UnitName {
+        UnitName::new(self.symbol()).with_singular(self.symbol())
+    }
+
+    /// Returns other spellings of this unit accepted when parsing, such as
+    /// "metre" or "km/hr".
+    ///
+    /// The symbol and the long name need not be repeated here. The default
+    /// returns an empty slice.
+    fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+
     /// Returns the conversion factor relative to the primary unit.
     ///
     /// The primary unit has a conversion factor of 1.0.
@@ -59,9 +88,42 @@ pub trait UnitOfMeasure: Debug + Clone + Copy + PartialEq + Eq + Hash + Display
     /// - Centimeters: 0.01
     fn conversion_factor(&self) -> f64;
 
+    /// Returns the value, in the primary unit, of zero in this unit.
+    ///
+    /// Conversions to the primary unit compute `value * factor + offset`.
+    /// The offset is zero for almost every unit; for temperature scales with
+    /// Kelvin as the primary unit, Celsius has an offset of 273.15.
+    fn conversion_offset(&self) -> f64 {
+        0.0
+    }
+
+    /// Returns the conversion factor as an exact rational, if it is defined
+    /// exactly.
+    ///
+    /// The default returns `None`, in which case conversions use
+    /// [`conversion_factor`](Self::conversion_factor).
+    fn exact_factor(&self) -> Option<Rational> {
+        None
+    }
+
+    /// Returns true if the conversion factor is known exactly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::space::LengthUnit;
+    /// use rquants::UnitOfMeasure;
+    ///
+    /// assert!(LengthUnit::Inches.is_exact());
+    /// assert!(!LengthUnit::Parsecs.is_exact());
+    /// ```
+    fn is_exact(&self) -> bool {
+        self.exact_factor().is_some()
+    }
+
     /// Returns true if this is the primary (base) unit for its dimension.
     fn is_primary(&self) -> bool {
-        (self.conversion_factor() - 1.0).abs() < f64::EPSILON
+        (self.conversion_factor() - 1.0).abs() < f64::EPSILON && self.conversion_offset() == 0.0
     }
 
     /// Returns true if this is an SI unit.
@@ -69,8 +131,38 @@ pub trait UnitOfMeasure: Debug + Clone + Copy + PartialEq + Eq + Hash + Display
         false
     }
 
+    /// Returns true if converting to the primary unit only scales the value.
+    ///
+    /// Arithmetic on a quantity in a linear unit works on its stored value;
+    /// units with an offset or with their own conversion functions convert to
+    /// the primary unit first. Units that override the conversion functions
+    /// must also override this method to return `false`.
+    fn is_linear(&self) -> bool {
+        self.conversion_offset() == 0.0
+    }
+
+    /// Returns every unit of this dimension.
+    ///
+    /// The default returns an empty slice.
+    fn all_units() -> &'static [Self] {
+        &[]
+    }
+
+    /// Returns the group of units, such as the metric or the imperial units
+    /// of length, that [`Quantity::to_best_unit`](crate::Quantity::to_best_unit)
+    /// rescales a quantity in this unit within.
+    ///
+    /// The default returns an empty slice: SI units are then rescaled among
+    /// all SI units of the dimension and other units are left alone.
+    fn family(&self) -> &'static [Self] {
+        &[]
+    }
+
     /// Converts a value from the primary unit to this unit.
     ///
+    /// This is the inverse of [`convert_to_primary`](Self::convert_to_primary);
+    /// override both to model a non-affine unit.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -82,11 +174,18 @@ pub trait UnitOfMeasure: Debug + Clone + Copy + PartialEq + Eq + Hash + Display
     /// assert_eq!(km.convert_from_primary(1000.0), 1.0);
     /// ```
     fn convert_from_primary(&self, value: f64) -> f64 {
-        value / self.conversion_factor()
+        let value = value - self.conversion_offset();
+        match self.exact_factor().and_then(Rational::recip) {
+            Some(reciprocal) => reciprocal.apply_f64(value),
+            None => value / self.conversion_factor(),
+        }
     }
 
     /// Converts a value from this unit to the primary unit.
     ///
+    /// The default computes `value * factor + offset`, using the exact
+    /// factor when there is one.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -98,13 +197,404 @@ pub trait UnitOfMeasure: Debug + Clone + Copy + PartialEq + Eq + Hash + Display
     /// assert_eq!(km.convert_to_primary(1.0), 1000.0);
     /// ```
     fn convert_to_primary(&self, value: f64) -> f64 {
-        value * self.conversion_factor()
+        let scaled = match self.exact_factor() {
+            Some(factor) => factor.apply_f64(value),
+            None => value * self.conversion_factor(),
+        };
+        scaled + self.conversion_offset()
     }
 
     /// Converts a value from this unit to another unit.
+    ///
+    /// When both units are linear and exact, the two factors are combined
+    /// into a single rational before it is applied to the value, so the
+    /// result is correctly rounded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::space::LengthUnit;
+    /// use rquants::UnitOfMeasure;
+    ///
+    /// // An inch is exactly 2.54 cm and a mile exactly 5280 ft
+    /// let inch = LengthUnit::Inches;
+    /// assert_eq!(inch.convert_to(1.0, &LengthUnit::Centimeters), 2.54);
+    /// assert_eq!(LengthUnit::Miles.convert_to(1.0, &LengthUnit::Feet), 5280.0);
+    /// assert_eq!(LengthUnit::Feet.convert_to(1.0, &inch), 12.0);
+    /// ```
     fn convert_to(&self, value: f64, target: &Self) -> f64 {
+        if self.is_linear() && target.is_linear() {
+            if let (Some(from), Some(to)) = (self.exact_factor(), target.exact_factor()) {
+                if let Some(ratio) = from.checked_div(to) {
+                    return ratio.apply_f64(value);
+                }
+            }
+        }
         target.convert_from_primary(self.convert_to_primary(value))
     }
+
+    /// Converts a value of any [`Scalar`] type from the primary unit to this
+    /// unit, through [`convert_from_primary`](Self::convert_from_primary) in
+    /// `f64`.
+    fn scalar_from_primary<T: Scalar>(&self, value: T) -> T {
+        T::from_f64(self.convert_from_primary(value.to_f64()))
+    }
+
+    /// Converts a value of any [`Scalar`] type from this unit to the primary
+    /// unit, through [`convert_to_primary`](Self::convert_to_primary) in
+    /// `f64`.
+    fn scalar_to_primary<T: Scalar>(&self, value: T) -> T {
+        T::from_f64(self.convert_to_primary(value.to_f64()))
+    }
+
+    /// Converts a value of any [`Scalar`] type from this unit to another
+    /// unit, through [`convert_to`](Self::convert_to) in `f64`.
+    fn scalar_convert_to<T: Scalar>(&self, value: T, target: &Self) -> T {
+        T::from_f64(self.convert_to(value.to_f64(), target))
+    }
+}
+
+/// How a unit converts to its primary unit, worked out once per unit.
+///
+/// [`quantity!`](crate::quantity) builds one for each unit in a constant, so
+/// a conversion does not look up the unit's factor, offset and conversion
+/// functions every time. Not part of the public API.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub enum Conversion {
+    /// `value * factor + offset` with an exact factor.
+    Exact {
+        /// The factor.
+        factor: Rational,
+        /// The reciprocal of the factor, for converting back.
+        reciprocal: Rational,
+        /// The value, in the primary unit, of zero in the unit.
+        offset: f64,
+    },
+    /// `value * factor + offset` with a rounded factor.
+    Rounded {
+        /// The factor.
+        factor: f64,
+        /// The value, in the primary unit, of zero in the unit.
+        offset: f64,
+    },
+    /// Functions converting a value to and from the primary unit.
+    Functions(fn(f64) -> f64, fn(f64) -> f64),
+}
+
+impl Conversion {
+    /// Creates the conversion of a unit with a factor and an offset.
+    pub const fn scaled(factor: f64, exact: Option<Rational>, offset: f64) -> Self {
+        if let Some(exact) = exact {
+            if let Some(reciprocal) = exact.recip() {
+                return Conversion::Exact {
+                    factor: exact,
+                    reciprocal,
+                    offset,
+                };
+            }
+        }
+        Conversion::Rounded { factor, offset }
+    }
+
+    /// Returns the exact factor, if there is one.
+    pub const fn exact_factor(self) -> Option<Rational> {
+        match self {
+            Conversion::Exact { factor, .. } => Some(factor),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the conversion only scales the value.
+    pub const fn is_linear(self) -> bool {
+        match self {
+            Conversion::Exact { offset, .. } | Conversion::Rounded { offset, .. } => offset == 0.0,
+            Conversion::Functions(..) => false,
+        }
+    }
+
+    /// Converts a value to the primary unit.
+    pub fn to_primary(self, value: f64) -> f64 {
+        match self {
+            Conversion::Functions(to_primary, _) => to_primary(value),
+            _ => self.to_primary_const(value),
+        }
+    }
+
+    /// Converts a value to the primary unit in a constant.
+    ///
+    /// # Panics
+    ///
+    /// Panics for a unit with conversion functions, which cannot be called
+    /// in a constant.
+    pub const fn to_primary_const(self, value: f64) -> f64 {
+        let (scaled, offset) = match self {
+            Conversion::Exact { factor, offset, .. } => (factor.apply_f64(value), offset),
+            Conversion::Rounded { factor, offset } => (value * factor, offset),
+            Conversion::Functions(..) => {
+                panic!("a unit with conversion functions cannot be converted in a constant")
+            }
+        };
+        // Adding a zero offset would turn -0.0 into 0.0.
+        if offset == 0.0 {
+            scaled
+        } else {
+            scaled + offset
+        }
+    }
+
+    /// Converts a value from the primary unit.
+    pub fn from_primary(self, value: f64) -> f64 {
+        match self {
+            Conversion::Exact {
+                reciprocal, offset, ..
+            } => reciprocal.apply_f64(value - offset),
+            Conversion::Rounded { factor, offset } => (value - offset) / factor,
+            Conversion::Functions(_, from_primary) => from_primary(value),
+        }
+    }
+}
+
+/// Returns the value in `unit` of a quantity stored as `primary`, its value
+/// in the primary unit.
+///
+/// Converting a value to the primary unit and back can be off by an ulp or
+/// two. Of the values within two ulps of the converted one that convert to
+/// the same primary value, this returns the one with the shortest decimal
+/// form, so a quantity created as 30° reads back as 30 rather than
+/// 29.999999999999996. Not part of the public API.
+#[doc(hidden)]
+pub fn value_from_primary<U: UnitOfMeasure, T: Scalar>(unit: &U, primary: T) -> T {
+    let value = unit.convert_from_primary(primary.to_f64());
+    if !value.is_finite() {
+        return T::from_f64(value);
+    }
+    let (mut best, mut best_len) = (value, decimal_len(value));
+    let (down, up) = (value.next_down(), value.next_up());
+    for candidate in [down, up, down.next_down(), up.next_up()] {
+        let len = decimal_len(candidate);
+        if len < best_len && T::from_f64(unit.convert_to_primary(candidate)) == primary {
+            (best, best_len) = (candidate, len);
+        }
+    }
+    T::from_f64(best)
+}
+
+/// Returns the length of the shortest decimal form of `value` that reads
+/// back as `value`, in scientific notation.
+fn decimal_len(value: f64) -> usize {
+    struct Counter(usize);
+
+    impl Write for Counter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    let _ = write!(counter, "{value:e}");
+    counter.0
+}
+
+/// The long name of a unit, in its plural and singular forms.
+///
+/// Names are either written out, like "US gallons", or derived from the
+/// CamelCase identifier of a unit variant, so `KilometersPerHour` reads
+/// "kilometers per hour". Unless the singular is given explicitly, it is
+/// formed from the plural by singularizing the word before the first "per"
+/// or "of", or else the last word.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::core::UnitName;
+///
+/// let name = UnitName::from_identifier("InchesOfMercury");
+/// assert_eq!(name.to_string(), "inches of mercury");
+/// assert_eq!(name.singular().to_string(), "inch of mercury");
+///
+/// let name = UnitName::new("degrees Celsius").with_singular("degree Celsius");
+/// assert_eq!(name.singular().to_string(), "degree Celsius");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitName {
+    plural: &'static str,
+    singular: Option<&'static str>,
+    identifier: bool,
+}
+
+impl UnitName {
+    /// Creates a name from its written-out plural form.
+    pub const fn new(plural: &'static str) -> Self {
+        Self {
+            plural,
+            singular: None,
+            identifier: false,
+        }
+    }
+
+    /// Creates a name from a CamelCase identifier such as `KilometersPerHour`.
+    pub const fn from_identifier(identifier: &'static str) -> Self {
+        Self {
+            plural: identifier,
+            singular: None,
+            identifier: true,
+        }
+    }
+
+    /// Sets the written-out singular form.
+    pub const fn with_singular(self, singular: &'static str) -> Self {
+        Self {
+            singular: Some(singular),
+            ..self
+        }
+    }
+
+    /// Returns the singular form, used for a value of exactly one.
+    pub fn singular(self) -> impl Display {
+        SingularName(self)
+    }
+
+    fn words(self) -> Words {
+        Words {
+            rest: self.plural,
+            identifier: self.identifier,
+        }
+    }
+
+    // The index of the word that changes in the singular.
+    fn counted_word(self) -> usize {
+        let mut last = 0;
+        for (index, word) in self.words().enumerate() {
+            if index > 0 && (word.eq_ignore_ascii_case("per") || word.eq_ignore_ascii_case("of")) {
+                return index - 1;
+            }
+            // "kilogram meters squared" counts meters
+            if index > 0
+                && (word.eq_ignore_ascii_case("squared") || word.eq_ignore_ascii_case("cubed"))
+            {
+                last = index - 1;
+            } else {
+                last = index;
+            }
+        }
+        last
+    }
+
+    fn write(self, f: &mut fmt::Formatter<'_>, singular: bool) -> fmt::Result {
+        match self.singular {
+            Some(text) if singular => return f.write_str(text),
+            _ if !singular && !self.identifier => return f.write_str(self.plural),
+            _ => {}
+        }
+        let counted = if singular {
+            self.counted_word()
+        } else {
+            usize::MAX
+        };
+        for (index, word) in self.words().enumerate() {
+            if index > 0 {
+                f.write_char(' ')?;
+            }
+            let (stem, ending) = if index == counted {
+                singularize(word)
+            } else {
+                (word, "")
+            };
+            if self.identifier {
+                for c in stem.chars() {
+                    f.write_char(c.to_ascii_lowercase())?;
+                }
+            } else {
+                f.write_str(stem)?;
+            }
+            f.write_str(ending)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&'static str> for UnitName {
+    fn from(plural: &'static str) -> Self {
+        UnitName::new(plural)
+    }
+}
+
+impl From<(&'static str, &'static str)> for UnitName {
+    fn from((plural, singular): (&'static str, &'static str)) -> Self {
+        UnitName::new(plural).with_singular(singular)
+    }
+}
+
+impl Display for UnitName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, false)
+    }
+}
+
+struct SingularName(UnitName);
+
+impl Display for SingularName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write(f, true)
+    }
+}
+
+// Splits a name into words at spaces, or an identifier before each capital
+// letter and around each run of digits.
+struct Words {
+    rest: &'static str,
+    identifier: bool,
+}
+
+impl Iterator for Words {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<&'static str> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let end = if self.identifier {
+            let bytes = self.rest.as_bytes();
+            (1..bytes.len())
+                .find(|&i| {
+                    bytes[i].is_ascii_uppercase()
+                        || bytes[i].is_ascii_digit() != bytes[i - 1].is_ascii_digit()
+                })
+                .unwrap_or(bytes.len())
+        } else {
+            self.rest.find(' ').unwrap_or(self.rest.len())
+        };
+        let word = &self.rest[..end];
+        self.rest = self.rest[end..].trim_start_matches(' ');
+        Some(word)
+    }
+}
+
+fn ends_with(word: &str, suffix: &str) -> bool {
+    word.len() >= suffix.len()
+        && word.is_char_boundary(word.len() - suffix.len())
+        && word[word.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+}
+
+// Returns the stem of the singular of an English plural and the ending to
+// append to it.
+fn singularize(word: &'static str) -> (&'static str, &'static str) {
+    let cut = |n: usize| &word[..word.len() - n];
+    if ends_with(word, "feet") {
+        (cut(4), "foot")
+    } else if ends_with(word, "ities") {
+        (cut(3), "y")
+    } else if ends_with(word, "ches") || ends_with(word, "sses") {
+        (cut(2), "")
+    } else if ends_with(word, "siemens") || ends_with(word, "ss") || word.ends_with("us") {
+        (word, "")
+    } else if ends_with(word, "s") {
+        (cut(1), "")
+    } else {
+        (word, "")
+    }
 }
 
 #[cfg(test)]
@@ -116,10 +606,11 @@ mod tests {
         Primary,
         Double,
         Half,
+        Shifted,
     }
 
     impl Display for TestUnit {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
             write!(f, "{}", self.symbol())
         }
     }
@@ -130,6 +621,7 @@ mod tests {
                 TestUnit::Primary => "p",
                 TestUnit::Double => "d",
                 TestUnit::Half => "h",
+                TestUnit::Shifted => "s",
             }
         }
 
@@ -138,6 +630,14 @@ mod tests {
                 TestUnit::Primary => 1.0,
                 TestUnit::Double => 2.0,
                 TestUnit::Half => 0.5,
+                TestUnit::Shifted => 1.0,
+            }
+        }
+
+        fn conversion_offset(&self) -> f64 {
+            match self {
+                TestUnit::Shifted => 10.0,
+                _ => 0.0,
             }
         }
     }
@@ -147,6 +647,7 @@ mod tests {
         assert!(TestUnit::Primary.is_primary());
         assert!(!TestUnit::Double.is_primary());
         assert!(!TestUnit::Half.is_primary());
+        assert!(!TestUnit::Shifted.is_primary());
     }
 
     #[test]
@@ -173,4 +674,115 @@ mod tests {
         // 10 half = 5 primary = 2.5 double
         assert_eq!(TestUnit::Half.convert_to(10.0, &TestUnit::Double), 2.5);
     }
+
+    #[test]
+    fn test_convert_with_offset() {
+        // 5 shifted = 15 primary = 7.5 double
+        assert_eq!(TestUnit::Shifted.convert_to_primary(5.0), 15.0);
+        assert_eq!(TestUnit::Shifted.convert_from_primary(15.0), 5.0);
+        assert_eq!(TestUnit::Shifted.convert_to(5.0, &TestUnit::Double), 7.5);
+        assert_eq!(TestUnit::Double.convert_to(7.5, &TestUnit::Shifted), 5.0);
+        assert!(TestUnit::Double.is_linear());
+        assert!(!TestUnit::Shifted.is_linear());
+    }
+
+    // A non-affine unit defined only through the `f64` conversions.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct SquaredUnit;
+
+    impl Display for SquaredUnit {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str("sq")
+        }
+    }
+
+    impl UnitOfMeasure for SquaredUnit {
+        fn symbol(&self) -> &'static str {
+            "sq"
+        }
+
+        fn conversion_factor(&self) -> f64 {
+            1.0
+        }
+
+        fn convert_to_primary(&self, value: f64) -> f64 {
+            value * value
+        }
+
+        fn convert_from_primary(&self, value: f64) -> f64 {
+            crate::math::sqrt(value)
+        }
+    }
+
+    #[test]
+    fn test_value_from_primary_prefers_shortest_decimal() {
+        use crate::core::Quantity;
+        use crate::space::{Angle, AngleUnit};
+
+        let primary = Angle::degrees(30.0).to_primary();
+        assert_eq!(
+            AngleUnit::Degrees.convert_from_primary(primary),
+            29.999999999999996
+        );
+        assert_eq!(value_from_primary(&AngleUnit::Degrees, primary), 30.0);
+        assert_eq!(
+            value_from_primary(&AngleUnit::Degrees, f64::INFINITY),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_scalar_conversions_use_f64_methods() {
+        assert_eq!(SquaredUnit.scalar_to_primary(3.0f32), 9.0f32);
+        assert_eq!(SquaredUnit.scalar_from_primary(16.0f32), 4.0f32);
+        assert_eq!(
+            TestUnit::Half.scalar_convert_to(10.0f32, &TestUnit::Double),
+            2.5f32
+        );
+    }
+
+    #[test]
+    fn test_unit_names() {
+        let name = |identifier| UnitName::from_identifier(identifier);
+        assert_eq!(name("Meters").to_string(), "meters");
+        assert_eq!(name("Meters").singular().to_string(), "meter");
+        assert_eq!(
+            name("KilometersPerHour").singular().to_string(),
+            "kilometer per hour"
+        );
+        assert_eq!(
+            name("LitersPer100Kilometers").to_string(),
+            "liters per 100 kilometers"
+        );
+        assert_eq!(
+            name("LitersPer100Kilometers").singular().to_string(),
+            "liter per 100 kilometers"
+        );
+        assert_eq!(name("SquareFeet").singular().to_string(), "square foot");
+        assert_eq!(name("Inches").singular().to_string(), "inch");
+        assert_eq!(name("SolarMasses").singular().to_string(), "solar mass");
+        assert_eq!(
+            name("SolarLuminosities").singular().to_string(),
+            "solar luminosity"
+        );
+        assert_eq!(name("Siemens").singular().to_string(), "siemens");
+        assert_eq!(name("Hertz").singular().to_string(), "hertz");
+        assert_eq!(
+            name("KilogramMetersSquared").singular().to_string(),
+            "kilogram meter squared"
+        );
+        assert_eq!(
+            UnitName::new("US gallons").singular().to_string(),
+            "US gallon"
+        );
+        assert_eq!(
+            UnitName::new("thousand BTUs").singular().to_string(),
+            "thousand BTU"
+        );
+        assert_eq!(
+            UnitName::from(("degrees Celsius", "degree Celsius")).to_string(),
+            "degrees Celsius"
+        );
+        assert_eq!(TestUnit::Double.name().singular().to_string(), "d");
+    }
 }
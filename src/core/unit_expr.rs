@@ -0,0 +1,670 @@
+//! Compound unit expression parsing.
+//!
+//! Parses unit expressions such as `"kg·m/s²"`, `"W/(m²·K)"` or `"m/s^2"`
+//! into a scale factor and its base dimensions, including the angle and
+//! information pseudo-dimensions. Symbols are resolved
+//! against the units of every built-in dimension, optionally preceded by a
+//! metric prefix (e.g., `"mN"` is milli-newton even though `Force` has no
+//! such unit). Dimensions defined outside this crate can be added with
+//! [`register`].
+//!
+//! # Grammar
+//!
+//! - Multiplication: `*`, `·`, `⋅`, `×` or whitespace between symbols
+//! - Division: `/` (left-associative, so use parentheses for `W/(m²·K)`)
+//! - Exponents: `^n`, `^-n`, Unicode superscripts (`²`, `⁻¹`) or trailing digits (`s2`)
+//! - Grouping: `(` and `)`
+//! - Numbers: a bare number is only allowed as the numerator of a quotient
+//!   (e.g., `1/s`); `5 ft 11` is an error rather than 55 ft
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::core::unit_expr::UnitExpr;
+//! use rquants::prelude::*;
+//!
+//! let expr = UnitExpr::parse("kg·m/s²").unwrap();
+//! assert_eq!(expr.dims(), [1, 1, -2, 0, 0, 0, 0]);
+//!
+//! // Dimension::parse falls back to unit expressions
+//! let g: Acceleration = "9.81 m/s^2".parse().unwrap();
+//! assert!((g.to_meters_per_second_squared() - 9.81).abs() < 1e-12);
+//!
+//! let p: Power = "3 kJ/min".parse().unwrap();
+//! assert!((p.to_watts() - 50.0).abs() < 1e-9);
+//! ```
+
+use super::dimension::{builtin_dimensions, BaseDimensions, Dimension};
+use super::dynamic::{DimensionVector, DynQuantity};
+use super::error::QuantityParseError;
+use super::quantity::Quantity;
+use super::unit::UnitOfMeasure;
+use crate::math;
+use crate::systems::metric::{metric_prefix, metric_symbol};
+use core::iter::Peekable;
+use core::str::CharIndices;
+use std::sync::{OnceLock, PoisonError, RwLock, RwLockReadGuard};
+
+/// Name used in parse errors for unit expressions.
+const EXPRESSION: &str = "unit expression";
+
+/// A parsed unit expression: a scale factor to coherent SI units and its base dimensions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitExpr {
+    factor: f64,
+    dims: BaseDimensions,
+}
+
+impl UnitExpr {
+    /// Parses a unit expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `QuantityParseError` for unknown symbols, unbalanced
+    /// parentheses or malformed exponents.
+    pub fn parse(s: &str) -> Result<Self, QuantityParseError> {
+        let normalized = normalize(s.trim());
+        if normalized.is_empty() {
+            return Err(QuantityParseError::new(EXPRESSION, s));
+        }
+        if let Some(entry) = lookup(&normalized) {
+            return Ok(Self::new(entry.factor, entry.dims));
+        }
+
+        let mut parser = Parser {
+            input: &normalized,
+            chars: normalized.char_indices().peekable(),
+        };
+        let expr = parser
+            .expression()
+            .ok_or_else(|| QuantityParseError::new(EXPRESSION, s))?;
+        if parser.chars.peek().is_some() {
+            return Err(QuantityParseError::new(EXPRESSION, s));
+        }
+        Ok(expr)
+    }
+
+    const fn new(factor: f64, dims: BaseDimensions) -> Self {
+        Self { factor, dims }
+    }
+
+    /// Returns the factor converting one of this unit into coherent SI units.
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Returns the SI base-dimension exponents of this unit.
+    pub fn dims(&self) -> DimensionVector {
+        self.dims.si()
+    }
+
+    /// Returns the base dimensions of this unit, including the angle and
+    /// information pseudo-dimensions that [`dims`](Self::dims) leaves out.
+    pub fn base_dimensions(&self) -> BaseDimensions {
+        self.dims
+    }
+
+    /// Returns a dynamic quantity of `value` in this unit.
+    ///
+    /// Dynamic quantities only track SI dimensions, so the pseudo-dimensions
+    /// are dropped.
+    pub fn quantity(&self, value: f64) -> DynQuantity {
+        DynQuantity::new(value * self.factor, self.dims.si())
+    }
+
+    fn mul(self, rhs: Self) -> Option<Self> {
+        let dims = self.dims.checked_mul(rhs.dims)?;
+        Some(Self::new(self.factor * rhs.factor, dims))
+    }
+
+    fn powi(self, n: i32) -> Option<Self> {
+        let dims = self.dims.checked_powi(n)?;
+        Some(Self::new(math::powi(self.factor, n), dims))
+    }
+}
+
+/// Returns the names of all built-in dimensions whose SI signature equals `dims`.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::core::unit_expr::matching_dimensions;
+///
+/// let names = matching_dimensions(&[2, 1, -2, 0, 0, 0, 0]);
+/// assert_eq!(names, vec!["Energy"]);
+/// ```
+pub fn matching_dimensions(dims: &DimensionVector) -> Vec<&'static str> {
+    let extras = registered();
+    let mut names: Vec<&'static str> = registry()
+        .iter()
+        .chain(extras.iter())
+        .filter(|entry| entry.dims.si() == *dims)
+        .map(|entry| entry.dimension)
+        .collect();
+    names.dedup();
+    names
+}
+
+/// Returns the name of the dimension a unit symbol or expression belongs
+/// to, if it is known.
+pub(crate) fn dimension_of(expression: &str) -> Option<&'static str> {
+    if let Some(entry) = lookup(&normalize(expression.trim())) {
+        return Some(entry.dimension);
+    }
+    let expr = UnitExpr::parse(expression).ok()?;
+    let extras = registered();
+    registry()
+        .iter()
+        .chain(extras.iter())
+        .find(|entry| entry.dims == expr.dims)
+        .map(|entry| entry.dimension)
+}
+
+/// Makes the units of a dimension defined outside this crate available to
+/// the expression parser.
+///
+/// Built-in dimensions are always available. Registering a dimension more
+/// than once, or registering a built-in one, has no effect. Symbols that
+/// clash with an already known symbol keep their existing meaning.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::core::unit_expr::{register, UnitExpr};
+/// use rquants::prelude::*;
+///
+/// rquants::quantity! {
+///     /// Specific acoustic impedance.
+///     pub quantity AcousticImpedance {
+///         unit: AcousticImpedanceUnit;
+///         dimension: AcousticImpedanceDimension;
+///         name: "AcousticImpedance";
+///         primary: Rayls;
+///         si: Rayls;
+///         dimensions: [-2, 1, -1, 0, 0, 0, 0];
+///
+///         units {
+///             /// Rayls (Pa·s/m)
+///             Rayls {
+///                 symbol: "Rayl",
+///                 factor: 1.0,
+///                 ctor: rayls,
+///                 to: to_rayls,
+///                 si: true
+///             }
+///         }
+///     }
+/// }
+///
+/// assert!(UnitExpr::parse("kRayl·m²").is_err());
+/// register::<AcousticImpedanceDimension>();
+/// let expr = UnitExpr::parse("kRayl·m²").unwrap();
+/// assert!((expr.factor() - 1e3).abs() < 1e-9);
+/// assert_eq!(expr.dims(), [0, 1, -1, 0, 0, 0, 0]);
+/// ```
+pub fn register<D>()
+where
+    D: Dimension,
+    D::Quantity: Into<DynQuantity>,
+{
+    let mut extras = REGISTERED.write().unwrap_or_else(PoisonError::into_inner);
+    let known = registry().iter().chain(extras.iter());
+    if known.clone().any(|entry| entry.dimension == D::name()) {
+        return;
+    }
+    let mut entries = Vec::new();
+    push_units::<D>(&mut entries);
+    entries.retain(|entry| !known.clone().any(|other| other.symbol == entry.symbol));
+    for entry in &mut entries {
+        entry.prefixable &= strip_prefix(registry(), entry.symbol).is_none()
+            && strip_prefix(&extras, entry.symbol).is_none();
+    }
+    extras.extend(entries);
+}
+
+/// A unit symbol known to the expression parser.
+#[derive(Debug, Clone, Copy)]
+struct UnitEntry {
+    symbol: &'static str,
+    factor: f64,
+    dims: BaseDimensions,
+    dimension: &'static str,
+    prefixable: bool,
+}
+
+fn push_units<D>(entries: &mut Vec<UnitEntry>)
+where
+    D: Dimension,
+    D::Quantity: Into<DynQuantity>,
+{
+    // Units with an offset or their own conversion functions cannot be
+    // multiplied into an expression.
+    let base = D::base_dimensions();
+    for unit in D::units().iter().filter(|unit| unit.is_linear()) {
+        let quantity: DynQuantity = D::Quantity::new(1.0, *unit).into();
+        entries.push(UnitEntry {
+            symbol: unit.symbol(),
+            factor: quantity.value(),
+            dims: BaseDimensions::new(quantity.dims())
+                .with_angle(base.angle())
+                .with_information(base.information()),
+            dimension: D::name(),
+            prefixable: unit.is_si(),
+        });
+    }
+}
+
+macro_rules! unit_registry {
+    ($($dimension:ty),+ $(,)?) => {
+        fn build_registry() -> Vec<UnitEntry> {
+            let mut entries = Vec::new();
+            $(push_units::<$dimension>(&mut entries);)+
+            entries
+        }
+    };
+}
+
+builtin_dimensions!(unit_registry);
+
+fn registry() -> &'static [UnitEntry] {
+    static REGISTRY: OnceLock<Vec<UnitEntry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut entries = build_registry();
+        // Kelvin is needed for expressions such as "W/(m²·K)"; offset scales are excluded.
+        entries.push(UnitEntry {
+            symbol: "K",
+            factor: 1.0,
+            dims: BaseDimensions::new([0, 0, 0, 0, 1, 0, 0]),
+            dimension: "Temperature",
+            prefixable: true,
+        });
+        // Symbols that already carry a prefix (e.g., "km", "kg") cannot take another one.
+        let prefixed: Vec<bool> = entries
+            .iter()
+            .map(|entry| strip_prefix(&entries, entry.symbol).is_some())
+            .collect();
+        for (entry, prefixed) in entries.iter_mut().zip(prefixed) {
+            entry.prefixable &= !prefixed;
+        }
+        entries
+    })
+}
+
+/// Units registered at runtime through [`register`].
+static REGISTERED: RwLock<Vec<UnitEntry>> = RwLock::new(Vec::new());
+
+fn registered() -> RwLockReadGuard<'static, Vec<UnitEntry>> {
+    REGISTERED.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn lookup(symbol: &str) -> Option<UnitEntry> {
+    registry()
+        .iter()
+        .chain(registered().iter())
+        .find(|entry| entry.symbol == symbol)
+        .copied()
+}
+
+/// Metric prefixes, including ASCII `u` for micro.
+fn prefixes() -> impl Iterator<Item = (&'static str, i32)> {
+    (-30..=30)
+        .filter_map(|exponent| metric_symbol(exponent).map(|prefix| (prefix, exponent)))
+        .chain([("µ", -6), ("u", -6)])
+}
+
+/// Splits `symbol` into a metric prefix factor and a prefixable registry entry.
+fn strip_prefix<'a>(entries: &'a [UnitEntry], symbol: &str) -> Option<(f64, &'a UnitEntry)> {
+    prefixes().find_map(|(prefix, exponent)| {
+        let base = symbol.strip_prefix(prefix)?;
+        let entry = entries
+            .iter()
+            .find(|entry| entry.prefixable && entry.symbol == base)?;
+        Some((metric_prefix(exponent), entry))
+    })
+}
+
+fn resolve_symbol(symbol: &str) -> Option<UnitExpr> {
+    if let Some(entry) = lookup(symbol) {
+        return Some(UnitExpr::new(entry.factor, entry.dims));
+    }
+    let extras = registered();
+    let (prefix, entry) =
+        strip_prefix(registry(), symbol).or_else(|| strip_prefix(&extras, symbol))?;
+    Some(UnitExpr::new(prefix * entry.factor, entry.dims))
+}
+
+/// Normalizes look-alike characters to the symbols used by the unit enums.
+fn normalize(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            'μ' => 'µ',
+            'Ω' => 'Ω',
+            c => c,
+        })
+        .collect()
+}
+
+fn is_operator(c: char) -> bool {
+    matches!(c, '*' | '·' | '⋅' | '×' | '/' | '(' | ')' | '^') || c.is_whitespace()
+}
+
+fn superscript_digit(c: char) -> Option<i32> {
+    match c {
+        '⁰' => Some(0),
+        '¹' => Some(1),
+        '²' => Some(2),
+        '³' => Some(3),
+        '⁴' => Some(4),
+        '⁵' => Some(5),
+        '⁶' => Some(6),
+        '⁷' => Some(7),
+        '⁸' => Some(8),
+        '⁹' => Some(9),
+        _ => None,
+    }
+}
+
+fn is_symbol_char(c: char) -> bool {
+    !is_operator(c) && !c.is_ascii_digit() && c != '⁻' && superscript_digit(c).is_none()
+}
+
+/// Recursive-descent parser over a normalized unit expression.
+struct Parser<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+    }
+
+    /// expression := (number `/` | factor) ((`*` | `/` | whitespace) factor)*
+    fn expression(&mut self) -> Option<UnitExpr> {
+        self.skip_whitespace();
+        let mut result = match self.number()? {
+            // A bare number is only allowed as the numerator of a quotient,
+            // as in "1/s", so "5 ft 11" is not read as 55 ft.
+            Some(number) => {
+                self.skip_whitespace();
+                self.chars.peek().filter(|(_, c)| *c == '/')?;
+                number
+            }
+            None => self.factor()?,
+        };
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek().map(|(_, c)| *c) {
+                Some('*' | '·' | '⋅' | '×') => {
+                    self.chars.next();
+                    self.skip_whitespace();
+                    result = result.mul(self.factor()?)?;
+                }
+                Some('/') => {
+                    self.chars.next();
+                    self.skip_whitespace();
+                    result = result.mul(self.factor()?.powi(-1)?)?;
+                }
+                Some(')') | None => return Some(result),
+                // Juxtaposition, e.g. "N m"
+                Some(_) => result = result.mul(self.factor()?)?,
+            }
+        }
+    }
+
+    /// number := digits (`.` digits)?
+    ///
+    /// Returns `Some(None)` when no number is present and `None` on a malformed one.
+    fn number(&mut self) -> Option<Option<UnitExpr>> {
+        let Some(&(start, c)) = self.chars.peek() else {
+            return Some(None);
+        };
+        if !c.is_ascii_digit() {
+            return Some(None);
+        }
+        let digits = self.take_while(start, |c| c.is_ascii_digit() || c == '.');
+        let value: f64 = digits.parse().ok()?;
+        Some(Some(UnitExpr::new(value, BaseDimensions::DIMENSIONLESS)))
+    }
+
+    /// factor := primary exponent?
+    fn factor(&mut self) -> Option<UnitExpr> {
+        let primary = self.primary()?;
+        match self.exponent()? {
+            Some(n) => primary.powi(n),
+            None => Some(primary),
+        }
+    }
+
+    /// primary := `(` expression `)` | symbol
+    fn primary(&mut self) -> Option<UnitExpr> {
+        let (start, c) = *self.chars.peek()?;
+        if c == '(' {
+            self.chars.next();
+            let inner = self.expression()?;
+            self.chars.next_if(|(_, c)| *c == ')')?;
+            return Some(inner);
+        }
+        if !is_symbol_char(c) {
+            return None;
+        }
+        let symbol = self.take_while(start, is_symbol_char);
+        // Multi-word symbols such as "fl oz" or "lb t"
+        let rest = &self.input[start + symbol.len()..];
+        if let Some(next) = rest.strip_prefix(' ') {
+            let word_len = next
+                .find(|c: char| !is_symbol_char(c))
+                .unwrap_or(next.len());
+            let joined = &self.input[start..start + symbol.len() + 1 + word_len];
+            if word_len > 0 && lookup(joined).is_some() {
+                while self
+                    .chars
+                    .next_if(|(i, _)| *i < start + joined.len())
+                    .is_some()
+                {}
+                return resolve_symbol(joined);
+            }
+        }
+        resolve_symbol(symbol)
+    }
+
+    /// exponent := `^` `-`? digits | superscripts | digits
+    ///
+    /// Returns `Some(None)` when no exponent is present and `None` on a malformed one.
+    fn exponent(&mut self) -> Option<Option<i32>> {
+        let Some(&(start, c)) = self.chars.peek() else {
+            return Some(None);
+        };
+        if c == '^' {
+            self.chars.next();
+            let (start, _) = *self.chars.peek()?;
+            let mut end = start;
+            if self
+                .chars
+                .next_if(|(_, c)| matches!(c, '-' | '+'))
+                .is_some()
+            {
+                end += 1;
+            }
+            while let Some((i, c)) = self.chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                end = i + c.len_utf8();
+            }
+            return self.input[start..end].parse().ok().map(Some);
+        }
+        if c == '⁻' || superscript_digit(c).is_some() {
+            let negative = self.chars.next_if(|(_, c)| *c == '⁻').is_some();
+            let mut value: Option<i32> = None;
+            while let Some((_, c)) = self.chars.next_if(|(_, c)| superscript_digit(*c).is_some()) {
+                let digit = superscript_digit(c)?;
+                value = Some(value.unwrap_or(0).checked_mul(10)?.checked_add(digit)?);
+            }
+            let value = value?;
+            return Some(Some(if negative { -value } else { value }));
+        }
+        if c.is_ascii_digit() {
+            let digits = self.take_while(start, |c| c.is_ascii_digit());
+            return digits.parse().ok().map(Some);
+        }
+        Some(None)
+    }
+
+    fn take_while(&mut self, start: usize, predicate: impl Fn(char) -> bool) -> &'a str {
+        let mut end = start;
+        while let Some((i, c)) = self.chars.next_if(|(_, c)| predicate(*c)) {
+            end = i + c.len_utf8();
+        }
+        &self.input[start..end]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::motion::Acceleration;
+    use crate::space::Length;
+
+    fn close(left: f64, right: f64) -> bool {
+        (left - right).abs() <= 1e-12 * left.abs().max(right.abs()).max(1.0)
+    }
+
+    #[test]
+    fn test_single_symbols() {
+        let km = UnitExpr::parse("km").unwrap();
+        assert_eq!(km.factor(), 1000.0);
+        assert_eq!(km.dims(), [1, 0, 0, 0, 0, 0, 0]);
+
+        let g = UnitExpr::parse("g").unwrap();
+        assert_eq!(g.dims(), [0, 1, 0, 0, 0, 0, 0]);
+        assert!(close(g.factor(), 1e-3));
+    }
+
+    #[test]
+    fn test_operators() {
+        for s in [
+            "kg·m/s²",
+            "kg*m/s^2",
+            "kg m s^-2",
+            "kg*m/s2",
+            "kg⋅m⋅s⁻²",
+            "(kg*m)/(s*s)",
+        ] {
+            let expr = UnitExpr::parse(s).unwrap();
+            assert_eq!(expr.dims(), [1, 1, -2, 0, 0, 0, 0], "{s}");
+            assert!(close(expr.factor(), 1.0), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_parentheses_and_kelvin() {
+        let expr = UnitExpr::parse("W/(m²·K)").unwrap();
+        assert_eq!(expr.dims(), [0, 1, -3, 0, -1, 0, 0]);
+        assert!(close(expr.factor(), 1.0));
+    }
+
+    #[test]
+    fn test_prefix_decomposition() {
+        let mn = UnitExpr::parse("mN").unwrap();
+        assert_eq!(mn.dims(), [1, 1, -2, 0, 0, 0, 0]);
+        assert!(close(mn.factor(), 1e-3));
+
+        let um = UnitExpr::parse("um").unwrap();
+        assert!(close(um.factor(), 1e-6));
+
+        let kmol = UnitExpr::parse("kmol").unwrap();
+        assert!(close(kmol.factor(), 1e3));
+
+        // Already-prefixed symbols cannot take another prefix
+        assert!(UnitExpr::parse("kkm").is_err());
+    }
+
+    #[test]
+    fn test_reciprocal_and_numbers() {
+        let hz = UnitExpr::parse("1/s").unwrap();
+        assert_eq!(hz.dims(), [0, 0, -1, 0, 0, 0, 0]);
+
+        let per_min = UnitExpr::parse("1/min").unwrap();
+        assert!(close(per_min.factor(), 1.0 / 60.0));
+    }
+
+    #[test]
+    fn test_multi_word_symbols() {
+        let expr = UnitExpr::parse("fl oz/s").unwrap();
+        assert_eq!(expr.dims(), [3, 0, -1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(UnitExpr::parse("").is_err());
+        assert!(UnitExpr::parse("xyz").is_err());
+        assert!(UnitExpr::parse("(m/s").is_err());
+        assert!(UnitExpr::parse("m/s)").is_err());
+        assert!(UnitExpr::parse("m^").is_err());
+        assert!(UnitExpr::parse("m/").is_err());
+
+        // Exponents that overflow fail instead of wrapping
+        assert!(UnitExpr::parse("m^100 m^100").is_err());
+        assert!(UnitExpr::parse("m¹¹¹¹¹¹¹¹¹¹¹¹").is_err());
+        assert!(UnitExpr::parse("m^2147483647 m").is_err());
+        assert!(UnitExpr::parse("m^-2147483648").is_err());
+
+        // Numbers are not multiplied into an expression
+        assert!(UnitExpr::parse("m 2").is_err());
+        assert!(UnitExpr::parse("3 m").is_err());
+        assert!(UnitExpr::parse("m*2").is_err());
+        assert!(UnitExpr::parse("ft 11").is_err());
+        assert!(UnitExpr::parse("m/2").is_err());
+        assert!(UnitExpr::parse("1").is_err());
+    }
+
+    #[test]
+    fn test_dimension_parse_fallback() {
+        let a: Acceleration = "9.81 m/s^2".parse().unwrap();
+        assert!(close(a.to_meters_per_second_squared(), 9.81));
+
+        let a2: Acceleration = "1 kg*m/s2 / kg".parse().unwrap();
+        assert!(close(a2.to_meters_per_second_squared(), 1.0));
+
+        // Wrong dimension is rejected
+        assert!("9.81 m/s".parse::<Acceleration>().is_err());
+
+        assert!("1 m^100 m^100".parse::<Length>().is_err());
+        assert!("1 m¹¹¹¹¹¹¹¹¹¹¹¹".parse::<Length>().is_err());
+        assert!("1 m 2".parse::<Length>().is_err());
+        assert!("2 3 m".parse::<Length>().is_err());
+        assert!("5 ft 11".parse::<Length>().is_err());
+    }
+
+    #[test]
+    fn test_pseudo_dimensions() {
+        use crate::core::Dimensionless;
+        use crate::information::DataRate;
+        use crate::photo::illuminance::IlluminanceDimension;
+        use crate::photo::Illuminance;
+        use crate::time::Frequency;
+
+        let expr = UnitExpr::parse("B/s").unwrap();
+        assert_eq!(expr.dims(), [0, 0, -1, 0, 0, 0, 0]);
+        assert_eq!(expr.base_dimensions().information(), 1);
+        assert_eq!(dimension_of("B/s"), Some("DataRate"));
+
+        // Angle and information must match, not just the SI exponents
+        assert!("10 B/s".parse::<Frequency>().is_err());
+        assert!("10 rad/s".parse::<Frequency>().is_err());
+        assert!("10 B".parse::<Dimensionless>().is_err());
+        assert!("10 B/min".parse::<DataRate>().is_ok());
+
+        let expr = UnitExpr::parse("lm/m²").unwrap();
+        assert_eq!(
+            expr.base_dimensions(),
+            IlluminanceDimension::base_dimensions()
+        );
+        assert!("5 lm/m²".parse::<Illuminance>().is_ok());
+    }
+
+    #[test]
+    fn test_matching_dimensions() {
+        let names = matching_dimensions(&[0, 0, -1, 0, 0, 0, 0]);
+        assert!(names.contains(&"Frequency"));
+        assert!(names.contains(&"Activity"));
+    }
+}
@@ -0,0 +1,407 @@
+//! Finding units by symbol, alias or long name.
+//!
+//! [`Dimension::find_unit`](crate::core::Dimension::find_unit) looks a unit
+//! up in tiers, stopping at the first tier with a match:
+//!
+//! 1. the unit symbol, such as `km`;
+//! 2. an alias declared for the unit, such as `metre`, or its long name in
+//!    the plural or singular, such as `meters` or `ohm`;
+//! 3. with [`UnitMatching::ascii`], the ASCII spelling of the symbol, such
+//!    as `um` for `µm` or `degC` for `°C`;
+//! 4. with [`UnitMatching::ignore_case`], any of the above regardless of
+//!    case, such as `KM` or `Meters`.
+//!
+//! If a tier matches more than one unit, the text is ambiguous and no unit
+//! is returned: ignoring case, `mw` could be milliwatts or megawatts.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::core::{UnitMatchError, UnitMatching};
+//! use rquants::energy::power::PowerDimension;
+//! use rquants::prelude::*;
+//! use rquants::space::length::LengthDimension;
+//!
+//! assert_eq!(LengthDimension::unit_by_symbol("metres"), Some(LengthUnit::Meters));
+//! assert_eq!(LengthDimension::parse("3 kilometers").unwrap(), Length::kilometers(3.0));
+//!
+//! let relaxed = UnitMatching::relaxed();
+//! assert_eq!(
+//!     LengthDimension::find_unit("UM", relaxed),
+//!     Ok(LengthUnit::Micrometers)
+//! );
+//! assert_eq!(
+//!     PowerDimension::find_unit("mw", relaxed),
+//!     Err(UnitMatchError::Ambiguous(PowerUnit::Milliwatts, PowerUnit::Megawatts))
+//! );
+//! ```
+
+use crate::core::format::is_ascii_spelling;
+use crate::core::UnitOfMeasure;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Write};
+
+/// How text is matched against units, beyond their symbols, aliases and
+/// long names.
+///
+/// The default, [`UnitMatching::exact`], matches text exactly as written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct UnitMatching {
+    ignore_case: bool,
+    ascii: bool,
+}
+
+impl UnitMatching {
+    /// Matches symbols, aliases and long names exactly as written.
+    pub const fn exact() -> Self {
+        Self {
+            ignore_case: false,
+            ascii: false,
+        }
+    }
+
+    /// Matches ASCII spellings of symbols as well, regardless of case.
+    pub const fn relaxed() -> Self {
+        Self::exact().ignore_case().ascii()
+    }
+
+    /// Falls back to matching regardless of case.
+    pub const fn ignore_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+
+    /// Falls back to matching the ASCII spelling of symbols written by
+    /// [`QuantityFormatter::ascii`](crate::core::QuantityFormatter::ascii),
+    /// such as `um`, `ohm` or `degC`.
+    pub const fn ascii(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+}
+
+/// The reason no single unit matches a text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitMatchError<U> {
+    /// No unit matches.
+    Unknown,
+    /// At least two units match equally well; these are the first two.
+    Ambiguous(U, U),
+}
+
+impl<U: UnitOfMeasure> fmt::Display for UnitMatchError<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnitMatchError::Unknown => f.write_str("unknown unit"),
+            UnitMatchError::Ambiguous(first, second) => {
+                write!(f, "ambiguous unit: '{first}' or '{second}'")
+            }
+        }
+    }
+}
+
+impl<U: UnitOfMeasure> core::error::Error for UnitMatchError<U> {}
+
+/// Finds the unit among `units` that `text` denotes.
+pub(crate) fn find_unit<U: UnitOfMeasure>(
+    units: &[U],
+    text: &str,
+    matching: UnitMatching,
+) -> Result<U, UnitMatchError<U>> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(UnitMatchError::Unknown);
+    }
+    let exact = |unit: &U| unit.symbol() == text;
+    let alias = |unit: &U, ignore_case: bool| {
+        unit.aliases()
+            .iter()
+            .any(|alias| displays_as(alias, text, ignore_case))
+            || displays_as(unit.name(), text, ignore_case)
+            || displays_as(unit.name().singular(), text, ignore_case)
+    };
+    let ascii = |unit: &U, ignore_case: bool| {
+        matching.ascii && is_ascii_spelling(unit.symbol(), text, ignore_case)
+    };
+
+    if let Some(found) = unique_match(units, exact) {
+        return found;
+    }
+    if let Some(found) = unique_match(units, |unit| alias(unit, false)) {
+        return found;
+    }
+    if let Some(found) = unique_match(units, |unit| ascii(unit, false)) {
+        return found;
+    }
+    if matching.ignore_case {
+        let any_case = |unit: &U| {
+            displays_as(unit.symbol(), text, true) || alias(unit, true) || ascii(unit, true)
+        };
+        if let Some(found) = unique_match(units, any_case) {
+            return found;
+        }
+    }
+    Err(UnitMatchError::Unknown)
+}
+
+/// Returns the unit matching `predicate`, an ambiguity error if two do, or
+/// `None` if none does.
+fn unique_match<U: UnitOfMeasure>(
+    units: &[U],
+    predicate: impl Fn(&U) -> bool,
+) -> Option<Result<U, UnitMatchError<U>>> {
+    let mut matches = units.iter().filter(|unit| predicate(unit));
+    let first = *matches.next()?;
+    match matches.find(|&&unit| unit != first) {
+        Some(&second) => Some(Err(UnitMatchError::Ambiguous(first, second))),
+        None => Some(Ok(first)),
+    }
+}
+
+/// Returns the spellings of `units` closest to `text`, closest first, to
+/// suggest in place of an unknown unit.
+///
+/// Each unit is represented by whichever of its symbol, aliases and long
+/// names is fewest edits from `text`, ignoring case, provided that is only
+/// a few edits and fewer than the length of the spelling.
+#[cfg(feature = "alloc")]
+pub(crate) fn suggestions<U: UnitOfMeasure>(units: &[U], text: &str) -> Vec<String> {
+    const MAX_SUGGESTIONS: usize = 3;
+    let text: Vec<char> = text.trim().chars().flat_map(char::to_lowercase).collect();
+    let max_edits = match text.len() {
+        0..=3 => 1,
+        4..=7 => 2,
+        _ => 3,
+    };
+
+    let mut closest: Vec<(usize, String)> = Vec::new();
+    for unit in units {
+        let name = unit.name();
+        let spellings = [
+            unit.symbol().to_string(),
+            name.to_string(),
+            name.singular().to_string(),
+        ]
+        .into_iter()
+        .chain(unit.aliases().iter().map(|alias| alias.to_string()));
+        let best = spellings
+            .map(|spelling| (edit_distance(&text, &spelling), spelling))
+            .filter(|(edits, spelling)| *edits <= max_edits && *edits < spelling.chars().count())
+            .min_by_key(|(edits, _)| *edits);
+        if let Some(best) = best {
+            if !closest.iter().any(|(_, spelling)| *spelling == best.1) {
+                closest.push(best);
+            }
+        }
+    }
+    closest.sort_by_key(|(edits, _)| *edits);
+    closest
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, spelling)| spelling)
+        .collect()
+}
+
+/// Returns the Levenshtein distance between `text`, already in lowercase,
+/// and `spelling`, ignoring case.
+#[cfg(feature = "alloc")]
+fn edit_distance(text: &[char], spelling: &str) -> usize {
+    let spelling: Vec<char> = spelling.chars().flat_map(char::to_lowercase).collect();
+    let mut previous: Vec<usize> = (0..=spelling.len()).collect();
+    let mut current = previous.clone();
+    for (i, &a) in text.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, &b) in spelling.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != b);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous[spelling.len()]
+}
+
+/// Returns true if `value` is displayed as exactly `text`.
+pub(crate) fn displays_as(value: impl Display, text: &str, ignore_case: bool) -> bool {
+    let mut matcher = Matcher {
+        rest: text,
+        ignore_case,
+    };
+    write!(matcher, "{value}").is_ok() && matcher.rest.is_empty()
+}
+
+/// Consumes the text it is compared with as it is written, failing on the
+/// first mismatch.
+pub(crate) struct Matcher<'a> {
+    pub(crate) rest: &'a str,
+    pub(crate) ignore_case: bool,
+}
+
+impl Write for Matcher<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let mut rest = self.rest.chars();
+            let expected = rest.next().ok_or(fmt::Error)?;
+            let same =
+                c == expected || (self.ignore_case && c.to_lowercase().eq(expected.to_lowercase()));
+            if !same {
+                return Err(fmt::Error);
+            }
+            self.rest = rest.as_str();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Dimension;
+    use crate::electro::electrical_resistance::ElectricalResistanceDimension;
+    use crate::electro::ElectricalResistanceUnit;
+    use crate::energy::power::PowerDimension;
+    use crate::energy::PowerUnit;
+    use crate::information::information::InformationDimension;
+    use crate::information::InformationUnit;
+    use crate::motion::velocity::VelocityDimension;
+    use crate::motion::VelocityUnit;
+    use crate::space::angle::AngleDimension;
+    use crate::space::length::LengthDimension;
+    use crate::space::{AngleUnit, LengthUnit};
+    use crate::thermal::temperature::TemperatureDimension;
+    use crate::thermal::TemperatureScale;
+    use crate::time::{TimeDimension, TimeUnit};
+
+    #[test]
+    fn test_symbols_aliases_and_names() {
+        let find = |text| LengthDimension::find_unit(text, UnitMatching::exact());
+        assert_eq!(find("km"), Ok(LengthUnit::Kilometers));
+        assert_eq!(find("meters"), Ok(LengthUnit::Meters));
+        assert_eq!(find("meter"), Ok(LengthUnit::Meters));
+        assert_eq!(find("metre"), Ok(LengthUnit::Meters));
+        assert_eq!(find("kilometres"), Ok(LengthUnit::Kilometers));
+        assert_eq!(find("feet"), Ok(LengthUnit::Feet));
+        assert_eq!(find("foot"), Ok(LengthUnit::Feet));
+        assert_eq!(find("Meters"), Err(UnitMatchError::Unknown));
+        assert_eq!(find("um"), Err(UnitMatchError::Unknown));
+        assert_eq!(find(""), Err(UnitMatchError::Unknown));
+
+        assert_eq!(
+            VelocityDimension::unit_by_symbol("km/hr"),
+            Some(VelocityUnit::KilometersPerHour)
+        );
+        assert_eq!(
+            InformationDimension::unit_by_symbol("kB"),
+            Some(InformationUnit::Kilobytes)
+        );
+        assert_eq!(
+            ElectricalResistanceDimension::unit_by_symbol("ohm"),
+            Some(ElectricalResistanceUnit::Ohms)
+        );
+        assert_eq!(
+            TemperatureDimension::unit_by_symbol("degC"),
+            Some(TemperatureScale::Celsius)
+        );
+        assert_eq!(TimeDimension::unit_by_symbol("hrs"), Some(TimeUnit::Hours));
+        assert_eq!(
+            AngleDimension::unit_by_symbol("''"),
+            Some(AngleUnit::ArcSeconds)
+        );
+    }
+
+    #[test]
+    fn test_relaxed_matching() {
+        let relaxed = UnitMatching::relaxed();
+        assert_eq!(
+            LengthDimension::find_unit("um", UnitMatching::exact().ascii()),
+            Ok(LengthUnit::Micrometers)
+        );
+        assert_eq!(
+            LengthDimension::find_unit("KM", UnitMatching::exact().ignore_case()),
+            Ok(LengthUnit::Kilometers)
+        );
+        assert_eq!(
+            LengthDimension::find_unit("Meters", relaxed),
+            Ok(LengthUnit::Meters)
+        );
+        assert_eq!(
+            LengthDimension::find_unit("UM", UnitMatching::exact().ignore_case()),
+            Err(UnitMatchError::Unknown)
+        );
+        assert_eq!(
+            ElectricalResistanceDimension::find_unit("kohm", relaxed),
+            Ok(ElectricalResistanceUnit::Kilohms)
+        );
+        // Exact matches win over case-insensitive ones
+        assert_eq!(
+            PowerDimension::find_unit("MW", relaxed),
+            Ok(PowerUnit::Megawatts)
+        );
+        assert_eq!(
+            PowerDimension::find_unit("mw", relaxed),
+            Err(UnitMatchError::Ambiguous(
+                PowerUnit::Milliwatts,
+                PowerUnit::Megawatts
+            ))
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_aliases() {
+        // Built-in symbols, aliases and names never denote two units
+        fn check<D: Dimension>() {
+            for unit in D::units() {
+                let texts = unit.aliases().iter().copied().chain([unit.symbol()]);
+                for text in texts {
+                    let found = D::find_unit(text, UnitMatching::exact());
+                    assert!(found.is_ok(), "{text}: {found:?}");
+                }
+            }
+        }
+        check::<LengthDimension>();
+        check::<TimeDimension>();
+        check::<AngleDimension>();
+        check::<VelocityDimension>();
+        check::<InformationDimension>();
+        check::<TemperatureDimension>();
+        check::<crate::space::volume::VolumeDimension>();
+        check::<crate::mass::mass::MassDimension>();
+
+        // A unit listed twice is not ambiguous
+        let units = [LengthUnit::Meters, LengthUnit::Meters, LengthUnit::Feet];
+        assert_eq!(
+            find_unit(&units, "M", UnitMatching::relaxed()),
+            Ok(LengthUnit::Meters)
+        );
+        let err = PowerDimension::find_unit("mw", UnitMatching::relaxed()).unwrap_err();
+        assert_eq!(err.to_string(), "ambiguous unit: 'mW' or 'MW'");
+    }
+
+    #[test]
+    fn test_suggestions() {
+        let units = LengthDimension::units();
+        assert_eq!(suggestions(units, "kms"), ["km"]);
+        assert_eq!(suggestions(units, "KM"), ["km", "nm", "µm"]);
+        assert_eq!(suggestions(units, "metrs")[0], "meters");
+        assert_eq!(suggestions(units, "kilometr"), ["kilometer"]);
+        assert!(suggestions(units, "x").is_empty());
+        assert!(suggestions(units, "parsnips").len() <= 3);
+        assert!(suggestions(units, "qwerty").is_empty());
+
+        let text: Vec<char> = "kitten".chars().collect();
+        assert_eq!(edit_distance(&text, "Sitting"), 3);
+        assert_eq!(edit_distance(&text, "kitten"), 0);
+        assert_eq!(edit_distance(&[], "km"), 2);
+    }
+
+    #[test]
+    fn test_displays_as() {
+        assert!(displays_as("m²", "m²", false));
+        assert!(!displays_as("m", "m²", false));
+        assert!(!displays_as("m²", "m", false));
+        assert!(displays_as("KiB", "kib", true));
+        assert!(!displays_as("KiB", "kib", false));
+    }
+}
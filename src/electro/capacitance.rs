@@ -1,6 +1,7 @@
 //! Capacitance quantity and units.
-use crate::core::Quantity;
-use std::ops::Mul;
+
+use crate::core::Rational;
+
 crate::quantity! {
     /// A quantity of capacitance.
     ///
@@ -31,6 +32,7 @@ crate::quantity! {
         name: "Capacitance";
         primary: Farads;
         si: Farads;
+        dimensions: [-2, -1, 4, 2, 0, 0, 0];
 
         units {
             /// Farads (F) - SI unit
@@ -44,7 +46,7 @@ crate::quantity! {
             /// Picofarads (pF)
             Picofarads {
                 symbol: "pF",
-                factor: 1e-12,
+                factor: Rational::pow10(-12),
                 ctor: picofarads,
                 to: to_picofarads,
                 si: true
@@ -52,7 +54,7 @@ crate::quantity! {
             /// Nanofarads (nF)
             Nanofarads {
                 symbol: "nF",
-                factor: 1e-9,
+                factor: Rational::pow10(-9),
                 ctor: nanofarads,
                 to: to_nanofarads,
                 si: true
@@ -60,7 +62,7 @@ crate::quantity! {
             /// Microfarads (µF)
             Microfarads {
                 symbol: "µF",
-                factor: 1e-6,
+                factor: Rational::pow10(-6),
                 ctor: microfarads,
                 to: to_microfarads,
                 si: true
@@ -68,7 +70,7 @@ crate::quantity! {
             /// Millifarads (mF)
             Millifarads {
                 symbol: "mF",
-                factor: 1e-3,
+                factor: Rational::pow10(-3),
                 ctor: millifarads,
                 to: to_millifarads,
                 si: true
@@ -76,33 +78,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::electric_charge::{ElectricCharge, ElectricChargeUnit};
-use super::electric_potential::ElectricPotential;
-
-// Capacitance * Potential = Charge (Q = CV)
-impl Mul<ElectricPotential> for Capacitance {
-    type Output = ElectricCharge;
-
-    fn mul(self, rhs: ElectricPotential) -> Self::Output {
-        let coulombs = self.to_farads() * rhs.to_volts();
-        ElectricCharge::new(coulombs, ElectricChargeUnit::Coulombs)
-    }
-}
-
-// Potential * Capacitance = Charge
-impl Mul<Capacitance> for ElectricPotential {
-    type Output = ElectricCharge;
-
-    fn mul(self, rhs: Capacitance) -> Self::Output {
-        let coulombs = self.to_volts() * rhs.to_farads();
-        ElectricCharge::new(coulombs, ElectricChargeUnit::Coulombs)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::electro::ElectricPotential;
 
     #[test]
     fn test_capacitance_creation() {
@@ -1,6 +1,4 @@
 //! Conductivity quantity and units.
-use crate::core::Quantity;
-use std::ops::Mul;
 crate::quantity! {
     /// A quantity of conductivity.
     ///
@@ -32,6 +30,7 @@ crate::quantity! {
         name: "Conductivity";
         primary: SiemensPerMeter;
         si: SiemensPerMeter;
+        dimensions: [-3, -1, 3, 2, 0, 0, 0];
 
         units {
             /// Siemens per meter (S/m) - SI unit
@@ -45,33 +44,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::electrical_conductance::{ElectricalConductance, ElectricalConductanceUnit};
-use crate::space::Length;
-
-// Conductivity * Length = Conductance
-impl Mul<Length> for Conductivity {
-    type Output = ElectricalConductance;
-
-    fn mul(self, rhs: Length) -> Self::Output {
-        let siemens = self.to_siemens_per_meter() * rhs.to_meters();
-        ElectricalConductance::new(siemens, ElectricalConductanceUnit::Siemens)
-    }
-}
-
-// Length * Conductivity = Conductance
-impl Mul<Conductivity> for Length {
-    type Output = ElectricalConductance;
-
-    fn mul(self, rhs: Conductivity) -> Self::Output {
-        let siemens = self.to_meters() * rhs.to_siemens_per_meter();
-        ElectricalConductance::new(siemens, ElectricalConductanceUnit::Siemens)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::space::Length;
 
     #[test]
     fn test_conductivity_creation() {
@@ -1,6 +1,7 @@
 //! Electric charge quantity and units.
-use crate::core::Quantity;
-use std::ops::{Div, Mul};
+
+use crate::core::Rational;
+
 crate::quantity! {
     /// A quantity of electric charge.
     ///
@@ -33,6 +34,7 @@ crate::quantity! {
         name: "ElectricCharge";
         primary: Coulombs;
         si: Coulombs;
+        dimensions: [0, 0, 1, 1, 0, 0, 0];
 
         units {
             /// Coulombs (C) - SI unit
@@ -46,7 +48,8 @@ crate::quantity! {
             /// Milliampere-hours (mAh)
             Milliamperehours {
                 symbol: "mAh",
-                factor: 3.6,
+                name: "milliampere-hours",
+                factor: Rational::new(36, 10),
                 ctor: milliamperehours,
                 to: to_milliamperehours,
                 si: false
@@ -54,6 +57,7 @@ crate::quantity! {
             /// Ampere-hours (Ah)
             Amperehours {
                 symbol: "Ah",
+                name: "ampere-hours",
                 factor: 3600.0,
                 ctor: amperehours,
                 to: to_amperehours,
@@ -62,66 +66,13 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::capacitance::{Capacitance, CapacitanceUnit};
-use super::electric_current::{ElectricCurrent, ElectricCurrentUnit};
-use super::electric_potential::{ElectricPotential, ElectricPotentialUnit};
-use crate::energy::{Energy, EnergyUnit};
-use crate::time::{Time, TimeUnit};
-
-// Charge / Time = Current
-impl Div<Time> for ElectricCharge {
-    type Output = ElectricCurrent;
-
-    fn div(self, rhs: Time) -> Self::Output {
-        let amperes = self.to_coulombs() / rhs.to_seconds();
-        ElectricCurrent::new(amperes, ElectricCurrentUnit::Amperes)
-    }
-}
-
-// Charge / Current = Time
-impl Div<ElectricCurrent> for ElectricCharge {
-    type Output = Time;
-
-    fn div(self, rhs: ElectricCurrent) -> Self::Output {
-        let seconds = self.to_coulombs() / rhs.to_amperes();
-        Time::new(seconds, TimeUnit::Seconds)
-    }
-}
-
-// Charge / Capacitance = Potential (V = Q/C)
-impl Div<Capacitance> for ElectricCharge {
-    type Output = ElectricPotential;
-
-    fn div(self, rhs: Capacitance) -> Self::Output {
-        let volts = self.to_coulombs() / rhs.to_farads();
-        ElectricPotential::new(volts, ElectricPotentialUnit::Volts)
-    }
-}
-
-// Charge / Potential = Capacitance (C = Q/V)
-impl Div<ElectricPotential> for ElectricCharge {
-    type Output = Capacitance;
-
-    fn div(self, rhs: ElectricPotential) -> Self::Output {
-        let farads = self.to_coulombs() / rhs.to_volts();
-        Capacitance::new(farads, CapacitanceUnit::Farads)
-    }
-}
-
-// Charge * Potential = Energy (E = QV)
-impl Mul<ElectricPotential> for ElectricCharge {
-    type Output = Energy;
-
-    fn mul(self, rhs: ElectricPotential) -> Self::Output {
-        let joules = self.to_coulombs() * rhs.to_volts();
-        Energy::new(joules, EnergyUnit::Joules)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::electro::ElectricCurrent;
+    use crate::electro::ElectricPotential;
+    use crate::time::Time;
 
     #[test]
     fn test_charge_creation() {
@@ -1,6 +1,7 @@
 //! Electric current quantity and units.
-use crate::core::Quantity;
-use std::ops::Mul;
+
+use crate::core::Rational;
+
 crate::quantity! {
     /// A quantity of electric current.
     ///
@@ -32,6 +33,7 @@ crate::quantity! {
         name: "ElectricCurrent";
         primary: Amperes;
         si: Amperes;
+        dimensions: [0, 0, 0, 1, 0, 0, 0];
 
         units {
             /// Amperes (A) - SI unit
@@ -45,7 +47,7 @@ crate::quantity! {
             /// Milliamperes (mA)
             Milliamperes {
                 symbol: "mA",
-                factor: 1e-3,
+                factor: Rational::pow10(-3),
                 ctor: milliamperes,
                 to: to_milliamperes,
                 si: true
@@ -53,76 +55,13 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::electric_charge::{ElectricCharge, ElectricChargeUnit};
-use super::electric_potential::{ElectricPotential, ElectricPotentialUnit};
-use super::electrical_resistance::ElectricalResistance;
-use crate::energy::{Power, PowerUnit};
-use crate::time::Time;
-
-// Current * Time = Charge
-impl Mul<Time> for ElectricCurrent {
-    type Output = ElectricCharge;
-
-    fn mul(self, rhs: Time) -> Self::Output {
-        let coulombs = self.to_amperes() * rhs.to_seconds();
-        ElectricCharge::new(coulombs, ElectricChargeUnit::Coulombs)
-    }
-}
-
-// Time * Current = Charge
-impl Mul<ElectricCurrent> for Time {
-    type Output = ElectricCharge;
-
-    fn mul(self, rhs: ElectricCurrent) -> Self::Output {
-        let coulombs = rhs.to_amperes() * self.to_seconds();
-        ElectricCharge::new(coulombs, ElectricChargeUnit::Coulombs)
-    }
-}
-
-// Current * Resistance = Potential (Ohm's law: V = IR)
-impl Mul<ElectricalResistance> for ElectricCurrent {
-    type Output = ElectricPotential;
-
-    fn mul(self, rhs: ElectricalResistance) -> Self::Output {
-        let volts = self.to_amperes() * rhs.to_ohms();
-        ElectricPotential::new(volts, ElectricPotentialUnit::Volts)
-    }
-}
-
-// Resistance * Current = Potential
-impl Mul<ElectricCurrent> for ElectricalResistance {
-    type Output = ElectricPotential;
-
-    fn mul(self, rhs: ElectricCurrent) -> Self::Output {
-        let volts = self.to_ohms() * rhs.to_amperes();
-        ElectricPotential::new(volts, ElectricPotentialUnit::Volts)
-    }
-}
-
-// Current * Potential = Power (P = IV)
-impl Mul<ElectricPotential> for ElectricCurrent {
-    type Output = Power;
-
-    fn mul(self, rhs: ElectricPotential) -> Self::Output {
-        let watts = self.to_amperes() * rhs.to_volts();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
-
-// Potential * Current = Power
-impl Mul<ElectricCurrent> for ElectricPotential {
-    type Output = Power;
-
-    fn mul(self, rhs: ElectricCurrent) -> Self::Output {
-        let watts = self.to_volts() * rhs.to_amperes();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::electro::ElectricPotential;
+    use crate::electro::ElectricalResistance;
+    use crate::time::Time;
 
     #[test]
     fn test_current_creation() {
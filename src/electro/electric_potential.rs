@@ -1,6 +1,7 @@
 //! Electric potential (voltage) quantity and units.
-use crate::core::Quantity;
-use std::ops::{Div, Mul};
+
+use crate::core::Rational;
+
 crate::quantity! {
     /// A quantity of electric potential (voltage).
     ///
@@ -35,6 +36,7 @@ crate::quantity! {
         name: "ElectricPotential";
         primary: Volts;
         si: Volts;
+        dimensions: [2, 1, -3, -1, 0, 0, 0];
 
         units {
             /// Volts (V) - SI unit
@@ -48,7 +50,7 @@ crate::quantity! {
             /// Microvolts (µV)
             Microvolts {
                 symbol: "µV",
-                factor: 1e-6,
+                factor: Rational::pow10(-6),
                 ctor: microvolts,
                 to: to_microvolts,
                 si: true
@@ -56,7 +58,7 @@ crate::quantity! {
             /// Millivolts (mV)
             Millivolts {
                 symbol: "mV",
-                factor: 1e-3,
+                factor: Rational::pow10(-3),
                 ctor: millivolts,
                 to: to_millivolts,
                 si: true
@@ -80,87 +82,14 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::electric_charge::{ElectricCharge, ElectricChargeUnit};
-use super::electric_current::{ElectricCurrent, ElectricCurrentUnit};
-use super::electrical_resistance::{ElectricalResistance, ElectricalResistanceUnit};
-use super::magnetic_flux::{MagneticFlux, MagneticFluxUnit};
-use crate::energy::{Energy, EnergyUnit, Power};
-use crate::time::Time;
-
-// Potential / Current = Resistance (R = V/I, Ohm's law)
-impl Div<ElectricCurrent> for ElectricPotential {
-    type Output = ElectricalResistance;
-
-    fn div(self, rhs: ElectricCurrent) -> Self::Output {
-        let ohms = self.to_volts() / rhs.to_amperes();
-        ElectricalResistance::new(ohms, ElectricalResistanceUnit::Ohms)
-    }
-}
-
-// Potential / Resistance = Current (I = V/R)
-impl Div<ElectricalResistance> for ElectricPotential {
-    type Output = ElectricCurrent;
-
-    fn div(self, rhs: ElectricalResistance) -> Self::Output {
-        let amperes = self.to_volts() / rhs.to_ohms();
-        ElectricCurrent::new(amperes, ElectricCurrentUnit::Amperes)
-    }
-}
-
-// Potential / Power = Current (I = P/V)
-impl Div<Power> for ElectricPotential {
-    type Output = ElectricCurrent;
-
-    fn div(self, rhs: Power) -> Self::Output {
-        let amperes = rhs.to_watts() / self.to_volts();
-        ElectricCurrent::new(amperes, ElectricCurrentUnit::Amperes)
-    }
-}
-
-// Potential / Energy = Charge (Q = E/V)
-impl Div<Energy> for ElectricPotential {
-    type Output = ElectricCharge;
-
-    fn div(self, rhs: Energy) -> Self::Output {
-        let coulombs = rhs.to_joules() / self.to_volts();
-        ElectricCharge::new(coulombs, ElectricChargeUnit::Coulombs)
-    }
-}
-
-// Potential * Charge = Energy (E = VQ)
-impl Mul<ElectricCharge> for ElectricPotential {
-    type Output = Energy;
-
-    fn mul(self, rhs: ElectricCharge) -> Self::Output {
-        let joules = self.to_volts() * rhs.to_coulombs();
-        Energy::new(joules, EnergyUnit::Joules)
-    }
-}
-
-// Potential * Time = MagneticFlux (Wb = V·s)
-impl Mul<Time> for ElectricPotential {
-    type Output = MagneticFlux;
-
-    fn mul(self, rhs: Time) -> Self::Output {
-        let webers = self.to_volts() * rhs.to_seconds();
-        MagneticFlux::new(webers, MagneticFluxUnit::Webers)
-    }
-}
-
-// Time * Potential = MagneticFlux
-impl Mul<ElectricPotential> for Time {
-    type Output = MagneticFlux;
-
-    fn mul(self, rhs: ElectricPotential) -> Self::Output {
-        let webers = self.to_seconds() * rhs.to_volts();
-        MagneticFlux::new(webers, MagneticFluxUnit::Webers)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::electro::ElectricCharge;
+    use crate::electro::ElectricCurrent;
+    use crate::electro::ElectricalResistance;
+    use crate::time::Time;
 
     #[test]
     fn test_potential_creation() {
@@ -1,6 +1,7 @@
 //! Electrical conductance quantity and units.
-use crate::core::Quantity;
-use std::ops::Div;
+
+use crate::core::Rational;
+
 crate::quantity! {
     /// A quantity of electrical conductance.
     ///
@@ -31,6 +32,7 @@ crate::quantity! {
         name: "ElectricalConductance";
         primary: Siemens;
         si: Siemens;
+        dimensions: [-2, -1, 3, 2, 0, 0, 0];
 
         units {
             /// Siemens (S) - SI unit
@@ -44,7 +46,7 @@ crate::quantity! {
             /// Millisiemens (mS)
             Millisiemens {
                 symbol: "mS",
-                factor: 1e-3,
+                factor: Rational::pow10(-3),
                 ctor: millisiemens,
                 to: to_millisiemens,
                 si: true
@@ -52,7 +54,7 @@ crate::quantity! {
             /// Microsiemens (µS)
             Microsiemens {
                 symbol: "µS",
-                factor: 1e-6,
+                factor: Rational::pow10(-6),
                 ctor: microsiemens,
                 to: to_microsiemens,
                 si: true
@@ -74,20 +76,6 @@ impl ElectricalConductance {
         ElectricalResistance::new(ohms, ElectricalResistanceUnit::Ohms)
     }
 }
-// Cross-quantity operations
-use crate::space::Length;
-
-// Conductance / Length = Conductivity
-use super::conductivity::{Conductivity, ConductivityUnit};
-
-impl Div<Length> for ElectricalConductance {
-    type Output = Conductivity;
-
-    fn div(self, rhs: Length) -> Self::Output {
-        let s_per_m = self.to_siemens() / rhs.to_meters();
-        Conductivity::new(s_per_m, ConductivityUnit::SiemensPerMeter)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::super::electrical_resistance::ElectricalResistance;
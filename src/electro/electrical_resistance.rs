@@ -1,6 +1,7 @@
 //! Electrical resistance quantity and units.
-use crate::core::Quantity;
-use std::ops::Mul;
+
+use crate::core::Rational;
+
 crate::quantity! {
     /// A quantity of electrical resistance.
     ///
@@ -31,6 +32,7 @@ crate::quantity! {
         name: "ElectricalResistance";
         primary: Ohms;
         si: Ohms;
+        dimensions: [2, 1, -3, -2, 0, 0, 0];
 
         units {
             /// Ohms (Ω) - SI unit
@@ -44,7 +46,7 @@ crate::quantity! {
             /// Milliohms (mΩ)
             Milliohms {
                 symbol: "mΩ",
-                factor: 1e-3,
+                factor: Rational::pow10(-3),
                 ctor: milliohms,
                 to: to_milliohms,
                 si: true
@@ -68,30 +70,6 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use crate::space::Length;
-
-// Resistance * Length = Resistivity
-use super::resistivity::{Resistivity, ResistivityUnit};
-
-impl Mul<Length> for ElectricalResistance {
-    type Output = Resistivity;
-
-    fn mul(self, rhs: Length) -> Self::Output {
-        let ohm_meters = self.to_ohms() * rhs.to_meters();
-        Resistivity::new(ohm_meters, ResistivityUnit::OhmMeters)
-    }
-}
-
-// Length * Resistance = Resistivity
-impl Mul<ElectricalResistance> for Length {
-    type Output = Resistivity;
-
-    fn mul(self, rhs: ElectricalResistance) -> Self::Output {
-        let ohm_meters = self.to_meters() * rhs.to_ohms();
-        Resistivity::new(ohm_meters, ResistivityUnit::OhmMeters)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
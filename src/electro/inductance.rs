@@ -1,6 +1,7 @@
 //! Inductance quantity and units.
-use crate::core::Quantity;
-use std::ops::Mul;
+
+use crate::core::Rational;
+
 crate::quantity! {
     /// A quantity of inductance.
     ///
@@ -32,6 +33,7 @@ crate::quantity! {
         name: "Inductance";
         primary: Henrys;
         si: Henrys;
+        dimensions: [2, 1, -2, -2, 0, 0, 0];
 
         units {
             /// Henrys (H) - SI unit
@@ -45,7 +47,7 @@ crate::quantity! {
             /// Microhenrys (µH)
             Microhenrys {
                 symbol: "µH",
-                factor: 1e-6,
+                factor: Rational::pow10(-6),
                 ctor: microhenrys,
                 to: to_microhenrys,
                 si: true
@@ -53,7 +55,7 @@ crate::quantity! {
             /// Millihenrys (mH)
             Millihenrys {
                 symbol: "mH",
-                factor: 1e-3,
+                factor: Rational::pow10(-3),
                 ctor: millihenrys,
                 to: to_millihenrys,
                 si: true
@@ -61,33 +63,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::electric_current::ElectricCurrent;
-use super::magnetic_flux::{MagneticFlux, MagneticFluxUnit};
-
-// Inductance * Current = MagneticFlux (Φ = LI)
-impl Mul<ElectricCurrent> for Inductance {
-    type Output = MagneticFlux;
-
-    fn mul(self, rhs: ElectricCurrent) -> Self::Output {
-        let webers = self.to_henrys() * rhs.to_amperes();
-        MagneticFlux::new(webers, MagneticFluxUnit::Webers)
-    }
-}
-
-// Current * Inductance = MagneticFlux
-impl Mul<Inductance> for ElectricCurrent {
-    type Output = MagneticFlux;
-
-    fn mul(self, rhs: Inductance) -> Self::Output {
-        let webers = self.to_amperes() * rhs.to_henrys();
-        MagneticFlux::new(webers, MagneticFluxUnit::Webers)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::electro::ElectricCurrent;
 
     #[test]
     fn test_inductance_creation() {
@@ -1,6 +1,4 @@
 //! Magnetic flux quantity and units.
-use crate::core::Quantity;
-use std::ops::Div;
 crate::quantity! {
     /// A quantity of magnetic flux.
     ///
@@ -33,6 +31,7 @@ crate::quantity! {
         name: "MagneticFlux";
         primary: Webers;
         si: Webers;
+        dimensions: [2, 1, -2, -1, 0, 0, 0];
 
         units {
             /// Webers (Wb) - SI unit
@@ -46,77 +45,13 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::electric_current::{ElectricCurrent, ElectricCurrentUnit};
-use super::electric_potential::{ElectricPotential, ElectricPotentialUnit};
-use super::inductance::{Inductance, InductanceUnit};
-use super::magnetic_flux_density::{MagneticFluxDensity, MagneticFluxDensityUnit};
-use crate::space::{Area, AreaUnit};
-use crate::time::{Time, TimeUnit};
-
-// MagneticFlux / Area = MagneticFluxDensity (B = Φ/A)
-impl Div<Area> for MagneticFlux {
-    type Output = MagneticFluxDensity;
-
-    fn div(self, rhs: Area) -> Self::Output {
-        let teslas = self.to_webers() / rhs.to_square_meters();
-        MagneticFluxDensity::new(teslas, MagneticFluxDensityUnit::Teslas)
-    }
-}
-
-// MagneticFlux / MagneticFluxDensity = Area (A = Φ/B)
-impl Div<MagneticFluxDensity> for MagneticFlux {
-    type Output = Area;
-
-    fn div(self, rhs: MagneticFluxDensity) -> Self::Output {
-        let m2 = self.to_webers() / rhs.to_teslas();
-        Area::new(m2, AreaUnit::SquareMeters)
-    }
-}
-
-// MagneticFlux / Current = Inductance (L = Φ/I)
-impl Div<ElectricCurrent> for MagneticFlux {
-    type Output = Inductance;
-
-    fn div(self, rhs: ElectricCurrent) -> Self::Output {
-        let henrys = self.to_webers() / rhs.to_amperes();
-        Inductance::new(henrys, InductanceUnit::Henrys)
-    }
-}
-
-// MagneticFlux / Inductance = Current (I = Φ/L)
-impl Div<Inductance> for MagneticFlux {
-    type Output = ElectricCurrent;
-
-    fn div(self, rhs: Inductance) -> Self::Output {
-        let amperes = self.to_webers() / rhs.to_henrys();
-        ElectricCurrent::new(amperes, ElectricCurrentUnit::Amperes)
-    }
-}
-
-// MagneticFlux / Time = Potential (V = dΦ/dt, Faraday's law)
-impl Div<Time> for MagneticFlux {
-    type Output = ElectricPotential;
-
-    fn div(self, rhs: Time) -> Self::Output {
-        let volts = self.to_webers() / rhs.to_seconds();
-        ElectricPotential::new(volts, ElectricPotentialUnit::Volts)
-    }
-}
-
-// MagneticFlux / Potential = Time
-impl Div<ElectricPotential> for MagneticFlux {
-    type Output = Time;
-
-    fn div(self, rhs: ElectricPotential) -> Self::Output {
-        let seconds = self.to_webers() / rhs.to_volts();
-        Time::new(seconds, TimeUnit::Seconds)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::electro::ElectricCurrent;
+    use crate::space::Area;
+    use crate::time::Time;
 
     #[test]
     fn test_flux_creation() {
@@ -1,6 +1,7 @@
 //! Magnetic flux density quantity and units.
-use crate::core::Quantity;
-use std::ops::Mul;
+
+use crate::core::Rational;
+
 crate::quantity! {
     /// A quantity of magnetic flux density.
     ///
@@ -32,6 +33,7 @@ crate::quantity! {
         name: "MagneticFluxDensity";
         primary: Teslas;
         si: Teslas;
+        dimensions: [0, 1, -2, -1, 0, 0, 0];
 
         units {
             /// Teslas (T) - SI unit
@@ -45,7 +47,7 @@ crate::quantity! {
             /// Gauss (G)
             Gauss {
                 symbol: "G",
-                factor: 1e-4,
+                factor: Rational::pow10(-4),
                 ctor: gauss,
                 to: to_gauss,
                 si: false
@@ -53,33 +55,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::magnetic_flux::{MagneticFlux, MagneticFluxUnit};
-use crate::space::Area;
-
-// MagneticFluxDensity * Area = MagneticFlux (Φ = B·A)
-impl Mul<Area> for MagneticFluxDensity {
-    type Output = MagneticFlux;
-
-    fn mul(self, rhs: Area) -> Self::Output {
-        let webers = self.to_teslas() * rhs.to_square_meters();
-        MagneticFlux::new(webers, MagneticFluxUnit::Webers)
-    }
-}
-
-// Area * MagneticFluxDensity = MagneticFlux
-impl Mul<MagneticFluxDensity> for Area {
-    type Output = MagneticFlux;
-
-    fn mul(self, rhs: MagneticFluxDensity) -> Self::Output {
-        let webers = self.to_square_meters() * rhs.to_teslas();
-        MagneticFlux::new(webers, MagneticFluxUnit::Webers)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::space::Area;
 
     #[test]
     fn test_density_creation() {
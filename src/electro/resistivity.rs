@@ -1,6 +1,4 @@
 //! Resistivity quantity and units.
-use crate::core::Quantity;
-use std::ops::Div;
 crate::quantity! {
     /// A quantity of resistivity.
     ///
@@ -32,6 +30,7 @@ crate::quantity! {
         name: "Resistivity";
         primary: OhmMeters;
         si: OhmMeters;
+        dimensions: [3, 1, -3, -2, 0, 0, 0];
 
         units {
             /// Ohm-meters (Ω·m) - SI unit
@@ -45,23 +44,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::electrical_resistance::{ElectricalResistance, ElectricalResistanceUnit};
-use crate::space::Length;
-
-// Resistivity / Length = Resistance
-impl Div<Length> for Resistivity {
-    type Output = ElectricalResistance;
-
-    fn div(self, rhs: Length) -> Self::Output {
-        let ohms = self.to_ohm_meters() / rhs.to_meters();
-        ElectricalResistance::new(ohms, ElectricalResistanceUnit::Ohms)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::space::Length;
 
     #[test]
     fn test_resistivity_creation() {
@@ -1,29 +1,27 @@
 //! Energy quantity and units.
 
-use crate::core::Quantity;
-use crate::mass::{ChemicalAmount, ChemicalAmountUnit, Mass, MassUnit};
-use crate::motion::{Force, ForceUnit};
-use crate::space::{Length, LengthUnit, Volume, VolumeUnit};
-use crate::time::{Time, TimeUnit};
-use std::ops::Div;
+use crate::mass::Mass;
+
+use crate::core::Rational;
 
 // Conversion factors relative to WattHours (primary unit)
-const SECONDS_PER_HOUR: f64 = 3600.0;
+const SECONDS_PER_HOUR: u128 = 3600;
 
 // Joules = Watt-seconds, so 1 Wh = 3600 J
 // Joule conversion factor to Wh = 1/3600
-const JOULE_TO_WH: f64 = 1.0 / SECONDS_PER_HOUR;
+const JOULE_TO_WH: Rational = Rational::new(1, SECONDS_PER_HOUR);
 
-// BTU conversion (1 BTU ≈ 0.293071 Wh)
-const BTU_TO_WH: f64 = 0.2930710701722222;
+// International Table BTU (1 BTU = 1055.05585262 J exactly, ≈ 0.293071 Wh)
+const BTU_TO_J: Rational = Rational::new(105_505_585_262, 100_000_000);
+const BTU_TO_WH: Rational = BTU_TO_J.mul(JOULE_TO_WH);
 
 // Electron-volt (1 eV = 1.602176634e-19 J exactly)
-const EV_TO_J: f64 = 1.602176634e-19;
-const EV_TO_WH: f64 = EV_TO_J * JOULE_TO_WH;
+const EV_TO_J: Rational = Rational::new(1_602_176_634, 10_000_000_000_000_000_000_000_000_000);
+const EV_TO_WH: Rational = EV_TO_J.mul(JOULE_TO_WH);
 
-// Calorie (1 cal = 4.184 J)
-const CAL_TO_J: f64 = 4.184;
-const CAL_TO_WH: f64 = CAL_TO_J * JOULE_TO_WH;
+// Thermochemical calorie (1 cal = 4.184 J exactly)
+const CAL_TO_J: Rational = Rational::new(4184, 1000);
+const CAL_TO_WH: Rational = CAL_TO_J.mul(JOULE_TO_WH);
 crate::quantity! {
     /// A quantity of energy.
     ///
@@ -59,6 +57,7 @@ crate::quantity! {
         name: "Energy";
         primary: WattHours;
         si: Joules;
+        dimensions: [2, 1, -2, 0, 0, 0, 0];
 
         units {
             /// Watt-hours (Wh) - primary unit
@@ -72,7 +71,7 @@ crate::quantity! {
             /// Milliwatt-hours (mWh)
             MilliwattHours {
                 symbol: "mWh",
-                factor: 1e-3,
+                factor: Rational::pow10(-3),
                 ctor: milliwatt_hours,
                 to: to_milliwatt_hours,
                 si: false
@@ -112,7 +111,7 @@ crate::quantity! {
             /// Picojoules (pJ)
             Picojoules {
                 symbol: "pJ",
-                factor: JOULE_TO_WH * 1e-12,
+                factor: JOULE_TO_WH.mul(Rational::pow10(-12)),
                 ctor: picojoules,
                 to: to_picojoules,
                 si: true
@@ -120,7 +119,7 @@ crate::quantity! {
             /// Nanojoules (nJ)
             Nanojoules {
                 symbol: "nJ",
-                factor: JOULE_TO_WH * 1e-9,
+                factor: JOULE_TO_WH.mul(Rational::pow10(-9)),
                 ctor: nanojoules,
                 to: to_nanojoules,
                 si: true
@@ -128,7 +127,7 @@ crate::quantity! {
             /// Microjoules (µJ)
             Microjoules {
                 symbol: "µJ",
-                factor: JOULE_TO_WH * 1e-6,
+                factor: JOULE_TO_WH.mul(Rational::pow10(-6)),
                 ctor: microjoules,
                 to: to_microjoules,
                 si: true
@@ -136,7 +135,7 @@ crate::quantity! {
             /// Millijoules (mJ)
             Millijoules {
                 symbol: "mJ",
-                factor: JOULE_TO_WH * 1e-3,
+                factor: JOULE_TO_WH.mul(Rational::pow10(-3)),
                 ctor: millijoules,
                 to: to_millijoules,
                 si: true
@@ -144,7 +143,7 @@ crate::quantity! {
             /// Kilojoules (kJ)
             Kilojoules {
                 symbol: "kJ",
-                factor: JOULE_TO_WH * 1e3,
+                factor: JOULE_TO_WH.mul(Rational::pow10(3)),
                 ctor: kilojoules,
                 to: to_kilojoules,
                 si: true
@@ -152,7 +151,7 @@ crate::quantity! {
             /// Megajoules (MJ)
             Megajoules {
                 symbol: "MJ",
-                factor: JOULE_TO_WH * 1e6,
+                factor: JOULE_TO_WH.mul(Rational::pow10(6)),
                 ctor: megajoules,
                 to: to_megajoules,
                 si: true
@@ -160,7 +159,7 @@ crate::quantity! {
             /// Gigajoules (GJ)
             Gigajoules {
                 symbol: "GJ",
-                factor: JOULE_TO_WH * 1e9,
+                factor: JOULE_TO_WH.mul(Rational::pow10(9)),
                 ctor: gigajoules,
                 to: to_gigajoules,
                 si: true
@@ -168,7 +167,7 @@ crate::quantity! {
             /// Terajoules (TJ)
             Terajoules {
                 symbol: "TJ",
-                factor: JOULE_TO_WH * 1e12,
+                factor: JOULE_TO_WH.mul(Rational::pow10(12)),
                 ctor: terajoules,
                 to: to_terajoules,
                 si: true
@@ -184,7 +183,8 @@ crate::quantity! {
             /// Thousand BTU (MBtu)
             MBtus {
                 symbol: "MBtu",
-                factor: BTU_TO_WH * 1e3,
+                name: "thousand BTUs",
+                factor: BTU_TO_WH.mul(Rational::pow10(3)),
                 ctor: mbtus,
                 to: to_mbtus,
                 si: false
@@ -192,7 +192,8 @@ crate::quantity! {
             /// Million BTU (MMBtu)
             MMBtus {
                 symbol: "MMBtu",
-                factor: BTU_TO_WH * 1e6,
+                name: "million BTUs",
+                factor: BTU_TO_WH.mul(Rational::pow10(6)),
                 ctor: mmbtus,
                 to: to_mmbtus,
                 si: false
@@ -200,6 +201,7 @@ crate::quantity! {
             /// Electron-volts (eV)
             ElectronVolts {
                 symbol: "eV",
+                name: "electronvolts",
                 factor: EV_TO_WH,
                 ctor: electron_volts,
                 to: to_electron_volts,
@@ -208,7 +210,8 @@ crate::quantity! {
             /// Milli-electron-volts (meV)
             MilliElectronVolts {
                 symbol: "meV",
-                factor: EV_TO_WH * 1e-3,
+                name: "millielectronvolts",
+                factor: EV_TO_WH.mul(Rational::pow10(-3)),
                 ctor: milli_electron_volts,
                 to: to_milli_electron_volts,
                 si: false
@@ -216,7 +219,8 @@ crate::quantity! {
             /// Kilo-electron-volts (keV)
             KiloElectronVolts {
                 symbol: "keV",
-                factor: EV_TO_WH * 1e3,
+                name: "kiloelectronvolts",
+                factor: EV_TO_WH.mul(Rational::pow10(3)),
                 ctor: kilo_electron_volts,
                 to: to_kilo_electron_volts,
                 si: false
@@ -224,7 +228,8 @@ crate::quantity! {
             /// Mega-electron-volts (MeV)
             MegaElectronVolts {
                 symbol: "MeV",
-                factor: EV_TO_WH * 1e6,
+                name: "megaelectronvolts",
+                factor: EV_TO_WH.mul(Rational::pow10(6)),
                 ctor: mega_electron_volts,
                 to: to_mega_electron_volts,
                 si: false
@@ -232,7 +237,8 @@ crate::quantity! {
             /// Giga-electron-volts (GeV)
             GigaElectronVolts {
                 symbol: "GeV",
-                factor: EV_TO_WH * 1e9,
+                name: "gigaelectronvolts",
+                factor: EV_TO_WH.mul(Rational::pow10(9)),
                 ctor: giga_electron_volts,
                 to: to_giga_electron_volts,
                 si: false
@@ -240,7 +246,8 @@ crate::quantity! {
             /// Tera-electron-volts (TeV)
             TeraElectronVolts {
                 symbol: "TeV",
-                factor: EV_TO_WH * 1e12,
+                name: "teraelectronvolts",
+                factor: EV_TO_WH.mul(Rational::pow10(12)),
                 ctor: tera_electron_volts,
                 to: to_tera_electron_volts,
                 si: false
@@ -248,7 +255,7 @@ crate::quantity! {
             /// Ergs (erg) - CGS unit
             Ergs {
                 symbol: "erg",
-                factor: JOULE_TO_WH * 1e-7,
+                factor: JOULE_TO_WH.mul(Rational::pow10(-7)),
                 ctor: ergs,
                 to: to_ergs,
                 si: false
@@ -264,7 +271,7 @@ crate::quantity! {
             /// Kilocalories (kcal)
             Kilocalories {
                 symbol: "kcal",
-                factor: CAL_TO_WH * 1e3,
+                factor: CAL_TO_WH.mul(Rational::pow10(3)),
                 ctor: kilocalories,
                 to: to_kilocalories,
                 si: false
@@ -281,112 +288,6 @@ impl Energy {
         Energy::joules(0.5 * m * v * v)
     }
 }
-// Forward declarations for cross-quantity operations
-// These will be implemented after Power is defined
-use super::energy_density::{EnergyDensity, EnergyDensityUnit};
-use super::molar_energy::{MolarEnergy, MolarEnergyUnit};
-use super::power::{Power, PowerUnit};
-use super::specific_energy::{SpecificEnergy, SpecificEnergyUnit};
-
-// Energy / Time = Power
-impl Div<Time> for Energy {
-    type Output = Power;
-
-    fn div(self, rhs: Time) -> Self::Output {
-        let watts = self.to_joules() / rhs.to_seconds();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
-
-// Energy / Power = Time
-impl Div<Power> for Energy {
-    type Output = Time;
-
-    fn div(self, rhs: Power) -> Self::Output {
-        let seconds = self.to_joules() / rhs.to_watts();
-        Time::new(seconds, TimeUnit::Seconds)
-    }
-}
-
-// Energy / Mass = SpecificEnergy
-impl Div<Mass> for Energy {
-    type Output = SpecificEnergy;
-
-    fn div(self, rhs: Mass) -> Self::Output {
-        let grays = self.to_joules() / rhs.to_kilograms();
-        SpecificEnergy::new(grays, SpecificEnergyUnit::Grays)
-    }
-}
-
-// Energy / SpecificEnergy = Mass
-impl Div<SpecificEnergy> for Energy {
-    type Output = Mass;
-
-    fn div(self, rhs: SpecificEnergy) -> Self::Output {
-        let kg = self.to_joules() / rhs.to_grays();
-        Mass::new(kg, MassUnit::Kilograms)
-    }
-}
-
-// Energy / Volume = EnergyDensity
-impl Div<Volume> for Energy {
-    type Output = EnergyDensity;
-
-    fn div(self, rhs: Volume) -> Self::Output {
-        let jpcm = self.to_joules() / rhs.to_cubic_meters();
-        EnergyDensity::new(jpcm, EnergyDensityUnit::JoulesPerCubicMeter)
-    }
-}
-
-// Energy / EnergyDensity = Volume
-impl Div<EnergyDensity> for Energy {
-    type Output = Volume;
-
-    fn div(self, rhs: EnergyDensity) -> Self::Output {
-        let m3 = self.to_joules() / rhs.to_joules_per_cubic_meter();
-        Volume::new(m3, VolumeUnit::CubicMeters)
-    }
-}
-
-// Energy / ChemicalAmount = MolarEnergy
-impl Div<ChemicalAmount> for Energy {
-    type Output = MolarEnergy;
-
-    fn div(self, rhs: ChemicalAmount) -> Self::Output {
-        let jpm = self.to_joules() / rhs.to_moles();
-        MolarEnergy::new(jpm, MolarEnergyUnit::JoulesPerMole)
-    }
-}
-
-// Energy / MolarEnergy = ChemicalAmount
-impl Div<MolarEnergy> for Energy {
-    type Output = ChemicalAmount;
-
-    fn div(self, rhs: MolarEnergy) -> Self::Output {
-        let mol = self.to_joules() / rhs.to_joules_per_mole();
-        ChemicalAmount::new(mol, ChemicalAmountUnit::Moles)
-    }
-}
-
-// Energy / Force = Length (Work = Force × Distance)
-impl Div<Force> for Energy {
-    type Output = Length;
-
-    fn div(self, rhs: Force) -> Self::Output {
-        let meters = self.to_joules() / rhs.to_newtons();
-        Length::new(meters, LengthUnit::Meters)
-    }
-}
-
-// Energy / Length = Force
-impl Div<Length> for Energy {
-    type Output = Force;
-
-    fn div(self, rhs: Length) -> Self::Output {
-        let newtons = self.to_joules() / rhs.to_meters();
-        Force::new(newtons, ForceUnit::Newtons)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -416,6 +317,8 @@ mod tests {
         let e = Energy::btus(1.0);
         // 1 BTU ≈ 1055.06 J
         assert!((e.to_joules() - 1055.06).abs() < 1.0);
+        assert_eq!(e.to_joules(), 1055.05585262);
+        assert!(EnergyUnit::BritishThermalUnits.is_exact());
     }
 
     #[test]
@@ -430,6 +333,7 @@ mod tests {
         let e = Energy::kilocalories(1.0);
         // 1 kcal = 4184 J
         assert!((e.to_joules() - 4184.0).abs() < 1.0);
+        assert_eq!(e.to_joules(), 4184.0);
     }
 
     #[test]
@@ -1,6 +1,4 @@
 //! Energy density quantity and units.
-use crate::core::Quantity;
-use std::ops::Mul;
 crate::quantity! {
     /// A quantity of energy density (energy per unit volume).
     ///
@@ -23,6 +21,7 @@ crate::quantity! {
         name: "EnergyDensity";
         primary: JoulesPerCubicMeter;
         si: JoulesPerCubicMeter;
+        dimensions: [-1, 1, -2, 0, 0, 0, 0];
 
         units {
             /// Joules per cubic meter (J/m³) - SI unit
@@ -36,33 +35,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::energy::{Energy, EnergyUnit};
-use crate::space::Volume;
-
-// EnergyDensity * Volume = Energy
-impl Mul<Volume> for EnergyDensity {
-    type Output = Energy;
-
-    fn mul(self, rhs: Volume) -> Self::Output {
-        let joules = self.to_joules_per_cubic_meter() * rhs.to_cubic_meters();
-        Energy::new(joules, EnergyUnit::Joules)
-    }
-}
-
-// Volume * EnergyDensity = Energy
-impl Mul<EnergyDensity> for Volume {
-    type Output = Energy;
-
-    fn mul(self, rhs: EnergyDensity) -> Self::Output {
-        let joules = rhs.to_joules_per_cubic_meter() * self.to_cubic_meters();
-        Energy::new(joules, EnergyUnit::Joules)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::space::Volume;
 
     #[test]
     fn test_energy_density_creation() {
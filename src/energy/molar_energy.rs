@@ -1,8 +1,5 @@
 //! Molar energy quantity and units.
 
-use crate::core::Quantity;
-use crate::mass::ChemicalAmount;
-use std::ops::Mul;
 crate::quantity! {
     /// A quantity of molar energy (energy per chemical amount).
     ///
@@ -25,6 +22,7 @@ crate::quantity! {
         name: "MolarEnergy";
         primary: JoulesPerMole;
         si: JoulesPerMole;
+        dimensions: [2, 1, -2, 0, 0, -1, 0];
 
         units {
             /// Joules per mole (J/mol) - SI unit
@@ -46,32 +44,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::energy::{Energy, EnergyUnit};
-
-// MolarEnergy * ChemicalAmount = Energy
-impl Mul<ChemicalAmount> for MolarEnergy {
-    type Output = Energy;
-
-    fn mul(self, rhs: ChemicalAmount) -> Self::Output {
-        let joules = self.to_joules_per_mole() * rhs.to_moles();
-        Energy::new(joules, EnergyUnit::Joules)
-    }
-}
-
-// ChemicalAmount * MolarEnergy = Energy
-impl Mul<MolarEnergy> for ChemicalAmount {
-    type Output = Energy;
-
-    fn mul(self, rhs: MolarEnergy) -> Self::Output {
-        let joules = rhs.to_joules_per_mole() * self.to_moles();
-        Energy::new(joules, EnergyUnit::Joules)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::mass::ChemicalAmount;
 
     #[test]
     fn test_molar_energy_creation() {
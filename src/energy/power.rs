@@ -1,14 +1,17 @@
 //! Power quantity and units.
 
-use crate::core::Quantity;
-use crate::time::{Time, TimeUnit};
-use std::ops::{Div, Mul};
+use crate::core::Rational;
 
 // Conversion factors relative to Watts
-const BTU_TO_J: f64 = 1055.06;
-const SECONDS_PER_HOUR: f64 = 3600.0;
-const BTU_PER_HOUR_TO_W: f64 = BTU_TO_J / SECONDS_PER_HOUR;
-const HORSEPOWER_TO_W: f64 = 745.7; // Mechanical horsepower
+// International Table BTU, as for `Energy` (1 BTU = 1055.05585262 J exactly)
+const BTU_TO_J: Rational = Rational::new(105_505_585_262, 100_000_000);
+const SECONDS_PER_HOUR: Rational = Rational::integer(3600);
+const BTU_PER_HOUR_TO_W: Rational = BTU_TO_J.div(SECONDS_PER_HOUR);
+const FOOT_TO_M: Rational = Rational::new(3048, 10_000);
+const POUND_FORCE_TO_N: Rational =
+    Rational::new(45_359_237, 100_000_000).mul(Rational::new(980_665, 100_000));
+// Mechanical horsepower: 550 ft·lbf/s ≈ 745.69987 W
+const HORSEPOWER_TO_W: Rational = FOOT_TO_M.mul(POUND_FORCE_TO_N).mul(Rational::integer(550));
 const SOLAR_LUMINOSITY_TO_W: f64 = 3.828e26;
 crate::quantity! {
     /// A quantity of power.
@@ -35,6 +38,7 @@ crate::quantity! {
         name: "Power";
         primary: Watts;
         si: Watts;
+        dimensions: [2, 1, -3, 0, 0, 0, 0];
 
         units {
             /// Watts (W) - SI unit
@@ -48,7 +52,7 @@ crate::quantity! {
             /// Milliwatts (mW)
             Milliwatts {
                 symbol: "mW",
-                factor: 1e-3,
+                factor: Rational::pow10(-3),
                 ctor: milliwatts,
                 to: to_milliwatts,
                 si: true
@@ -80,6 +84,7 @@ crate::quantity! {
             /// BTU per hour
             BtusPerHour {
                 symbol: "BTU/h",
+                name: "BTUs per hour",
                 factor: BTU_PER_HOUR_TO_W,
                 ctor: btus_per_hour,
                 to: to_btus_per_hour,
@@ -88,7 +93,7 @@ crate::quantity! {
             /// Ergs per second
             ErgsPerSecond {
                 symbol: "erg/s",
-                factor: 1e-7,
+                factor: Rational::pow10(-7),
                 ctor: ergs_per_second,
                 to: to_ergs_per_second,
                 si: false
@@ -112,75 +117,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::energy::{Energy, EnergyUnit};
-use super::power_density::{PowerDensity, PowerDensityUnit};
-use super::power_ramp::{PowerRamp, PowerRampUnit};
-use crate::space::{Volume, VolumeUnit};
-
-// Power * Time = Energy
-impl Mul<Time> for Power {
-    type Output = Energy;
-
-    fn mul(self, rhs: Time) -> Self::Output {
-        let joules = self.to_watts() * rhs.to_seconds();
-        Energy::new(joules, EnergyUnit::Joules)
-    }
-}
-
-// Time * Power = Energy
-impl Mul<Power> for Time {
-    type Output = Energy;
-
-    fn mul(self, rhs: Power) -> Self::Output {
-        let joules = rhs.to_watts() * self.to_seconds();
-        Energy::new(joules, EnergyUnit::Joules)
-    }
-}
-
-// Power / Time = PowerRamp
-impl Div<Time> for Power {
-    type Output = PowerRamp;
-
-    fn div(self, rhs: Time) -> Self::Output {
-        let wph = self.to_watts() / rhs.to_hours();
-        PowerRamp::new(wph, PowerRampUnit::WattsPerHour)
-    }
-}
-
-// Power / PowerRamp = Time
-impl Div<PowerRamp> for Power {
-    type Output = Time;
-
-    fn div(self, rhs: PowerRamp) -> Self::Output {
-        let hours = self.to_watts() / rhs.to_watts_per_hour();
-        Time::new(hours, TimeUnit::Hours)
-    }
-}
-
-// Power / Volume = PowerDensity
-impl Div<Volume> for Power {
-    type Output = PowerDensity;
-
-    fn div(self, rhs: Volume) -> Self::Output {
-        let wpcm = self.to_watts() / rhs.to_cubic_meters();
-        PowerDensity::new(wpcm, PowerDensityUnit::WattsPerCubicMeter)
-    }
-}
-
-// Power / PowerDensity = Volume
-impl Div<PowerDensity> for Power {
-    type Output = Volume;
-
-    fn div(self, rhs: PowerDensity) -> Self::Output {
-        let m3 = self.to_watts() / rhs.to_watts_per_cubic_meter();
-        Volume::new(m3, VolumeUnit::CubicMeters)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::time::Time;
 
     #[test]
     fn test_power_creation() {
@@ -201,8 +142,9 @@ mod tests {
     #[test]
     fn test_horsepower_conversion() {
         let p = Power::horsepower(1.0);
-        // 1 hp ≈ 745.7 W
-        assert!((p.to_watts() - 745.7).abs() < 1.0);
+        // 1 hp = 550 ft·lbf/s = 745.69987158227022 W exactly
+        assert_eq!(p.to_watts(), 745.699_871_582_270_2);
+        assert_eq!(PowerUnit::Horsepower.exact_factor(), Some(HORSEPOWER_TO_W));
     }
 
     #[test]
@@ -222,4 +164,16 @@ mod tests {
         // 100 kW / 2 h = 50 kW/h = 50000 W/h
         assert!((pr.to_watts_per_hour() - 50000.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_btu_matches_energy() {
+        use crate::energy::Energy;
+
+        assert!(PowerUnit::BtusPerHour.is_exact());
+        assert_eq!(Power::btus_per_hour(3600.0).to_watts(), 1055.05585262);
+        assert_eq!(
+            Power::btus_per_hour(1.0) * Time::hours(1.0),
+            Energy::btus(1.0)
+        );
+    }
 }
@@ -1,6 +1,4 @@
 //! Power density quantity and units.
-use crate::core::Quantity;
-use std::ops::Mul;
 crate::quantity! {
     /// A quantity of power density (power per unit volume).
     ///
@@ -23,6 +21,7 @@ crate::quantity! {
         name: "PowerDensity";
         primary: WattsPerCubicMeter;
         si: WattsPerCubicMeter;
+        dimensions: [-1, 1, -3, 0, 0, 0, 0];
 
         units {
             /// Watts per cubic meter (W/m³) - SI unit
@@ -36,33 +35,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::power::{Power, PowerUnit};
-use crate::space::Volume;
-
-// PowerDensity * Volume = Power
-impl Mul<Volume> for PowerDensity {
-    type Output = Power;
-
-    fn mul(self, rhs: Volume) -> Self::Output {
-        let watts = self.to_watts_per_cubic_meter() * rhs.to_cubic_meters();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
-
-// Volume * PowerDensity = Power
-impl Mul<PowerDensity> for Volume {
-    type Output = Power;
-
-    fn mul(self, rhs: PowerDensity) -> Self::Output {
-        let watts = rhs.to_watts_per_cubic_meter() * self.to_cubic_meters();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::space::Volume;
 
     #[test]
     fn test_power_density_creation() {
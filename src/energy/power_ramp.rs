@@ -1,8 +1,5 @@
 //! Power ramp quantity and units.
 
-use crate::core::Quantity;
-use crate::time::Time;
-use std::ops::Mul;
 crate::quantity! {
     /// A quantity of power ramp (rate of power change).
     ///
@@ -28,6 +25,8 @@ crate::quantity! {
         name: "PowerRamp";
         primary: WattsPerHour;
         si: WattsPerHour;
+        dimensions: [2, 1, -4, 0, 0, 0, 0];
+        coherent: 3600.0;
 
         units {
             /// Watts per hour (W/h) - primary unit
@@ -81,35 +80,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::power::{Power, PowerUnit};
-
-// PowerRamp * Time = Power
-impl Mul<Time> for PowerRamp {
-    type Output = Power;
-
-    fn mul(self, rhs: Time) -> Self::Output {
-        let watts = self.to_watts_per_hour() * rhs.to_hours();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
-
-// Time * PowerRamp = Power
-impl Mul<PowerRamp> for Time {
-    type Output = Power;
-
-    fn mul(self, rhs: PowerRamp) -> Self::Output {
-        let watts = rhs.to_watts_per_hour() * self.to_hours();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
-
-// PowerRamp / Power = 1/Time (returns frequency-like, but we return Time for simplicity)
-// Note: This is Power / PowerRamp = Time, implemented in power.rs
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::time::Time;
 
     #[test]
     fn test_power_ramp_creation() {
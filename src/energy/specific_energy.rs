@@ -1,8 +1,7 @@
 //! Specific energy quantity and units.
 
-use crate::core::Quantity;
-use crate::mass::Mass;
-use std::ops::Mul;
+use crate::core::Rational;
+
 crate::quantity! {
     /// A quantity of specific energy (energy per unit mass).
     ///
@@ -28,6 +27,7 @@ crate::quantity! {
         name: "SpecificEnergy";
         primary: Grays;
         si: Grays;
+        dimensions: [2, 0, -2, 0, 0, 0, 0];
 
         units {
             /// Grays (Gy) - SI unit (J/kg)
@@ -41,7 +41,7 @@ crate::quantity! {
             /// Rads (rad) - CGS unit
             Rads {
                 symbol: "rad",
-                factor: 0.01,
+                factor: Rational::pow10(-2),
                 ctor: rads,
                 to: to_rads,
                 si: false
@@ -49,7 +49,7 @@ crate::quantity! {
             /// Ergs per gram (erg/g)
             ErgsPerGram {
                 symbol: "erg/g",
-                factor: 0.0001,
+                factor: Rational::pow10(-4),
                 ctor: ergs_per_gram,
                 to: to_ergs_per_gram,
                 si: false
@@ -57,32 +57,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::energy::{Energy, EnergyUnit};
-
-// SpecificEnergy * Mass = Energy
-impl Mul<Mass> for SpecificEnergy {
-    type Output = Energy;
-
-    fn mul(self, rhs: Mass) -> Self::Output {
-        let joules = self.to_grays() * rhs.to_kilograms();
-        Energy::new(joules, EnergyUnit::Joules)
-    }
-}
-
-// Mass * SpecificEnergy = Energy
-impl Mul<SpecificEnergy> for Mass {
-    type Output = Energy;
-
-    fn mul(self, rhs: SpecificEnergy) -> Self::Output {
-        let joules = rhs.to_grays() * self.to_kilograms();
-        Energy::new(joules, EnergyUnit::Joules)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::mass::Mass;
 
     #[test]
     fn test_specific_energy_creation() {
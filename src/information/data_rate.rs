@@ -1,9 +1,9 @@
 //! DataRate quantity and units.
-use crate::core::Quantity;
-use std::ops::{Div, Mul};
+
+use crate::core::Rational;
 
 // Conversion factors relative to BytesPerSecond
-const BITS_PER_BYTE: f64 = 8.0;
+const BITS_PER_BYTE: Rational = Rational::integer(8);
 crate::quantity! {
     /// A quantity of data rate.
     ///
@@ -29,6 +29,9 @@ crate::quantity! {
         name: "DataRate";
         primary: BytesPerSecond;
         si: BytesPerSecond;
+        dimensions: [0, 0, -1, 0, 0, 0, 0];
+        information: 1;
+        families: [BYTES, BITS];
 
         units {
             /// Bytes per second (B/s) - primary unit
@@ -42,7 +45,7 @@ crate::quantity! {
             /// Bits per second (bps)
             BitsPerSecond {
                 symbol: "bps",
-                factor: 1.0 / BITS_PER_BYTE,
+                factor: Rational::integer(1).div(BITS_PER_BYTE),
                 ctor: bits_per_second,
                 to: to_bits_per_second,
                 si: false
@@ -74,7 +77,7 @@ crate::quantity! {
             /// Kilobits per second (Kbps)
             KilobitsPerSecond {
                 symbol: "Kbps",
-                factor: 1e3 / BITS_PER_BYTE,
+                factor: Rational::pow10(3).div(BITS_PER_BYTE),
                 ctor: kilobits_per_second,
                 to: to_kilobits_per_second,
                 si: false
@@ -82,7 +85,7 @@ crate::quantity! {
             /// Megabits per second (Mbps)
             MegabitsPerSecond {
                 symbol: "Mbps",
-                factor: 1e6 / BITS_PER_BYTE,
+                factor: Rational::pow10(6).div(BITS_PER_BYTE),
                 ctor: megabits_per_second,
                 to: to_megabits_per_second,
                 si: false
@@ -90,7 +93,7 @@ crate::quantity! {
             /// Gigabits per second (Gbps)
             GigabitsPerSecond {
                 symbol: "Gbps",
-                factor: 1e9 / BITS_PER_BYTE,
+                factor: Rational::pow10(9).div(BITS_PER_BYTE),
                 ctor: gigabits_per_second,
                 to: to_gigabits_per_second,
                 si: false
@@ -98,43 +101,31 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::information::{Information, InformationUnit};
-use crate::time::{Time, TimeUnit};
 
-// DataRate * Time = Information
-impl Mul<Time> for DataRate {
-    type Output = Information;
+impl DataRateUnit {
+    /// Bytes per second and its decimal multiples.
+    pub const BYTES: &'static [DataRateUnit] = &[
+        DataRateUnit::BytesPerSecond,
+        DataRateUnit::KilobytesPerSecond,
+        DataRateUnit::MegabytesPerSecond,
+        DataRateUnit::GigabytesPerSecond,
+    ];
 
-    fn mul(self, rhs: Time) -> Self::Output {
-        let bytes = self.to_bytes_per_second() * rhs.to_seconds();
-        Information::new(bytes, InformationUnit::Bytes)
-    }
+    /// Bits per second and its decimal multiples.
+    pub const BITS: &'static [DataRateUnit] = &[
+        DataRateUnit::BitsPerSecond,
+        DataRateUnit::KilobitsPerSecond,
+        DataRateUnit::MegabitsPerSecond,
+        DataRateUnit::GigabitsPerSecond,
+    ];
 }
 
-// Time * DataRate = Information
-impl Mul<DataRate> for Time {
-    type Output = Information;
-
-    fn mul(self, rhs: DataRate) -> Self::Output {
-        let bytes = rhs.to_bytes_per_second() * self.to_seconds();
-        Information::new(bytes, InformationUnit::Bytes)
-    }
-}
-
-// Information / DataRate = Time
-impl Div<DataRate> for Information {
-    type Output = Time;
-
-    fn div(self, rhs: DataRate) -> Self::Output {
-        let seconds = self.to_bytes() / rhs.to_bytes_per_second();
-        Time::new(seconds, TimeUnit::Seconds)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::information::Information;
+    use crate::time::Time;
 
     #[test]
     fn test_data_rate_creation() {
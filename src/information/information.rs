@@ -1,9 +1,9 @@
 //! Information quantity and units.
-use crate::core::Quantity;
-use std::ops::Div;
+
+use crate::core::Rational;
 
 // Conversion factors relative to Bytes
-const BITS_PER_BYTE: f64 = 8.0;
+const BITS_PER_BYTE: Rational = Rational::integer(8);
 crate::quantity! {
     /// A quantity of information.
     ///
@@ -25,6 +25,9 @@ crate::quantity! {
         name: "Information";
         primary: Bytes;
         si: Bytes;
+        dimensions: [0, 0, 0, 0, 0, 0, 0];
+        information: 1;
+        families: [BINARY_BYTES, DECIMAL_BYTES, BITS];
 
         units {
             /// Bytes (B) - primary unit
@@ -38,7 +41,7 @@ crate::quantity! {
             /// Bits (bit)
             Bits {
                 symbol: "bit",
-                factor: 1.0 / BITS_PER_BYTE,
+                factor: Rational::integer(1).div(BITS_PER_BYTE),
                 ctor: bits,
                 to: to_bits,
                 si: false
@@ -46,6 +49,7 @@ crate::quantity! {
             /// Kilobytes (KB) - 1000 bytes
             Kilobytes {
                 symbol: "KB",
+                aliases: ["kB"],
                 factor: 1e3,
                 ctor: kilobytes,
                 to: to_kilobytes,
@@ -142,7 +146,8 @@ crate::quantity! {
             /// Kilobits (Kbit) - 1000 bits
             Kilobits {
                 symbol: "Kbit",
-                factor: 1e3 / BITS_PER_BYTE,
+                aliases: ["kbit"],
+                factor: Rational::pow10(3).div(BITS_PER_BYTE),
                 ctor: kilobits,
                 to: to_kilobits,
                 si: false
@@ -150,7 +155,7 @@ crate::quantity! {
             /// Megabits (Mbit) - 1000² bits
             Megabits {
                 symbol: "Mbit",
-                factor: 1e6 / BITS_PER_BYTE,
+                factor: Rational::pow10(6).div(BITS_PER_BYTE),
                 ctor: megabits,
                 to: to_megabits,
                 si: false
@@ -158,7 +163,7 @@ crate::quantity! {
             /// Gigabits (Gbit) - 1000³ bits
             Gigabits {
                 symbol: "Gbit",
-                factor: 1e9 / BITS_PER_BYTE,
+                factor: Rational::pow10(9).div(BITS_PER_BYTE),
                 ctor: gigabits,
                 to: to_gigabits,
                 si: false
@@ -166,7 +171,7 @@ crate::quantity! {
             /// Terabits (Tbit) - 1000⁴ bits
             Terabits {
                 symbol: "Tbit",
-                factor: 1e12 / BITS_PER_BYTE,
+                factor: Rational::pow10(12).div(BITS_PER_BYTE),
                 ctor: terabits,
                 to: to_terabits,
                 si: false
@@ -174,23 +179,45 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::data_rate::{DataRate, DataRateUnit};
-use crate::time::Time;
 
-// Information / Time = DataRate
-impl Div<Time> for Information {
-    type Output = DataRate;
+impl InformationUnit {
+    /// The byte and its binary multiples: B, KiB, MiB, GiB, TiB, PiB and EiB.
+    pub const BINARY_BYTES: &'static [InformationUnit] = &[
+        InformationUnit::Bytes,
+        InformationUnit::Kibibytes,
+        InformationUnit::Mebibytes,
+        InformationUnit::Gibibytes,
+        InformationUnit::Tebibytes,
+        InformationUnit::Pebibytes,
+        InformationUnit::Exbibytes,
+    ];
 
-    fn div(self, rhs: Time) -> Self::Output {
-        let bps = self.to_bytes() / rhs.to_seconds();
-        DataRate::new(bps, DataRateUnit::BytesPerSecond)
-    }
+    /// The byte and its decimal multiples: B, KB, MB, GB, TB, PB and EB.
+    pub const DECIMAL_BYTES: &'static [InformationUnit] = &[
+        InformationUnit::Bytes,
+        InformationUnit::Kilobytes,
+        InformationUnit::Megabytes,
+        InformationUnit::Gigabytes,
+        InformationUnit::Terabytes,
+        InformationUnit::Petabytes,
+        InformationUnit::Exabytes,
+    ];
+
+    /// The bit and its decimal multiples: bit, Kbit, Mbit, Gbit and Tbit.
+    pub const BITS: &'static [InformationUnit] = &[
+        InformationUnit::Bits,
+        InformationUnit::Kilobits,
+        InformationUnit::Megabits,
+        InformationUnit::Gigabits,
+        InformationUnit::Terabits,
+    ];
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::time::Time;
 
     #[test]
     fn test_information_creation() {
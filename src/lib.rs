@@ -43,8 +43,25 @@
 //! - **12 domain modules**: time, space, mass, motion, energy, thermal, electro, information, radio, photo, market
 //! - **Ergonomic DSL**: `100.0.meters()`, `5.0.seconds()`, etc.
 //! - **Extensive unit coverage**: 200+ units across all domains
+//! - **Runtime dimensions**: `DynQuantity` for derived quantities without a static type
+//! - **Custom quantities**: [`quantity!`] defines new quantity types that work like the built-in ones
+//! - **Time series**: `QuantitySeries` with integration, differentiation and resampling
+//! - **Uncertainty**: `Measured<Q>` propagates standard uncertainties through arithmetic
+//! - **Intervals**: `Interval<Q>` gives guaranteed bounds for worst-case tolerance analysis
+//! - **Generic storage**: quantities default to `f64` and also work over `f32` or any [`Scalar`]
+//! - **`no_std`**: runs on microcontrollers without an allocator, with `libm` for float functions
+//! - **Exact factors**: units defined exactly (inch, pound, calorie, BTU) convert through rationals and round once
+//! - **Total ordering**: `OrderedQuantity<Q>` (via `q.total()`) implements `Ord`, `Eq` and `Hash` for sorting and map keys
+//! - **Formatting**: `Display` honors precision, sign and width; `q.display()` adds significant figures, engineering notation and long unit names
+//! - **Readable units**: `q.to_best_unit()` and `q.display_auto()` pick the unit that reads best, restricted by a `UnitPolicy`
+//! - **Composite units**: `CompositeUnit` writes and parses "5 ft 11 in", "1h 23m 4s" and "12° 30′ 15″"
+//! - **Unit aliases**: parsing accepts aliases and long names ("10 metres"), optionally ignoring case or using ASCII spellings
+//! - **Parse diagnostics**: parse errors carry a kind, the span of the offending token and "did you mean" suggestions
+//! - **Locale-aware numbers**: `NumberFormat` sets the decimal and group separators for parsing and formatting ("1.234,5 kg")
 //! - **Approximate equality**: `approx_eq` for floating-point comparisons
 //! - **Temperature**: Special scale vs. degree conversions (Kelvin, Celsius, Fahrenheit, Rankine)
+//! - **Decibels**: `Level<Q>` in dBm, dBW, dBV, dBµV and dB SPL, and `Gain` in dB or nepers
+//! - **Affine points**: `Temperature - Temperature = TemperatureDelta`, and `Point<Q>` for positions, instants and gauge pressures
 //! - **Financial**: Money, Currency, Exchange Rates, and generic `Price<Q>`
 //!
 //! ## Modules
@@ -52,9 +69,9 @@
 //! - [`core`] - Core traits (Quantity, UnitOfMeasure, Dimension), dimensionless, ranges, ratios
 //! - [`systems`] - Metric and binary prefix systems
 //! - [`time`] - Time and frequency
-//! - [`space`] - Length, area, volume, angle, solid angle
+//! - [`space`] - Length, area, volume, angle, solid angle, fuel economy
 //! - [`mass`] - Mass, density, area density, chemical amount, moment of inertia
-//! - [`motion`] - Velocity, acceleration, force, momentum, pressure
+//! - [`motion`] - Velocity, acceleration, force, momentum, pressure, volume flow
 //! - [`energy`] - Energy, power, power ramp, specific energy, energy density, molar energy
 //! - [`thermal`] - Temperature (with scale/degree conversions), thermal capacity
 //! - [`electro`] - Current, charge, potential, resistance, capacitance, inductance, magnetic flux
@@ -62,27 +79,72 @@
 //! - [`radio`] - Activity, dose, irradiance, radiance, spectral power, particle flux
 //! - [`photo`] - Luminous intensity, flux, illuminance, luminance, luminous energy
 //! - [`market`] - Money, currency, exchange rates, generic Price&lt;Q&gt;
+//!
+//! ## `no_std`
+//!
+//! The `std` feature is enabled by default. Without it the crate is
+//! `#![no_std]` and takes its float functions (trigonometry for `Angle`,
+//! rounding, square roots) from [`libm`](https://docs.rs/libm), so the
+//! `libm` feature must be enabled instead:
+//!
+//! ```toml
+//! rquants = { version = "0.1", default-features = false, features = ["libm"] }
+//! ```
+//!
+//! Every quantity type, its units, conversions, arithmetic, cross-quantity
+//! operators, `Display`, `DynQuantity`, `Measured` and `Interval` work
+//! without an allocator. The `alloc` feature adds the items that allocate:
+//!
+//! - Parsing (`FromStr`, [`Dimension::parse`]) and the error types
+//! - Fallible conversions such as `TryFrom<DynQuantity>`
+//! - `QuantitySeries`, `QuantityRange::divide` and `Interval::new`
+//! - The [`market`] module
+//! - serde support (the `serde` feature enables `alloc`)
+//!
+//! Compound unit expressions such as `"9.81 m/s^2"` and
+//! `unit_expr::register` need `std`; with `alloc` alone, parsing accepts
+//! each dimension's own unit symbols.
 
 // Module names intentionally mirror their parent (e.g., energy::energy, mass::mass)
 // to match the Scala squants source structure.
 #![allow(clippy::module_inception)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("rquants needs either the `std` feature or, under `no_std`, the `libm` feature");
 
 pub mod core;
 pub mod electro;
 pub mod energy;
 pub mod information;
 mod macros;
+#[cfg(feature = "alloc")]
 pub mod market;
 pub mod mass;
+mod math;
 pub mod motion;
 pub mod photo;
 pub mod prelude;
 pub mod radio;
+mod relations;
 pub mod space;
 pub mod systems;
 pub mod thermal;
 pub mod time;
 
+/// Re-exports used by exported macros. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::string::String;
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
+
 // Re-export commonly used items at crate root
-pub use crate::core::error::{QuantityError, QuantityParseError};
-pub use crate::core::{Dimension, Quantity, UnitOfMeasure};
+#[cfg(feature = "alloc")]
+pub use crate::core::error::{ParseErrorKind, QuantityError, QuantityParseError};
+pub use crate::core::{Dimension, Quantity, Scalar, UnitOfMeasure};
@@ -1,32 +1,335 @@
-//! Internal macros for generating quantity boilerplate.
-
-#[doc(hidden)]
+//! Macros for defining quantity types.
+
+/// Defines a quantity type, its unit enum, dimension metadata and DSL trait.
+///
+/// Every built-in quantity in this crate is generated by this macro, and
+/// downstream crates can use it to define their own quantities that behave
+/// exactly like the built-in ones.
+///
+/// # Syntax
+///
+/// ```text
+/// rquants::quantity! {
+///     /// Doc comments and attributes for the quantity struct.
+///     pub quantity Name {
+///         unit: NameUnit;                 // unit enum to generate
+///         dimension: NameDimension;       // dimension struct to generate
+///         conversions: NameConversions;   // optional: DSL trait for f64
+///         name: "Name";                   // human-readable dimension name
+///         primary: PrimaryVariant;        // unit with factor 1.0
+///         si: SiVariant;                  // SI unit of the dimension
+///         dimensions: [L, M, T, I, Θ, N, J]; // SI base-dimension exponents
+///         coherent: 3600.0;               // optional: see below
+///         angle: 1;                       // optional: plane-angle exponent
+///         information: 1;                 // optional: information exponent
+///         families: [METRIC, IMPERIAL];   // optional: rescaling groups
+///         matching: UnitMatching::relaxed(); // optional
+///
+///         units {
+///             /// Doc comment for the unit variant and its methods.
+///             Variant {
+///                 symbol: "sym",          // display and parsing symbol
+///                 name: "names",          // optional: long name(s)
+///                 aliases: ["alias"],     // optional: other spellings
+///                 factor: 1.0,            // primary units per unit
+///                 offset: 0.0,            // optional: zero in primary units
+///                 convert: (to, from),    // optional: non-linear functions
+///                 ctor: constructor_name, // `Name::constructor_name(1.0)`
+///                 to: to_method_name,     // `q.to_method_name()`
+///                 si: true                // whether this is an SI unit
+///             },
+///         }
+///
+///         relations {                     // optional: operators
+///             Name * Other => Product;
+///             Name / Other => Quotient;
+///         }
+///     }
+/// }
+/// ```
+///
+/// `factor` is any [`ConversionFactor`](crate::core::ConversionFactor): an
+/// `f64`, which counts as exact only when it is a whole number, or a
+/// [`Rational`](crate::core::Rational) for units defined as an exact fraction
+/// of the primary unit, like the inch. Conversions between exact units
+/// combine their factors before rounding.
+///
+/// A unit with an `offset` converts to the primary unit as
+/// `value * factor + offset`, like a temperature scale or gauge pressure.
+/// `convert` replaces both with a pair of `fn(f64) -> f64` functions (or
+/// non-capturing closures) converting a value to and from the primary unit;
+/// `factor` is then unused and conventionally 1.0. Arithmetic on a quantity
+/// whose unit has an offset or conversion functions is carried out in the
+/// primary unit, and the result is returned in the primary unit.
+///
+/// A unit's `name` is its plural long name or a `("names", "name")` pair.
+/// Without it, the long name is derived from the variant, so
+/// `KilometersPerHour` reads "kilometers per hour" and, in the singular,
+/// "kilometer per hour" (see [`UnitName`](crate::core::UnitName)).
+///
+/// Parsing accepts a unit's symbol, its `aliases` and its long name in the
+/// plural or singular. `matching` additionally lets
+/// [`Dimension::parse`](crate::Dimension::parse) fall back to ASCII
+/// spellings of symbols or ignore case (see
+/// [`UnitMatching`](crate::core::UnitMatching)).
+///
+/// Each entry in `families` names an associated constant of the unit enum
+/// (a `&'static [Unit]`, defined next to the macro invocation or `ALL`).
+/// [`Quantity::to_best_unit`](crate::Quantity::to_best_unit) rescales a
+/// quantity within the first family containing its unit, so metric lengths
+/// stay metric and imperial lengths stay imperial.
+///
+/// `coherent` is only needed when the SI unit is not coherent (for example
+/// `PowerRamp`, whose SI-flavoured unit is W/h rather than W/s). Each entry in
+/// `relations` generates exactly one operator; use [`relation!`] to generate
+/// every direction of a relation at once. Relations multiply or divide the
+/// values in the coherent SI units and return the output in its SI unit, so
+/// `Power * Time` gives joules. They are generic over the scalar type, so
+/// every type involved must take a scalar type parameter as the generated
+/// quantities and [`Dimensionless`](crate::core::Dimensionless) do. Outside
+/// this crate, the orphan rules require the left-hand side or the right-hand
+/// side of each relation to be a local type.
+///
+/// # Generated items
+///
+/// - The unit enum with `ALL`, `symbol`, `name`, `aliases`,
+///   `conversion_factor`, `exact_factor`, `is_exact`, `conversion_offset`,
+///   `is_primary`, `is_si`, `is_linear`, `family`, `Display` and
+///   [`UnitOfMeasure`](crate::UnitOfMeasure)
+/// - The quantity struct, generic over its [`Scalar`](crate::Scalar) storage
+///   type with `f64` as the default, with one `f64` constructor and one
+///   `to_*` method per unit, `from_primary`, `cast`,
+///   [`Quantity`](crate::Quantity), `Display`, comparison, `Hash`,
+///   arithmetic, `Sum`, conversions to `DynQuantity`, and serde support when
+///   the `serde` feature of this crate is enabled
+/// - `FromStr` and conversion from `DynQuantity` for the `f64` quantity when
+///   the `alloc` feature of this crate is enabled
+/// - The dimension struct implementing [`Dimension`](crate::Dimension)
+/// - The DSL trait implemented for `f64`, if `conversions` is given
+///
+/// A quantity stores its value in the primary unit together with the unit
+/// it was created in, which [`Quantity::value`](crate::Quantity::value) and
+/// `Display` convert back to. Equality, ordering, hashing and arithmetic
+/// work on the stored value directly, so `Length::kilometers(1.0)` and
+/// `Length::meters(1000.0)` are equal and hash alike, and adding quantities
+/// in different units converts neither.
+///
+/// To make the units usable inside compound unit expressions (e.g.
+/// `"ppi·in"`), register the dimension once with
+/// [`unit_expr::register`](crate::core::unit_expr::register). Downstream
+/// crates typically re-export the quantity, its unit enum and its DSL trait
+/// next to `rquants::prelude::*` in their own prelude module.
+///
+/// # Stability
+///
+/// The syntax above is part of the public API and follows semantic
+/// versioning. New optional sections may be added in minor releases;
+/// existing sections will not change or become required without a major
+/// version bump. Arms starting with `@` are internal and not covered.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::prelude::*;
+///
+/// rquants::quantity! {
+///     /// Pixel density of a display.
+///     pub quantity PixelDensity {
+///         unit: PixelDensityUnit;
+///         dimension: PixelDensityDimension;
+///         conversions: PixelDensityConversions;
+///         name: "PixelDensity";
+///         primary: PixelsPerMeter;
+///         si: PixelsPerMeter;
+///         dimensions: [-1, 0, 0, 0, 0, 0, 0];
+///
+///         units {
+///             /// Pixels per meter
+///             PixelsPerMeter {
+///                 symbol: "px/m",
+///                 factor: 1.0,
+///                 ctor: pixels_per_meter,
+///                 to: to_pixels_per_meter,
+///                 si: true
+///             },
+///             /// Pixels per inch
+///             PixelsPerInch {
+///                 symbol: "ppi",
+///                 factor: 1.0 / 0.0254,
+///                 ctor: pixels_per_inch,
+///                 to: to_pixels_per_inch,
+///                 si: false
+///             }
+///         }
+///
+///         relations {
+///             PixelDensity * Length => Dimensionless;
+///         }
+///     }
+/// }
+///
+/// let density: PixelDensity = "326 ppi".parse().unwrap();
+/// let pixels = density * Length::inches(2.0);
+/// assert!((pixels.to_each() - 652.0).abs() < 1e-9);
+/// let ppm = 96.0.pixels_per_inch().to_pixels_per_meter();
+/// assert!((ppm - 3779.527559).abs() < 1e-6);
+/// ```
 #[macro_export]
 macro_rules! quantity {
+    // Number of primary units in one coherent SI unit. Defaults to the SI
+    // unit's conversion factor unless the quantity overrides it.
+    (@coherent $si:path) => {
+        $crate::core::UnitOfMeasure::conversion_factor(&$si)
+    };
+    (@coherent $si:path, $coherent:expr) => {
+        $coherent
+    };
+    (@name $variant:ident) => {
+        $crate::core::UnitName::from_identifier(stringify!($variant))
+    };
+    (@name $variant:ident $name:expr) => {
+        $crate::core::UnitName::from($name)
+    };
+    (@offset) => {
+        0.0
+    };
+    (@offset $offset:expr) => {
+        $offset
+    };
+    // How a unit converts to the primary unit, worked out in a constant.
+    (@conversion $factor:expr; $($offset:expr)?;) => {
+        $crate::core::unit::Conversion::scaled(
+            $crate::core::rational::ConstFactor($factor).to_f64(),
+            $crate::core::rational::ConstFactor($factor).to_rational(),
+            $crate::quantity!(@offset $($offset)?),
+        )
+    };
+    (@conversion $factor:expr; $($offset:expr)?; $to_primary:expr, $from_primary:expr) => {
+        $crate::core::unit::Conversion::Functions($to_primary, $from_primary)
+    };
+    (@conversions [] $quantity:ident { $($ctor_tokens:tt)* }) => {};
+    (@conversions [$conversions:ident] $quantity:ident {
+        $($(#[$unit_meta:meta])* $ctor:ident),+
+    }) => {
+        #[doc = concat!("Extension trait for creating [`", stringify!($quantity), "`] values from `f64`.")]
+        pub trait $conversions {
+            $(
+                $(#[$unit_meta])*
+                fn $ctor(self) -> $quantity;
+            )+
+        }
+
+        impl $conversions for f64 {
+            $(
+                fn $ctor(self) -> $quantity {
+                    $quantity::$ctor(self)
+                }
+            )+
+        }
+    };
+    // Operators that cannot be generic over the scalar type without
+    // overlapping `Mul<T>`/`Div<T>`, generated for each built-in scalar.
+    (@scalar_ops $quantity:ident $($scalar:ty),+) => {
+        $(
+            impl ::core::ops::Mul<$quantity<$scalar>> for $scalar {
+                type Output = $quantity<$scalar>;
+
+                fn mul(self, rhs: $quantity<$scalar>) -> Self::Output {
+                    rhs * self
+                }
+            }
+
+            impl ::core::ops::Mul<$crate::core::dynamic::DynQuantity> for $quantity<$scalar> {
+                type Output = $crate::core::dynamic::DynQuantity;
+
+                fn mul(self, rhs: $crate::core::dynamic::DynQuantity) -> Self::Output {
+                    $crate::core::dynamic::DynQuantity::from(self) * rhs
+                }
+            }
+
+            impl ::core::ops::Div<$crate::core::dynamic::DynQuantity> for $quantity<$scalar> {
+                type Output = $crate::core::dynamic::DynQuantity;
+
+                fn div(self, rhs: $crate::core::dynamic::DynQuantity) -> Self::Output {
+                    $crate::core::dynamic::DynQuantity::from(self) / rhs
+                }
+            }
+        )+
+    };
+    // The value of a quantity in the coherent SI unit, and a quantity in the
+    // SI unit from its value in the coherent unit. Without `coherent`, the SI
+    // unit is the coherent unit.
+    (@to_coherent $quantity:expr, $si:path) => {
+        $crate::core::Quantity::to($quantity, $si)
+    };
+    (@to_coherent $quantity:expr, $si:path, $coherent:expr) => {
+        $crate::core::Quantity::to_primary($quantity) / T::from_f64($coherent)
+    };
+    (@from_coherent $value:expr, $si:path) => {
+        <Self as $crate::core::Quantity<T>>::new($value, $si)
+    };
+    (@from_coherent $value:expr, $si:path, $coherent:expr) => {
+        Self::from_primary($value * T::from_f64($coherent), $si)
+    };
+    (@relation $lhs:ident * $rhs:ident => $output:ident) => {
+        impl<T: $crate::core::Scalar> ::core::ops::Mul<$rhs<T>> for $lhs<T> {
+            type Output = $output<T>;
+
+            fn mul(self, rhs: $rhs<T>) -> Self::Output {
+                $output::__from_coherent(self.__to_coherent() * rhs.__to_coherent())
+            }
+        }
+    };
+    (@relation $lhs:ident / $rhs:ident => $output:ident) => {
+        impl<T: $crate::core::Scalar> ::core::ops::Div<$rhs<T>> for $lhs<T> {
+            type Output = $output<T>;
+
+            fn div(self, rhs: $rhs<T>) -> Self::Output {
+                $output::__from_coherent(self.__to_coherent() / rhs.__to_coherent())
+            }
+        }
+    };
     (
         $(#[$quantity_meta:meta])*
         pub quantity $quantity:ident {
             unit: $unit:ident;
             dimension: $dimension:ident;
-            conversions: $conversions:ident;
+            $(conversions: $conversions:ident;)?
             name: $name:expr;
             primary: $primary:ident;
             si: $si_unit:ident;
+            dimensions: $dimensions:expr;
+            $(coherent: $coherent:expr;)?
+            $(angle: $angle:expr;)?
+            $(information: $information:expr;)?
+            $(families: [$($family:ident),+ $(,)?];)?
+            $(matching: $matching:expr;)?
 
             units {
                 $(
                     $(#[$unit_meta:meta])*
                     $unit_variant:ident {
                         symbol: $symbol:expr,
+                        $(name: $unit_name:expr,)?
+                        $(aliases: [$($alias:expr),* $(,)?],)?
                         factor: $factor:expr,
+                        $(offset: $offset:expr,)?
+                        $(convert: ($to_primary:expr, $from_primary:expr),)?
                         ctor: $ctor:ident,
                         to: $to:ident,
                         si: $is_si:expr
                     }
                 ),+ $(,)?
             }
+
+            $(
+                relations {
+                    $($lhs:ident $op:tt $rhs:ident => $output:ident;)*
+                }
+            )?
         }
     ) => {
+        #[doc = concat!("Units of measure for [`", stringify!($quantity), "`].")]
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         pub enum $unit {
             $(
@@ -36,35 +339,101 @@ macro_rules! quantity {
         }
 
         impl $unit {
+            /// All units of this dimension.
             pub const ALL: &'static [$unit] = &[
                 $($unit::$unit_variant,)+
             ];
 
+            /// Returns the symbol for this unit.
             pub fn symbol(&self) -> &'static str {
                 match self {
                     $($unit::$unit_variant => $symbol,)+
                 }
             }
 
+            /// Returns the long name of this unit.
+            pub fn name(&self) -> $crate::core::UnitName {
+                match self {
+                    $($unit::$unit_variant => {
+                        $crate::quantity!(@name $unit_variant $($unit_name)?)
+                    })+
+                }
+            }
+
+            /// Returns other spellings of this unit accepted when parsing.
+            pub fn aliases(&self) -> &'static [&'static str] {
+                match self {
+                    $($unit::$unit_variant => &[$($($alias),*)?],)+
+                }
+            }
+
+            /// Returns the conversion factor relative to the primary unit.
             pub fn conversion_factor(&self) -> f64 {
                 match self {
-                    $($unit::$unit_variant => $factor,)+
+                    $($unit::$unit_variant => $crate::core::ConversionFactor::to_f64($factor),)+
+                }
+            }
+
+            /// Returns the exact conversion factor, if there is one.
+            pub fn exact_factor(&self) -> Option<$crate::core::Rational> {
+                self.conversion().exact_factor()
+            }
+
+            /// Returns true if the conversion factor is known exactly.
+            pub fn is_exact(&self) -> bool {
+                self.exact_factor().is_some()
+            }
+
+            /// Returns the value, in the primary unit, of zero in this unit.
+            pub fn conversion_offset(&self) -> f64 {
+                match self {
+                    $($unit::$unit_variant => $crate::quantity!(@offset $($offset)?),)+
                 }
             }
 
+            /// Returns true if this is the primary unit.
             pub fn is_primary(&self) -> bool {
                 matches!(self, $unit::$primary)
             }
 
+            /// Returns true if this is an SI unit.
             pub fn is_si(&self) -> bool {
                 match self {
                     $($unit::$unit_variant => $is_si,)+
                 }
             }
+
+            /// Returns true if conversion to the primary unit is a scaling.
+            pub fn is_linear(&self) -> bool {
+                self.conversion().is_linear()
+            }
+
+            /// Returns the group of units a quantity in this unit is rescaled
+            /// within, or an empty slice if the unit is in no group.
+            pub fn family(&self) -> &'static [$unit] {
+                $($(
+                    if $unit::$family.contains(self) {
+                        return $unit::$family;
+                    }
+                )+)?
+                &[]
+            }
+
+            // How this unit converts to the primary unit, computed once per
+            // unit at compile time.
+            const fn conversion(&self) -> $crate::core::unit::Conversion {
+                match self {
+                    $($unit::$unit_variant => const {
+                        $crate::quantity!(
+                            @conversion $factor; $($offset)?; $($to_primary, $from_primary)?
+                        )
+                    },)+
+                }
+            }
         }
 
-        impl ::std::fmt::Display for $unit {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        impl ::core::fmt::Display for $unit {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 write!(f, "{}", $crate::core::UnitOfMeasure::symbol(self))
             }
         }
@@ -74,10 +443,26 @@ macro_rules! quantity {
                 $unit::symbol(self)
             }
 
+            fn name(&self) -> $crate::core::UnitName {
+                $unit::name(self)
+            }
+
+            fn aliases(&self) -> &'static [&'static str] {
+                $unit::aliases(self)
+            }
+
             fn conversion_factor(&self) -> f64 {
                 $unit::conversion_factor(self)
             }
 
+            fn conversion_offset(&self) -> f64 {
+                $unit::conversion_offset(self)
+            }
+
+            fn exact_factor(&self) -> Option<$crate::core::Rational> {
+                $unit::exact_factor(self)
+            }
+
             fn is_primary(&self) -> bool {
                 $unit::is_primary(self)
             }
@@ -85,206 +470,336 @@ macro_rules! quantity {
             fn is_si(&self) -> bool {
                 $unit::is_si(self)
             }
+
+            fn is_linear(&self) -> bool {
+                $unit::is_linear(self)
+            }
+
+            fn all_units() -> &'static [Self] {
+                $unit::ALL
+            }
+
+            fn family(&self) -> &'static [Self] {
+                $unit::family(self)
+            }
+
+            fn convert_to_primary(&self, value: f64) -> f64 {
+                self.conversion().to_primary(value)
+            }
+
+            fn convert_from_primary(&self, value: f64) -> f64 {
+                self.conversion().from_primary(value)
+            }
         }
 
         $(#[$quantity_meta])*
         #[derive(Debug, Clone, Copy)]
-        pub struct $quantity {
-            value: f64,
+        pub struct $quantity<T = f64> {
+            primary: T,
             unit: $unit,
         }
 
+        // Constructors take `f64` so that `Name::ctor(1.0)` never leaves the
+        // scalar type to inference; other scalars use `Quantity::new` or `cast`.
         impl $quantity {
             /// Creates a new quantity with the given value and unit.
+            pub fn new(value: f64, unit: $unit) -> Self {
+                <Self as $crate::core::Quantity>::new(value, unit)
+            }
+
+            /// Creates a new quantity with the given value and unit; usable
+            /// in constants.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `unit` converts with functions rather than a factor
+            /// and an offset.
             pub const fn new_const(value: f64, unit: $unit) -> Self {
-                Self { value, unit }
+                Self {
+                    primary: unit.conversion().to_primary_const(value),
+                    unit,
+                }
+            }
+
+            /// Returns the base-dimension signature of this quantity type.
+            pub fn base_dimensions() -> $crate::core::BaseDimensions {
+                <$dimension as $crate::core::Dimension>::base_dimensions()
+            }
+
+            /// Converts a runtime-dimensioned quantity, returning `None` if
+            /// the dimensions differ. Not part of the public API; use
+            /// `TryFrom` or `Dimension::from_dynamic`.
+            #[doc(hidden)]
+            pub fn __from_dynamic(quantity: $crate::core::dynamic::DynQuantity) -> Option<Self> {
+                if quantity.dims() != $dimensions {
+                    return None;
+                }
+                let coherent = $crate::quantity!(@coherent $unit::$si_unit $(, $coherent)?);
+                Some(Self::new(quantity.value() * coherent, $unit::$primary))
             }
 
             $(
                 $(#[$unit_meta])*
                 pub fn $ctor(value: f64) -> Self {
-                    <Self as $crate::core::Quantity>::new(value, $unit::$unit_variant)
+                    Self::new(value, $unit::$unit_variant)
+                }
+            )+
+        }
+
+        impl<T: $crate::core::Scalar> $quantity<T> {
+            /// Creates a quantity from its value in the primary unit, to be
+            /// displayed in `unit`.
+            pub const fn from_primary(primary: T, unit: $unit) -> Self {
+                Self { primary, unit }
+            }
+
+            /// Returns the value in the coherent SI unit. Used by relations;
+            /// not part of the public API.
+            #[doc(hidden)]
+            pub fn __to_coherent(&self) -> T {
+                $crate::quantity!(@to_coherent self, $unit::$si_unit $(, $coherent)?)
+            }
+
+            /// Creates a quantity in the SI unit from its value in the
+            /// coherent SI unit. Used by relations; not part of the public API.
+            #[doc(hidden)]
+            pub fn __from_coherent(value: T) -> Self {
+                $crate::quantity!(@from_coherent value, $unit::$si_unit $(, $coherent)?)
+            }
+
+            /// Converts the stored value to another scalar type.
+            pub fn cast<U: $crate::core::Scalar>(self) -> $quantity<U> {
+                $quantity {
+                    primary: U::from_f64($crate::core::Scalar::to_f64(self.primary)),
+                    unit: self.unit,
                 }
+            }
 
+            $(
                 $(#[$unit_meta])*
-                pub fn $to(&self) -> f64 {
+                pub fn $to(&self) -> T {
                     $crate::core::Quantity::to(self, $unit::$unit_variant)
                 }
             )+
+
+            // Arithmetic works on the values in the primary unit and keeps
+            // the left-hand side's unit if it is linear. Other units return
+            // the result in the primary unit, since reading a difference or a
+            // multiple back through an offset would shift it.
+            fn with_primary(&self, primary: T) -> Self {
+                if self.unit.is_linear() {
+                    Self::from_primary(primary, self.unit)
+                } else {
+                    Self::from_primary(primary, $unit::$primary)
+                }
+            }
         }
 
-        impl ::std::fmt::Display for $quantity {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                write!(
-                    f,
-                    "{} {}",
-                    self.value,
-                    $crate::core::UnitOfMeasure::symbol(&self.unit)
-                )
+        impl<T: $crate::core::Scalar> ::core::fmt::Display for $quantity<T> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&$crate::core::Quantity::display(self), f)
             }
         }
 
-        impl ::std::cmp::PartialEq for $quantity {
+        // Equality, ordering and hashing only look at the value in the
+        // primary unit, so `1 km == 1000 m` and both hash alike.
+        impl<T: $crate::core::Scalar> ::core::cmp::PartialEq for $quantity<T> {
             fn eq(&self, other: &Self) -> bool {
-                $crate::core::Quantity::to_primary(self)
-                    == $crate::core::Quantity::to_primary(other)
+                self.primary == other.primary
             }
         }
 
-        impl ::std::cmp::PartialOrd for $quantity {
-            fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
-                $crate::core::Quantity::to_primary(self)
-                    .partial_cmp(&$crate::core::Quantity::to_primary(other))
+        impl<T: $crate::core::Scalar> ::core::cmp::PartialOrd for $quantity<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                self.primary.partial_cmp(&other.primary)
             }
         }
 
-        impl $crate::core::Quantity for $quantity {
+        impl<T: $crate::core::Scalar> ::core::hash::Hash for $quantity<T> {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                // 0.0 and -0.0 are equal, so they must hash alike.
+                let primary = $crate::core::Scalar::to_f64(self.primary);
+                let primary = if primary == 0.0 { 0.0 } else { primary };
+                primary.to_bits().hash(state);
+            }
+        }
+
+        impl<T: $crate::core::Scalar> $crate::core::Quantity<T> for $quantity<T> {
             type Unit = $unit;
 
-            fn new(value: f64, unit: Self::Unit) -> Self {
-                Self { value, unit }
+            fn new(value: T, unit: Self::Unit) -> Self {
+                if unit == $unit::$primary {
+                    Self::from_primary(value, unit)
+                } else {
+                    let primary = $crate::core::UnitOfMeasure::scalar_to_primary(&unit, value);
+                    Self::from_primary(primary, unit)
+                }
             }
 
-            fn value(&self) -> f64 {
-                self.value
+            fn value(&self) -> T {
+                if self.unit == $unit::$primary {
+                    self.primary
+                } else {
+                    $crate::core::unit::value_from_primary(&self.unit, self.primary)
+                }
             }
 
             fn unit(&self) -> Self::Unit {
                 self.unit
             }
+
+            fn to_primary(&self) -> T {
+                self.primary
+            }
+
+            fn to(&self, target_unit: Self::Unit) -> T {
+                let exact = |unit: $unit| unit.is_linear() && unit.is_exact();
+                if target_unit == self.unit {
+                    $crate::core::Quantity::value(self)
+                } else if target_unit == $unit::$primary {
+                    self.primary
+                } else if exact(self.unit) && exact(target_unit) {
+                    // Between exact units the factors combine before the
+                    // value is rounded, as `UnitOfMeasure::convert_to` does.
+                    let value = $crate::core::Quantity::value(self);
+                    $crate::core::UnitOfMeasure::scalar_convert_to(&self.unit, value, &target_unit)
+                } else {
+                    $crate::core::UnitOfMeasure::scalar_from_primary(&target_unit, self.primary)
+                }
+            }
+
+            fn in_unit(&self, target_unit: Self::Unit) -> Self {
+                Self::from_primary(self.primary, target_unit)
+            }
         }
 
-        impl ::std::ops::Add for $quantity {
-            type Output = $quantity;
+        // Arithmetic keeps the left-hand side's unit (see `with_primary`).
+        impl<T: $crate::core::Scalar> ::core::ops::Add for $quantity<T> {
+            type Output = $quantity<T>;
 
             fn add(self, rhs: Self) -> Self::Output {
-                let rhs_value = $crate::core::Quantity::to(&rhs, self.unit);
-                <$quantity as $crate::core::Quantity>::new(self.value + rhs_value, self.unit)
+                self.with_primary(self.primary + rhs.primary)
             }
         }
 
-        impl ::std::ops::AddAssign for $quantity {
+        impl<T: $crate::core::Scalar> ::core::ops::AddAssign for $quantity<T> {
             fn add_assign(&mut self, rhs: Self) {
                 *self = *self + rhs;
             }
         }
 
-        impl ::std::ops::Sub for $quantity {
-            type Output = $quantity;
+        impl<T: $crate::core::Scalar> ::core::ops::Sub for $quantity<T> {
+            type Output = $quantity<T>;
 
             fn sub(self, rhs: Self) -> Self::Output {
-                let rhs_value = $crate::core::Quantity::to(&rhs, self.unit);
-                <$quantity as $crate::core::Quantity>::new(self.value - rhs_value, self.unit)
+                self.with_primary(self.primary - rhs.primary)
             }
         }
 
-        impl ::std::ops::SubAssign for $quantity {
+        impl<T: $crate::core::Scalar> ::core::ops::SubAssign for $quantity<T> {
             fn sub_assign(&mut self, rhs: Self) {
                 *self = *self - rhs;
             }
         }
 
-        impl ::std::ops::Mul<f64> for $quantity {
-            type Output = $quantity;
+        impl<T: $crate::core::Scalar> ::core::ops::Mul<T> for $quantity<T> {
+            type Output = $quantity<T>;
 
-            fn mul(self, rhs: f64) -> Self::Output {
-                <$quantity as $crate::core::Quantity>::new(self.value * rhs, self.unit)
+            fn mul(self, rhs: T) -> Self::Output {
+                self.with_primary(self.primary * rhs)
             }
         }
 
-        impl ::std::ops::MulAssign<f64> for $quantity {
-            fn mul_assign(&mut self, rhs: f64) {
-                self.value *= rhs;
+        impl<T: $crate::core::Scalar> ::core::ops::MulAssign<T> for $quantity<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                *self = *self * rhs;
             }
         }
 
-        impl ::std::ops::Mul<$quantity> for f64 {
-            type Output = $quantity;
+        $crate::quantity!(@scalar_ops $quantity f32, f64);
 
-            fn mul(self, rhs: $quantity) -> Self::Output {
-                <$quantity as $crate::core::Quantity>::new(self * rhs.value, rhs.unit)
-            }
-        }
-
-        impl ::std::ops::Div<f64> for $quantity {
-            type Output = $quantity;
+        impl<T: $crate::core::Scalar> ::core::ops::Div<T> for $quantity<T> {
+            type Output = $quantity<T>;
 
-            fn div(self, rhs: f64) -> Self::Output {
-                <$quantity as $crate::core::Quantity>::new(self.value / rhs, self.unit)
+            fn div(self, rhs: T) -> Self::Output {
+                self.with_primary(self.primary / rhs)
             }
         }
 
-        impl ::std::ops::DivAssign<f64> for $quantity {
-            fn div_assign(&mut self, rhs: f64) {
-                self.value /= rhs;
+        impl<T: $crate::core::Scalar> ::core::ops::DivAssign<T> for $quantity<T> {
+            fn div_assign(&mut self, rhs: T) {
+                *self = *self / rhs;
             }
         }
 
-        impl ::std::ops::Div<$quantity> for $quantity {
-            type Output = f64;
+        impl<T: $crate::core::Scalar> ::core::ops::Div<$quantity<T>> for $quantity<T> {
+            type Output = T;
 
-            fn div(self, rhs: $quantity) -> Self::Output {
-                $crate::core::Quantity::to_primary(&self)
-                    / $crate::core::Quantity::to_primary(&rhs)
+            fn div(self, rhs: $quantity<T>) -> Self::Output {
+                self.primary / rhs.primary
             }
         }
 
-        impl ::std::ops::Neg for $quantity {
-            type Output = $quantity;
+        impl<T: $crate::core::Scalar> ::core::ops::Neg for $quantity<T> {
+            type Output = $quantity<T>;
 
             fn neg(self) -> Self::Output {
-                <$quantity as $crate::core::Quantity>::new(-self.value, self.unit)
+                self.with_primary(-self.primary)
             }
         }
 
-        impl ::std::iter::Sum for $quantity {
+        impl<T: $crate::core::Scalar> ::core::iter::Sum for $quantity<T> {
             fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-                let total = iter.fold(0.0, |total, quantity| {
-                    total + $crate::core::Quantity::to_primary(&quantity)
+                let total = iter.fold(<T as $crate::core::Scalar>::ZERO, |total, quantity| {
+                    total + quantity.primary
                 });
-                <$quantity as $crate::core::Quantity>::new(
-                    total,
-                    <$dimension as $crate::core::Dimension>::primary_unit(),
-                )
+                Self::from_primary(total, $unit::$primary)
             }
         }
 
-        impl<'a> ::std::iter::Sum<&'a $quantity> for $quantity {
-            fn sum<I: Iterator<Item = &'a $quantity>>(iter: I) -> Self {
+        impl<'a, T: $crate::core::Scalar> ::core::iter::Sum<&'a $quantity<T>> for $quantity<T> {
+            fn sum<I: Iterator<Item = &'a $quantity<T>>>(iter: I) -> Self {
                 iter.copied().sum()
             }
         }
 
-        impl ::std::str::FromStr for $quantity {
-            type Err = $crate::core::error::QuantityParseError;
-
-            fn from_str(s: &str) -> Result<Self, Self::Err> {
-                <$dimension as $crate::core::Dimension>::parse(s)
+        $crate::__quantity_alloc!($quantity, $dimension, $name, $dimensions);
+        $crate::__quantity_serde!($quantity);
+
+        impl<T: $crate::core::Scalar> ::core::convert::From<$quantity<T>>
+            for $crate::core::dynamic::DynQuantity
+        {
+            fn from(quantity: $quantity<T>) -> Self {
+                let coherent = $crate::quantity!(@coherent $unit::$si_unit $(, $coherent)?);
+                let primary = $crate::core::Quantity::to_primary(&quantity);
+                $crate::core::dynamic::DynQuantity::new(
+                    $crate::core::Scalar::to_f64(primary) / coherent,
+                    $dimensions,
+                )
             }
         }
 
-        #[cfg(feature = "serde")]
-        impl ::serde::Serialize for $quantity {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-            where
-                S: ::serde::Serializer,
-            {
-                serializer.serialize_str(&self.to_string())
+        impl<T: $crate::core::Scalar> ::core::ops::Mul<$quantity<T>>
+            for $crate::core::dynamic::DynQuantity
+        {
+            type Output = $crate::core::dynamic::DynQuantity;
+
+            fn mul(self, rhs: $quantity<T>) -> Self::Output {
+                self * $crate::core::dynamic::DynQuantity::from(rhs)
             }
         }
 
-        #[cfg(feature = "serde")]
-        impl<'de> ::serde::Deserialize<'de> for $quantity {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-            where
-                D: ::serde::Deserializer<'de>,
-            {
-                let s = <::std::string::String as ::serde::Deserialize>::deserialize(deserializer)?;
-                <Self as ::std::str::FromStr>::from_str(&s)
-                    .map_err(::serde::de::Error::custom)
+        impl<T: $crate::core::Scalar> ::core::ops::Div<$quantity<T>>
+            for $crate::core::dynamic::DynQuantity
+        {
+            type Output = $crate::core::dynamic::DynQuantity;
+
+            fn div(self, rhs: $quantity<T>) -> Self::Output {
+                self / $crate::core::dynamic::DynQuantity::from(rhs)
             }
         }
 
+        #[doc = concat!("Dimension metadata for [`", stringify!($quantity), "`].")]
         pub struct $dimension;
 
         impl $crate::core::Dimension for $dimension {
@@ -306,21 +821,194 @@ macro_rules! quantity {
             fn units() -> &'static [Self::Unit] {
                 $unit::ALL
             }
-        }
 
-        pub trait $conversions {
-            $(
-                $(#[$unit_meta])*
-                fn $ctor(self) -> $quantity;
-            )+
-        }
+            fn base_dimensions() -> $crate::core::BaseDimensions {
+                $crate::core::BaseDimensions::new($dimensions)
+                    $(.with_angle($angle))?
+                    $(.with_information($information))?
+            }
 
-        impl $conversions for f64 {
             $(
-                fn $ctor(self) -> $quantity {
-                    $quantity::$ctor(self)
+                fn unit_matching() -> $crate::core::UnitMatching {
+                    $matching
                 }
-            )+
+            )?
+
+            fn from_dynamic(
+                quantity: $crate::core::dynamic::DynQuantity,
+            ) -> Option<Self::Quantity> {
+                $quantity::__from_dynamic(quantity)
+            }
+        }
+
+        $crate::quantity!(@conversions [$($conversions)?] $quantity {
+            $($(#[$unit_meta])* $ctor),+
+        });
+
+        $($(
+            $crate::quantity!(@relation $lhs $op $rhs => $output);
+        )*)?
+    };
+}
+
+/// Declares cross-quantity relations and generates their operators.
+///
+/// Each entry describes one physical relation and expands to the matching
+/// `Mul`/`Div` implementations, which return the output in its SI unit:
+///
+/// - `A * B => C;` generates `A * B`, `B * A`, `C / A` and `C / B`
+/// - `A ^ 2 => C;` generates `A * A` and `C / A`
+/// - `product A * B => C;` generates only `A * B` and `B * A`
+/// - `quotient C / A => B;` generates only `C / A`
+///
+/// The restricted forms are for relations whose quotient already has a
+/// different meaning, such as `Energy / Volume`, which is an `EnergyDensity`
+/// rather than a `Pressure`. Every type must be in scope at the call site.
+/// Outside this crate, the orphan rules require at least one local type in
+/// each generated operator, so a relation between one local and two
+/// built-in quantities has to use the `product` form.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::prelude::*;
+///
+/// rquants::quantity! {
+///     /// Rotational stiffness.
+///     pub quantity TorsionalStiffness {
+///         unit: TorsionalStiffnessUnit;
+///         dimension: TorsionalStiffnessDimension;
+///         name: "TorsionalStiffness";
+///         primary: NewtonMetersPerRadian;
+///         si: NewtonMetersPerRadian;
+///         dimensions: [2, 1, -2, 0, 0, 0, 0];
+///         angle: -1;
+///
+///         units {
+///             /// Newton-meters per radian
+///             NewtonMetersPerRadian {
+///                 symbol: "N·m/rad",
+///                 factor: 1.0,
+///                 ctor: newton_meters_per_radian,
+///                 to: to_newton_meters_per_radian,
+///                 si: true
+///             }
+///         }
+///     }
+/// }
+///
+/// rquants::relation! {
+///     product TorsionalStiffness * Angle => Energy;
+/// }
+///
+/// let stiffness = TorsionalStiffness::newton_meters_per_radian(4.0);
+/// let energy = stiffness * Angle::radians(0.5);
+/// assert!((energy.to_joules() - 2.0).abs() < 1e-12);
+/// let energy = Angle::radians(0.25) * stiffness;
+/// assert!((energy.to_joules() - 1.0).abs() < 1e-12);
+/// ```
+#[macro_export]
+macro_rules! relation {
+    () => {};
+    (product $lhs:ident * $rhs:ident => $output:ident; $($rest:tt)*) => {
+        $crate::quantity!(@relation $lhs * $rhs => $output);
+        $crate::quantity!(@relation $rhs * $lhs => $output);
+        $crate::relation!($($rest)*);
+    };
+    (quotient $lhs:ident / $rhs:ident => $output:ident; $($rest:tt)*) => {
+        $crate::quantity!(@relation $lhs / $rhs => $output);
+        $crate::relation!($($rest)*);
+    };
+    ($base:ident ^ 2 => $output:ident; $($rest:tt)*) => {
+        $crate::quantity!(@relation $base * $base => $output);
+        $crate::quantity!(@relation $output / $base => $base);
+        $crate::relation!($($rest)*);
+    };
+    ($lhs:ident * $rhs:ident => $output:ident; $($rest:tt)*) => {
+        $crate::quantity!(@relation $lhs * $rhs => $output);
+        $crate::quantity!(@relation $rhs * $lhs => $output);
+        $crate::quantity!(@relation $output / $lhs => $rhs);
+        $crate::quantity!(@relation $output / $rhs => $lhs);
+        $crate::relation!($($rest)*);
+    };
+}
+
+/// Implements parsing and fallible conversion from `DynQuantity` for a
+/// generated quantity when this crate's `alloc` feature is enabled. Not part
+/// of the public API.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __quantity_alloc {
+    ($quantity:ident, $dimension:ident, $name:expr, $dimensions:expr) => {
+        impl ::core::str::FromStr for $quantity {
+            type Err = $crate::core::error::QuantityParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <$dimension as $crate::core::Dimension>::parse(s)
+            }
+        }
+
+        impl ::core::convert::TryFrom<$crate::core::dynamic::DynQuantity> for $quantity {
+            type Error = $crate::core::error::QuantityError;
+
+            fn try_from(quantity: $crate::core::dynamic::DynQuantity) -> Result<Self, Self::Error> {
+                quantity.expect_dims($name, $dimensions)?;
+                Ok($quantity::__from_dynamic(quantity).expect("dimensions were checked"))
+            }
+        }
+    };
+}
+
+/// No-op when this crate's `alloc` feature is disabled. Not part of the
+/// public API.
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __quantity_alloc {
+    ($quantity:ident, $dimension:ident, $name:expr, $dimensions:expr) => {};
+}
+
+/// Implements serde support for a generated quantity when this crate's
+/// `serde` feature is enabled. Not part of the public API.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __quantity_serde {
+    ($quantity:ident) => {
+        impl<T: $crate::core::Scalar> $crate::__private::serde::Serialize for $quantity<T> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de, T: $crate::core::Scalar> $crate::__private::serde::Deserialize<'de>
+            for $quantity<T>
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let s =
+                    <$crate::__private::String as $crate::__private::serde::Deserialize>::deserialize(
+                        deserializer,
+                    )?;
+                <$quantity as ::core::str::FromStr>::from_str(&s)
+                    .map($quantity::cast)
+                    .map_err($crate::__private::serde::de::Error::custom)
+            }
         }
     };
 }
+
+/// No-op when this crate's `serde` feature is disabled. Not part of the
+/// public API.
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __quantity_serde {
+    ($quantity:ident) => {};
+}
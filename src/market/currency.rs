@@ -1,6 +1,6 @@
 //! Currency definitions for money quantities.
 
-use std::fmt;
+use core::fmt;
 
 /// Currency represents a unit of money.
 ///
@@ -2,8 +2,10 @@
 
 use super::currency::Currency;
 use crate::core::error::QuantityError;
-use std::fmt;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use crate::core::locale::{write_localized, NumberFormat};
+use alloc::{format, string::String};
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
 /// Represents a quantity of money in a specific currency.
 ///
@@ -106,13 +108,30 @@ impl Money {
     /// assert_eq!(money.to_formatted_string(), "$123.46");
     /// ```
     pub fn to_formatted_string(&self) -> String {
-        let decimals = self.currency.format_decimals();
-        format!(
-            "{}{:.decimals$}",
-            self.currency.symbol(),
-            self.amount,
-            decimals = decimals as usize
-        )
+        self.to_formatted_string_with(NumberFormat::PLAIN)
+    }
+
+    /// Returns a formatted string with the currency symbol, writing the
+    /// amount with the decimal and group separators of `format`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    ///
+    /// let money = Money::eur(1234567.891);
+    /// assert_eq!(money.to_formatted_string_with(NumberFormat::DE_DE), "€1.234.567,89");
+    /// let money = Money::inr(1234567.891);
+    /// assert_eq!(money.to_formatted_string_with(NumberFormat::EN_IN), "₹12,34,567.89");
+    /// ```
+    pub fn to_formatted_string_with(&self, format: NumberFormat) -> String {
+        let decimals = self.currency.format_decimals() as usize;
+        let mut text = String::from(self.currency.symbol());
+        // Writing to a String cannot fail
+        let _ = write_localized(&mut text, format, |out| {
+            write!(out, "{:.decimals$}", self.amount)
+        });
+        text
     }
 
     /// Checks if this money can be operated with another (same currency).
@@ -160,7 +179,7 @@ impl PartialEq for Money {
 }
 
 impl PartialOrd for Money {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         if self.currency != other.currency {
             None
         } else {
@@ -284,8 +303,6 @@ impl MoneyConversions for f64 {
     }
 }
 
-
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -312,6 +329,29 @@ mod tests {
         assert_eq!(yen.to_formatted_string(), "¥1235");
     }
 
+    #[test]
+    fn test_money_formatted_string_with() {
+        let m = Money::usd(-1234.5);
+        assert_eq!(
+            m.to_formatted_string_with(NumberFormat::EN_US),
+            "$-1,234.50"
+        );
+        assert_eq!(
+            m.to_formatted_string_with(NumberFormat::FR_FR),
+            "$-1\u{202F}234,50"
+        );
+        let yen = Money::jpy(1234567.0);
+        assert_eq!(
+            yen.to_formatted_string_with(NumberFormat::DE_DE),
+            "¥1.234.567"
+        );
+        let rupees = Money::inr(100000.0);
+        assert_eq!(
+            rupees.to_formatted_string_with(NumberFormat::EN_IN),
+            "₹1,00,000.00"
+        );
+    }
+
     #[test]
     fn test_money_arithmetic_same_currency() {
         let m1 = Money::usd(100.0);
@@ -2,8 +2,8 @@
 
 use super::money::Money;
 use crate::core::Quantity;
-use std::fmt;
-use std::ops::{Div, Mul};
+use core::fmt;
+use core::ops::{Div, Mul};
 
 /// Represents a price - the ratio of Money to some Quantity.
 ///
@@ -1,17 +1,17 @@
 //! Area density quantity and units (mass per area).
 
-use super::mass::{Mass, MassUnit};
-use crate::core::Quantity;
-use crate::space::area::{Area, AreaUnit};
-use std::ops::{Div, Mul};
+use super::mass::Mass;
+use crate::core::Rational;
+use crate::space::area::Area;
 
 // Conversion factors to kg/m² (primary unit)
 // 1 hectare = 10,000 m², so 1 kg/ha = 0.0001 kg/m²
-const KG_PER_HECTARE_FACTOR: f64 = 0.0001;
+const KG_PER_HECTARE_FACTOR: Rational = Rational::pow10(-4);
 // 1 cm² = 0.0001 m², 1 g = 0.001 kg, so 1 g/cm² = 0.001/0.0001 = 10 kg/m²
-const G_PER_CM2_FACTOR: f64 = 10.0;
-// 1 lb ≈ 0.4536 kg, 1 acre ≈ 4046.86 m², so 1 lb/ac ≈ 0.000112 kg/m²
-const LB_PER_ACRE_FACTOR: f64 = 0.45359237 / 4046.8564224;
+const G_PER_CM2_FACTOR: Rational = Rational::integer(10);
+// 1 lb = 0.45359237 kg, 1 acre = 4046.8564224 m², so 1 lb/ac ≈ 0.000112 kg/m²
+const LB_PER_ACRE_FACTOR: Rational =
+    Rational::new(45_359_237, 100_000_000).div(Rational::new(40_468_564_224, 10_000_000));
 crate::quantity! {
     /// A quantity of area density (mass per area).
     ///
@@ -37,6 +37,7 @@ crate::quantity! {
         name: "AreaDensity";
         primary: KilogramsPerSquareMeter;
         si: KilogramsPerSquareMeter;
+        dimensions: [-2, 1, 0, 0, 0, 0, 0];
 
         units {
             /// Kilograms per square meter (kg/m²) - SI unit
@@ -81,35 +82,6 @@ impl AreaDensity {
         Self::new(kg_per_m2, AreaDensityUnit::KilogramsPerSquareMeter)
     }
 }
-
-// AreaDensity * Area = Mass
-impl Mul<Area> for AreaDensity {
-    type Output = Mass;
-
-    fn mul(self, rhs: Area) -> Self::Output {
-        let mass_kg = self.to_kilograms_per_square_meter() * rhs.to_square_meters();
-        Mass::new(mass_kg, MassUnit::Kilograms)
-    }
-}
-
-// Mass / Area = AreaDensity
-impl Div<Area> for Mass {
-    type Output = AreaDensity;
-
-    fn div(self, rhs: Area) -> Self::Output {
-        AreaDensity::from_mass_and_area(self, rhs)
-    }
-}
-
-// Mass / AreaDensity = Area
-impl Div<AreaDensity> for Mass {
-    type Output = Area;
-
-    fn div(self, rhs: AreaDensity) -> Self::Output {
-        let area_m2 = self.to_kilograms() / rhs.to_kilograms_per_square_meter();
-        Area::new(area_m2, AreaUnit::SquareMeters)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
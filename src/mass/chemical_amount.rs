@@ -1,8 +1,11 @@
 //! Chemical amount (substance) quantity and units.
 
+use super::mass::POUND_TO_GRAM;
+use crate::core::Rational;
+
 // Conversion factors to moles (primary unit)
 // 1 lb-mol = 453.59237 mol (same as pounds to grams ratio)
-const POUND_MOLE_FACTOR: f64 = 453.59237;
+const POUND_MOLE_FACTOR: Rational = POUND_TO_GRAM;
 crate::quantity! {
     /// A quantity of chemical amount (amount of substance).
     ///
@@ -28,6 +31,7 @@ crate::quantity! {
         name: "ChemicalAmount";
         primary: Moles;
         si: Moles;
+        dimensions: [0, 0, 0, 0, 0, 1, 0];
 
         units {
             /// Moles (mol) - SI base unit
@@ -1,23 +1,23 @@
 //! Density quantity and units.
 
-use super::mass::{Mass, MassUnit};
-use crate::core::Quantity;
-use crate::space::volume::{Volume, VolumeUnit};
-use std::ops::{Div, Mul};
+use super::mass::{Mass, POUND_TO_GRAM};
+use crate::core::Rational;
+use crate::space::volume::{Volume, CUBIC_FOOT_TO_CUBIC_METER, US_GALLON_TO_CUBIC_METER};
 
 // Conversion factors to kg/m³ (primary unit)
+const POUND_TO_KG: Rational = POUND_TO_GRAM.mul(Rational::pow10(-3));
 // 1 L = 0.001 m³, so 1 kg/L = 1000 kg/m³
 const KG_PER_LITER_FACTOR: f64 = 1000.0;
 // 1 g/L = 1 kg/m³
 const G_PER_LITER_FACTOR: f64 = 1.0;
 // 1 mg/L = 0.001 kg/m³
-const MG_PER_LITER_FACTOR: f64 = 0.001;
+const MG_PER_LITER_FACTOR: Rational = Rational::pow10(-3);
 // 1 g/mL = 1000 kg/m³ (same as g/cm³)
 const G_PER_ML_FACTOR: f64 = 1000.0;
 // 1 lb/ft³ ≈ 16.0185 kg/m³
-const LB_PER_CUFT_FACTOR: f64 = 16.01846337396;
+const LB_PER_CUFT_FACTOR: Rational = POUND_TO_KG.div(CUBIC_FOOT_TO_CUBIC_METER);
 // 1 lb/gal (US) ≈ 119.826 kg/m³
-const LB_PER_GAL_FACTOR: f64 = 119.8264273167;
+const LB_PER_GAL_FACTOR: Rational = POUND_TO_KG.div(US_GALLON_TO_CUBIC_METER);
 crate::quantity! {
     /// A quantity of density (mass per volume).
     ///
@@ -43,6 +43,7 @@ crate::quantity! {
         name: "Density";
         primary: KilogramsPerCubicMeter;
         si: KilogramsPerCubicMeter;
+        dimensions: [-3, 1, 0, 0, 0, 0, 0];
 
         units {
             /// Kilograms per cubic meter (kg/m³) - SI unit
@@ -119,35 +120,6 @@ impl Density {
         Self::new(kg_per_m3, DensityUnit::KilogramsPerCubicMeter)
     }
 }
-
-// Density * Volume = Mass
-impl Mul<Volume> for Density {
-    type Output = Mass;
-
-    fn mul(self, rhs: Volume) -> Self::Output {
-        let mass_kg = self.to_kilograms_per_cubic_meter() * rhs.to_cubic_meters();
-        Mass::new(mass_kg, MassUnit::Kilograms)
-    }
-}
-
-// Mass / Volume = Density
-impl Div<Volume> for Mass {
-    type Output = Density;
-
-    fn div(self, rhs: Volume) -> Self::Output {
-        Density::from_mass_and_volume(self, rhs)
-    }
-}
-
-// Mass / Density = Volume
-impl Div<Density> for Mass {
-    type Output = Volume;
-
-    fn div(self, rhs: Density) -> Self::Output {
-        let volume_m3 = self.to_kilograms() / rhs.to_kilograms_per_cubic_meter();
-        Volume::new(volume_m3, VolumeUnit::CubicMeters)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,6 +141,15 @@ mod tests {
         // 1 g/mL = 1 kg/L = 1000 kg/m³
         let d2 = Density::grams_per_milliliter(1.0);
         assert_eq!(d2.to_kilograms_per_liter(), 1.0);
+
+        // Imperial densities derive from the exact pound and volume factors
+        let water = Density::pounds_per_cubic_foot(62.4);
+        assert!((water.to_kilograms_per_cubic_meter() - 999.552_114_535_1).abs() < 1e-9);
+        assert!(DensityUnit::PoundsPerGallon.is_exact());
+        assert_eq!(
+            Density::milligrams_per_liter(250.0).to_kilograms_per_cubic_meter(),
+            0.25
+        );
     }
 
     #[test]
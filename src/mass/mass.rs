@@ -1,12 +1,11 @@
 //! Mass quantity and units.
 
-use crate::systems::metric::{KILO, MEGA, MICRO, MILLI, NANO};
+use crate::core::{CompositeUnit, Rational};
 
 // Conversion factors to grams (primary unit)
-const KILOGRAM_TO_GRAM: f64 = KILO; // 1000
-const POUND_TO_GRAM: f64 = KILOGRAM_TO_GRAM * 0.45359237; // ~453.59 g
-const OUNCE_TO_GRAM: f64 = POUND_TO_GRAM / 16.0; // ~28.35 g
-const TROY_GRAIN_TO_GRAM: f64 = 0.06479891; // ~64.8 mg
+pub(crate) const POUND_TO_GRAM: Rational = Rational::new(45_359_237, 100_000); // 453.59237 g exactly
+const OUNCE_TO_GRAM: Rational = POUND_TO_GRAM.div(Rational::integer(16)); // ~28.35 g
+const TROY_GRAIN_TO_GRAM: Rational = POUND_TO_GRAM.div(Rational::integer(7000)); // ~64.8 mg
 const DALTON_TO_GRAM: f64 = 1.66053906660e-24; // atomic mass unit
 crate::quantity! {
     /// A quantity of mass.
@@ -31,12 +30,14 @@ crate::quantity! {
         name: "Mass";
         primary: Grams;
         si: Kilograms;
+        dimensions: [0, 1, 0, 0, 0, 0, 0];
+        families: [AVOIRDUPOIS];
 
         units {
             /// Nanograms (ng)
             Nanograms {
                 symbol: "ng",
-                factor: NANO,
+                factor: Rational::pow10(-9),
                 ctor: nanograms,
                 to: to_nanograms,
                 si: true
@@ -44,7 +45,8 @@ crate::quantity! {
             /// Micrograms (mcg)
             Micrograms {
                 symbol: "mcg",
-                factor: MICRO,
+                aliases: ["µg", "ug"],
+                factor: Rational::pow10(-6),
                 ctor: micrograms,
                 to: to_micrograms,
                 si: true
@@ -52,7 +54,7 @@ crate::quantity! {
             /// Milligrams (mg)
             Milligrams {
                 symbol: "mg",
-                factor: MILLI,
+                factor: Rational::pow10(-3),
                 ctor: milligrams,
                 to: to_milligrams,
                 si: true
@@ -68,7 +70,8 @@ crate::quantity! {
             /// Kilograms (kg) - SI base unit
             Kilograms {
                 symbol: "kg",
-                factor: KILO,
+                aliases: ["kilo", "kilos"],
+                factor: Rational::pow10(3),
                 ctor: kilograms,
                 to: to_kilograms,
                 si: true
@@ -76,7 +79,7 @@ crate::quantity! {
             /// Tonnes (t) - metric ton = 1000 kg
             Tonnes {
                 symbol: "t",
-                factor: MEGA,
+                factor: Rational::pow10(6),
                 ctor: tonnes,
                 to: to_tonnes,
                 si: true
@@ -92,6 +95,7 @@ crate::quantity! {
             /// Pounds (lb)
             Pounds {
                 symbol: "lb",
+                aliases: ["lbs"],
                 factor: POUND_TO_GRAM,
                 ctor: pounds,
                 to: to_pounds,
@@ -100,7 +104,7 @@ crate::quantity! {
             /// Kilopounds (klb)
             Kilopounds {
                 symbol: "klb",
-                factor: POUND_TO_GRAM * KILO,
+                factor: POUND_TO_GRAM.mul(Rational::pow10(3)),
                 ctor: kilopounds,
                 to: to_kilopounds,
                 si: false
@@ -108,7 +112,7 @@ crate::quantity! {
             /// Megapounds (Mlb)
             Megapounds {
                 symbol: "Mlb",
-                factor: POUND_TO_GRAM * MEGA,
+                factor: POUND_TO_GRAM.mul(Rational::pow10(6)),
                 ctor: megapounds,
                 to: to_megapounds,
                 si: false
@@ -116,7 +120,7 @@ crate::quantity! {
             /// Stone (st) = 14 pounds
             Stone {
                 symbol: "st",
-                factor: POUND_TO_GRAM * 14.0,
+                factor: POUND_TO_GRAM.mul(Rational::integer(14)),
                 ctor: stone,
                 to: to_stone,
                 si: false
@@ -132,7 +136,7 @@ crate::quantity! {
             /// Pennyweights (dwt) = 24 troy grains
             Pennyweights {
                 symbol: "dwt",
-                factor: TROY_GRAIN_TO_GRAM * 24.0,
+                factor: TROY_GRAIN_TO_GRAM.mul(Rational::integer(24)),
                 ctor: pennyweights,
                 to: to_pennyweights,
                 si: false
@@ -140,7 +144,7 @@ crate::quantity! {
             /// Troy ounces (oz t) = 480 troy grains
             TroyOunces {
                 symbol: "oz t",
-                factor: TROY_GRAIN_TO_GRAM * 480.0,
+                factor: TROY_GRAIN_TO_GRAM.mul(Rational::integer(480)),
                 ctor: troy_ounces,
                 to: to_troy_ounces,
                 si: false
@@ -148,7 +152,7 @@ crate::quantity! {
             /// Troy pounds (lb t) = 12 troy ounces
             TroyPounds {
                 symbol: "lb t",
-                factor: TROY_GRAIN_TO_GRAM * 480.0 * 12.0,
+                factor: TROY_GRAIN_TO_GRAM.mul(Rational::integer(5760)),
                 ctor: troy_pounds,
                 to: to_troy_pounds,
                 si: false
@@ -156,7 +160,7 @@ crate::quantity! {
             /// Tolas - South Asian unit
             Tolas {
                 symbol: "tola",
-                factor: TROY_GRAIN_TO_GRAM * 180.0,
+                factor: TROY_GRAIN_TO_GRAM.mul(Rational::integer(180)),
                 ctor: tolas,
                 to: to_tolas,
                 si: false
@@ -164,7 +168,7 @@ crate::quantity! {
             /// Carats (ct) - for gemstones
             Carats {
                 symbol: "ct",
-                factor: MILLI * 200.0,
+                factor: Rational::new(1, 5),
                 ctor: carats,
                 to: to_carats,
                 si: false
@@ -180,6 +184,7 @@ crate::quantity! {
             /// Daltons (Da) - atomic mass unit
             Dalton {
                 symbol: "Da",
+                name: "daltons",
                 factor: DALTON_TO_GRAM,
                 ctor: daltons,
                 to: to_daltons,
@@ -188,6 +193,17 @@ crate::quantity! {
         }
     }
 }
+
+impl MassUnit {
+    /// The avoirdupois ounce and pound.
+    pub const AVOIRDUPOIS: &'static [MassUnit] = &[MassUnit::Ounces, MassUnit::Pounds];
+}
+
+impl CompositeUnit<MassUnit> {
+    /// Pounds and ounces, as in `7 lb 4 oz`.
+    pub const POUNDS_OUNCES: Self = CompositeUnit::new(&[MassUnit::Pounds, MassUnit::Ounces]);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,15 +1,15 @@
 //! Moment of inertia (rotational inertia) quantity and units.
 
 use super::mass::{Mass, MassUnit};
-use crate::core::Quantity;
-use crate::space::area::Area;
+use crate::core::Rational;
 use crate::space::length::Length;
-use std::ops::{Div, Mul};
 
 // Conversion factors to kg·m² (primary unit)
 // 1 lb = 0.45359237 kg, 1 ft = 0.3048 m
 // 1 lb·ft² = 0.45359237 * 0.3048² kg·m² ≈ 0.0421401 kg·m²
-const LB_FT2_FACTOR: f64 = 0.45359237 * 0.3048 * 0.3048;
+const LB_TO_KG: Rational = Rational::new(45_359_237, 100_000_000);
+const FT_TO_M: Rational = Rational::new(3048, 10_000);
+const LB_FT2_FACTOR: Rational = LB_TO_KG.mul(FT_TO_M).mul(FT_TO_M);
 crate::quantity! {
     /// A quantity of moment of inertia (rotational inertia).
     ///
@@ -37,6 +37,7 @@ crate::quantity! {
         name: "MomentOfInertia";
         primary: KilogramMetersSquared;
         si: KilogramMetersSquared;
+        dimensions: [2, 1, 0, 0, 0, 0, 0];
 
         units {
             /// Kilogram-meters squared (kg·m²) - SI unit
@@ -81,26 +82,6 @@ impl MomentOfInertia {
     }
 }
 
-// MomentOfInertia / Area = Mass
-impl Div<Area> for MomentOfInertia {
-    type Output = Mass;
-
-    fn div(self, rhs: Area) -> Self::Output {
-        let mass_kg = self.to_kilogram_meters_squared() / rhs.to_square_meters();
-        Mass::new(mass_kg, MassUnit::Kilograms)
-    }
-}
-
-// Mass * Area = MomentOfInertia (approximation for I = m*r²)
-impl Mul<Area> for Mass {
-    type Output = MomentOfInertia;
-
-    fn mul(self, rhs: Area) -> Self::Output {
-        let inertia = self.to_kilograms() * rhs.to_square_meters();
-        MomentOfInertia::new(inertia, MomentOfInertiaUnit::KilogramMetersSquared)
-    }
-}
-
 /// Extension method for creating MomentOfInertia from Mass and Length.
 impl Mass {
     /// Creates a MomentOfInertia for a point mass at the given radius.
@@ -128,6 +109,7 @@ impl Mass {
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::space::Area;
 
     #[test]
     fn test_moment_of_inertia_creation() {
@@ -0,0 +1,117 @@
+//! Float functions that work with and without `std`.
+//!
+//! `f64` and `f32` only have methods such as `sqrt` and `sin` when `std` is
+//! linked. Without it they come from `libm`, so code outside tests calls the
+//! functions in this module instead of the inherent methods.
+
+// `sqrt` is only used by code that needs the `alloc` feature.
+#![cfg_attr(not(feature = "alloc"), allow(dead_code))]
+
+/// Floating-point types with the functions used by this crate.
+pub(crate) trait Float: Copy {
+    fn abs(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn ln(self) -> Self;
+    fn log10(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn powf(self, other: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+}
+
+macro_rules! float_impl {
+    (
+        $float:ident, $powf:ident;
+        unary { $($unary:ident => $libm_unary:ident),+ }
+        binary { $($binary:ident => $libm_binary:ident),+ }
+    ) => {
+        impl Float for $float {
+            $(
+                #[inline]
+                fn $unary(self) -> Self {
+                    #[cfg(feature = "std")]
+                    return $float::$unary(self);
+                    #[cfg(not(feature = "std"))]
+                    return libm::$libm_unary(self);
+                }
+            )+
+            $(
+                #[inline]
+                fn $binary(self, other: Self) -> Self {
+                    #[cfg(feature = "std")]
+                    return $float::$binary(self, other);
+                    #[cfg(not(feature = "std"))]
+                    return libm::$libm_binary(self, other);
+                }
+            )+
+
+            #[inline]
+            fn powi(self, n: i32) -> Self {
+                #[cfg(feature = "std")]
+                return $float::powi(self, n);
+                #[cfg(not(feature = "std"))]
+                return libm::$powf(self, n as $float);
+            }
+        }
+    };
+}
+
+float_impl! {
+    f64, pow;
+    unary {
+        abs => fabs, floor => floor, ceil => ceil, round => round, sqrt => sqrt,
+        sin => sin, cos => cos, tan => tan, asin => asin, acos => acos, atan => atan,
+        ln => log, log10 => log10
+    }
+    binary { atan2 => atan2, hypot => hypot, powf => pow }
+}
+
+float_impl! {
+    f32, powf;
+    unary {
+        abs => fabsf, floor => floorf, ceil => ceilf, round => roundf, sqrt => sqrtf,
+        sin => sinf, cos => cosf, tan => tanf, asin => asinf, acos => acosf, atan => atanf,
+        ln => logf, log10 => log10f
+    }
+    binary { atan2 => atan2f, hypot => hypotf, powf => powf }
+}
+
+macro_rules! float_functions {
+    ($($name:ident($($arg:ident: $ty:ty),*);)+) => {
+        $(
+            #[inline]
+            pub(crate) fn $name<F: Float>(x: F $(, $arg: $ty)*) -> F {
+                x.$name($($arg),*)
+            }
+        )+
+    };
+}
+
+float_functions! {
+    abs();
+    floor();
+    ceil();
+    round();
+    sqrt();
+    sin();
+    cos();
+    tan();
+    asin();
+    acos();
+    atan();
+    ln();
+    log10();
+    atan2(other: F);
+    hypot(other: F);
+    powf(n: F);
+    powi(n: i32);
+}
@@ -1,16 +1,15 @@
 //! Acceleration quantity and units.
 
-use super::velocity::{Velocity, VelocityUnit};
-use crate::core::Quantity;
+use super::velocity::Velocity;
+use crate::core::Rational;
 use crate::time::Time;
-use std::ops::{Div, Mul};
 
 // Conversion factors to m/s²
-const MM_PER_M: f64 = 0.001;
-const FT_PER_M: f64 = 0.3048;
-const MILE_PER_M: f64 = 1609.344;
-const SECONDS_PER_HOUR: f64 = 3600.0;
-const STANDARD_GRAVITY: f64 = 9.80665;
+const MM_PER_M: Rational = Rational::pow10(-3);
+const FT_PER_M: Rational = Rational::new(3048, 10_000);
+const MILE_PER_M: Rational = Rational::new(1_609_344, 1000);
+const SECONDS_PER_HOUR: Rational = Rational::integer(3600);
+const STANDARD_GRAVITY: Rational = Rational::new(980_665, 100_000);
 crate::quantity! {
     /// A quantity of acceleration (rate of change of velocity).
     ///
@@ -35,6 +34,7 @@ crate::quantity! {
         name: "Acceleration";
         primary: MetersPerSecondSquared;
         si: MetersPerSecondSquared;
+        dimensions: [1, 0, -2, 0, 0, 0, 0];
 
         units {
             /// Meters per second squared (m/s²) - SI unit
@@ -64,7 +64,7 @@ crate::quantity! {
             /// Miles per hour squared (mph²)
             MilesPerHourSquared {
                 symbol: "mph²",
-                factor: MILE_PER_M / (SECONDS_PER_HOUR * SECONDS_PER_HOUR),
+                factor: MILE_PER_M.div(SECONDS_PER_HOUR.mul(SECONDS_PER_HOUR)),
                 ctor: miles_per_hour_squared,
                 to: to_miles_per_hour_squared,
                 si: false
@@ -87,35 +87,6 @@ impl Acceleration {
         Self::new(mpss, AccelerationUnit::MetersPerSecondSquared)
     }
 }
-
-// Acceleration * Time = Velocity
-impl Mul<Time> for Acceleration {
-    type Output = Velocity;
-
-    fn mul(self, rhs: Time) -> Self::Output {
-        let mps = self.to_meters_per_second_squared() * rhs.to_seconds();
-        Velocity::new(mps, VelocityUnit::MetersPerSecond)
-    }
-}
-
-// Velocity / Time = Acceleration
-impl Div<Time> for Velocity {
-    type Output = Acceleration;
-
-    fn div(self, rhs: Time) -> Self::Output {
-        Acceleration::from_velocity_and_time(self, rhs)
-    }
-}
-
-// Velocity / Acceleration = Time
-impl Div<Acceleration> for Velocity {
-    type Output = Time;
-
-    fn div(self, rhs: Acceleration) -> Self::Output {
-        let seconds = self.to_meters_per_second() / rhs.to_meters_per_second_squared();
-        Time::seconds(seconds)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,13 +1,12 @@
 //! Force quantity and units.
 
-use super::acceleration::{Acceleration, AccelerationUnit};
-use crate::core::Quantity;
-use crate::mass::{Mass, MassUnit};
-use std::ops::{Div, Mul};
+use super::acceleration::Acceleration;
+use crate::core::Rational;
+use crate::mass::Mass;
 
 // Conversion factors to Newtons
-const STANDARD_GRAVITY: f64 = 9.80665;
-const POUND_TO_KG: f64 = 0.45359237;
+const STANDARD_GRAVITY: Rational = Rational::new(980_665, 100_000);
+const POUND_TO_KG: Rational = Rational::new(45_359_237, 100_000_000);
 crate::quantity! {
     /// A quantity of force.
     ///
@@ -33,6 +32,7 @@ crate::quantity! {
         name: "Force";
         primary: Newtons;
         si: Newtons;
+        dimensions: [1, 1, -2, 0, 0, 0, 0];
 
         units {
             /// Newtons (N) - SI unit
@@ -54,6 +54,7 @@ crate::quantity! {
             /// Kilogram-force (kgf)
             KilogramForce {
                 symbol: "kgf",
+                name: ("kilograms-force", "kilogram-force"),
                 factor: STANDARD_GRAVITY,
                 ctor: kilogram_force,
                 to: to_kilogram_force,
@@ -62,7 +63,8 @@ crate::quantity! {
             /// Pound-force (lbf)
             PoundForce {
                 symbol: "lbf",
-                factor: POUND_TO_KG * STANDARD_GRAVITY,
+                name: ("pounds-force", "pound-force"),
+                factor: POUND_TO_KG.mul(STANDARD_GRAVITY),
                 ctor: pound_force,
                 to: to_pound_force,
                 si: false
@@ -70,7 +72,7 @@ crate::quantity! {
             /// Dynes (dyn) - CGS unit
             Dynes {
                 symbol: "dyn",
-                factor: 1e-5,
+                factor: Rational::pow10(-5),
                 ctor: dynes,
                 to: to_dynes,
                 si: true
@@ -85,44 +87,6 @@ impl Force {
         Self::new(newtons, ForceUnit::Newtons)
     }
 }
-
-// Force / Mass = Acceleration
-impl Div<Mass> for Force {
-    type Output = Acceleration;
-
-    fn div(self, rhs: Mass) -> Self::Output {
-        let mpss = self.to_newtons() / rhs.to_kilograms();
-        Acceleration::new(mpss, AccelerationUnit::MetersPerSecondSquared)
-    }
-}
-
-// Force / Acceleration = Mass
-impl Div<Acceleration> for Force {
-    type Output = Mass;
-
-    fn div(self, rhs: Acceleration) -> Self::Output {
-        let kg = self.to_newtons() / rhs.to_meters_per_second_squared();
-        Mass::new(kg, MassUnit::Kilograms)
-    }
-}
-
-// Mass * Acceleration = Force
-impl Mul<Acceleration> for Mass {
-    type Output = Force;
-
-    fn mul(self, rhs: Acceleration) -> Self::Output {
-        Force::from_mass_and_acceleration(self, rhs)
-    }
-}
-
-// Acceleration * Mass = Force
-impl Mul<Mass> for Acceleration {
-    type Output = Force;
-
-    fn mul(self, rhs: Mass) -> Self::Output {
-        Force::from_mass_and_acceleration(rhs, self)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
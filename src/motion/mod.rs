@@ -7,15 +7,18 @@
 //! - [`Force`] - Push or pull on an object (N, lbf)
 //! - [`Momentum`] - Mass in motion (kg·m/s)
 //! - [`Pressure`] - Force per unit area (Pa, bar, psi)
+//! - [`VolumeFlow`] - Volume per unit time (m³/s, L/min, GPM)
 
 pub mod acceleration;
 pub mod force;
 pub mod momentum;
 pub mod pressure;
 pub mod velocity;
+pub mod volume_flow;
 
 pub use acceleration::{Acceleration, AccelerationConversions, AccelerationUnit};
 pub use force::{Force, ForceConversions, ForceUnit};
 pub use momentum::{Momentum, MomentumConversions, MomentumUnit};
-pub use pressure::{Pressure, PressureConversions, PressureUnit};
+pub use pressure::{AbsolutePressure, GaugePressure, Pressure, PressureConversions, PressureUnit};
 pub use velocity::{Velocity, VelocityConversions, VelocityUnit};
+pub use volume_flow::{VolumeFlow, VolumeFlowConversions, VolumeFlowUnit};
@@ -1,13 +1,12 @@
 //! Momentum quantity and units.
 
-use super::velocity::{Velocity, VelocityUnit};
-use crate::core::Quantity;
-use crate::mass::{Mass, MassUnit};
-use std::ops::{Div, Mul};
+use super::velocity::Velocity;
+use crate::core::Rational;
+use crate::mass::Mass;
 
 // Conversion factors to kg·m/s
-const LB_TO_KG: f64 = 0.45359237;
-const FT_TO_M: f64 = 0.3048;
+const LB_TO_KG: Rational = Rational::new(45_359_237, 100_000_000);
+const FT_TO_M: Rational = Rational::new(3048, 10_000);
 crate::quantity! {
     /// A quantity of momentum (mass in motion).
     ///
@@ -33,6 +32,7 @@ crate::quantity! {
         name: "Momentum";
         primary: KilogramMetersPerSecond;
         si: KilogramMetersPerSecond;
+        dimensions: [1, 1, -1, 0, 0, 0, 0];
 
         units {
             /// Kilogram-meters per second (kg·m/s) - SI unit
@@ -54,7 +54,7 @@ crate::quantity! {
             /// Pound-feet per second (lb·ft/s)
             PoundFeetPerSecond {
                 symbol: "lb·ft/s",
-                factor: LB_TO_KG * FT_TO_M,
+                factor: LB_TO_KG.mul(FT_TO_M),
                 ctor: pound_feet_per_second,
                 to: to_pound_feet_per_second,
                 si: false
@@ -69,44 +69,6 @@ impl Momentum {
         Self::new(kgmps, MomentumUnit::KilogramMetersPerSecond)
     }
 }
-
-// Momentum / Mass = Velocity
-impl Div<Mass> for Momentum {
-    type Output = Velocity;
-
-    fn div(self, rhs: Mass) -> Self::Output {
-        let mps = self.to_kilogram_meters_per_second() / rhs.to_kilograms();
-        Velocity::new(mps, VelocityUnit::MetersPerSecond)
-    }
-}
-
-// Momentum / Velocity = Mass
-impl Div<Velocity> for Momentum {
-    type Output = Mass;
-
-    fn div(self, rhs: Velocity) -> Self::Output {
-        let kg = self.to_kilogram_meters_per_second() / rhs.to_meters_per_second();
-        Mass::new(kg, MassUnit::Kilograms)
-    }
-}
-
-// Mass * Velocity = Momentum
-impl Mul<Velocity> for Mass {
-    type Output = Momentum;
-
-    fn mul(self, rhs: Velocity) -> Self::Output {
-        Momentum::from_mass_and_velocity(self, rhs)
-    }
-}
-
-// Velocity * Mass = Momentum
-impl Mul<Mass> for Velocity {
-    type Output = Momentum;
-
-    fn mul(self, rhs: Mass) -> Self::Output {
-        Momentum::from_mass_and_velocity(rhs, self)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
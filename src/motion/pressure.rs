@@ -1,17 +1,21 @@
 //! Pressure quantity and units.
 
-use super::force::{Force, ForceUnit};
-use crate::core::Quantity;
-use crate::space::area::{Area, AreaUnit};
-use std::ops::{Div, Mul};
+use super::force::Force;
+use crate::core::affine::{Frame, Point};
+use crate::space::area::Area;
+
+use crate::core::Rational;
 
 // Conversion factors to Pascals
 const BAR_TO_PA: f64 = 100_000.0;
 const ATM_TO_PA: f64 = 101_325.0;
-const PSI_TO_PA: f64 = 6894.757293168;
-const MMHG_TO_PA: f64 = 133.322387415;
-const INHG_TO_PA: f64 = 3386.389;
-const TORR_TO_PA: f64 = ATM_TO_PA / 760.0;
+// One pound-force (0.45359237 kg × 9.80665 m/s²) per square inch (0.0254² m²)
+const PSI_TO_PA: Rational = Rational::new(45_359_237, 100_000_000)
+    .mul(Rational::new(980_665, 100_000))
+    .div(Rational::new(64_516, 100_000_000));
+const MMHG_TO_PA: Rational = Rational::new(133_322_387_415, 1_000_000_000);
+const INHG_TO_PA: Rational = Rational::new(3_386_389, 1000);
+const TORR_TO_PA: Rational = Rational::new(101_325, 760);
 crate::quantity! {
     /// A quantity of pressure (force per unit area).
     ///
@@ -37,6 +41,7 @@ crate::quantity! {
         name: "Pressure";
         primary: Pascals;
         si: Pascals;
+        dimensions: [-1, 1, -2, 0, 0, 0, 0];
 
         units {
             /// Pascals (Pa) - SI unit (N/m²)
@@ -79,6 +84,20 @@ crate::quantity! {
                 to: to_psi,
                 si: false
             },
+            /// Pounds per square inch gauge (psig), measured from one standard
+            /// atmosphere. The quantity holds the absolute pressure, so
+            /// `Pressure::psig(0.0)` equals `Pressure::atmospheres(1.0)`, and
+            /// arithmetic on it gives absolute pressures in pascals; use
+            /// [`GaugePressure`] for readings relative to another ambient
+            /// pressure.
+            PoundsPerSquareInchGauge {
+                symbol: "psig",
+                factor: PSI_TO_PA,
+                offset: ATM_TO_PA,
+                ctor: psig,
+                to: to_psig,
+                si: false
+            },
             /// Standard atmospheres (atm)
             Atmospheres {
                 symbol: "atm",
@@ -122,32 +141,50 @@ impl Pressure {
     }
 }
 
-// Pressure * Area = Force
-impl Mul<Area> for Pressure {
-    type Output = Force;
+/// Frame of absolute pressures, measured from a perfect vacuum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vacuum;
 
-    fn mul(self, rhs: Area) -> Self::Output {
-        let newtons = self.to_pascals() * rhs.to_square_meters();
-        Force::new(newtons, ForceUnit::Newtons)
-    }
-}
+impl Frame for Vacuum {}
+
+/// Frame of gauge pressures, measured from the ambient atmospheric pressure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Ambient;
 
-// Force / Area = Pressure
-impl Div<Area> for Force {
-    type Output = Pressure;
+impl Frame for Ambient {}
 
-    fn div(self, rhs: Area) -> Self::Output {
-        Pressure::from_force_and_area(self, rhs)
+/// A pressure measured from a perfect vacuum (e.g., "psia").
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::prelude::*;
+/// use rquants::motion::{AbsolutePressure, GaugePressure};
+///
+/// let tyre = GaugePressure::new(Pressure::psi(32.0));
+/// let absolute = tyre.to_absolute(Pressure::atmospheres(1.0));
+/// assert!((absolute.offset().to_psi() - 46.696).abs() < 1e-3);
+///
+/// // The difference between two absolute pressures is a plain Pressure
+/// let drop: Pressure = absolute - AbsolutePressure::new(Pressure::bars(2.0));
+/// assert!(drop.to_kilopascals() > 100.0);
+/// ```
+pub type AbsolutePressure = Point<Pressure, Vacuum>;
+
+/// A pressure measured relative to the ambient atmospheric pressure (e.g., "psig").
+pub type GaugePressure = Point<Pressure, Ambient>;
+
+impl Point<Pressure, Vacuum> {
+    /// Converts to a gauge pressure given the ambient atmospheric pressure.
+    pub fn to_gauge(self, atmosphere: Pressure) -> GaugePressure {
+        GaugePressure::new(self - AbsolutePressure::new(atmosphere))
     }
 }
 
-// Force / Pressure = Area
-impl Div<Pressure> for Force {
-    type Output = Area;
-
-    fn div(self, rhs: Pressure) -> Self::Output {
-        let sqm = self.to_newtons() / rhs.to_pascals();
-        Area::new(sqm, AreaUnit::SquareMeters)
+impl Point<Pressure, Ambient> {
+    /// Converts to an absolute pressure given the ambient atmospheric pressure.
+    pub fn to_absolute(self, atmosphere: Pressure) -> AbsolutePressure {
+        self.to_frame(AbsolutePressure::new(atmosphere))
     }
 }
 #[cfg(test)]
@@ -181,6 +218,43 @@ mod tests {
         assert!((p.to_pascals() - 101325.0).abs() < 100.0);
     }
 
+    #[test]
+    fn test_psig_conversion() {
+        let p = Pressure::psig(0.0);
+        assert!((p.to_atmospheres() - 1.0).abs() < 1e-12);
+
+        let tyre = Pressure::psig(32.0);
+        assert!((tyre.to_psi() - 46.695949).abs() < 1e-6);
+        assert!((Pressure::bars(3.0).to_psig() - 28.816).abs() < 1e-3);
+        assert!(!PressureUnit::PoundsPerSquareInchGauge.is_linear());
+    }
+
+    #[test]
+    fn test_psig_arithmetic_is_absolute() {
+        // Adding a pressure to a gauge reading raises the gauge reading, and
+        // the result is an absolute pressure in pascals
+        let p = Pressure::psig(30.0) + Pressure::psi(2.0);
+        assert_eq!(p.unit(), PressureUnit::Pascals);
+        assert!((p.to_psig() - 32.0).abs() < 1e-9);
+
+        // Scaling works on the absolute pressure
+        let halved = Pressure::psig(0.0) / 2.0;
+        assert!((halved.to_atmospheres() - 0.5).abs() < 1e-12);
+        let doubled = Pressure::psig(10.0) * 2.0;
+        assert_eq!(doubled.unit(), PressureUnit::Pascals);
+        assert!((doubled.to_psi() - 2.0 * (10.0 + 14.695_948_8)).abs() < 1e-6);
+
+        // The difference of two gauge readings is the same in absolute terms
+        let drop = Pressure::psig(32.0) - Pressure::psig(12.0);
+        assert_eq!(drop.unit(), PressureUnit::Pascals);
+        assert!((drop.to_psi() - 20.0).abs() < 1e-9);
+        assert_eq!(drop.to_string(), format!("{} Pa", drop.to_pascals()));
+
+        let parsed: Pressure = "32 psig".parse().unwrap();
+        assert_eq!(parsed.unit(), PressureUnit::PoundsPerSquareInchGauge);
+        assert_eq!(parsed.value(), 32.0);
+    }
+
     #[test]
     fn test_torr_conversion() {
         let p = Pressure::torr(760.0);
@@ -211,4 +285,17 @@ mod tests {
         let a = f / p;
         assert_eq!(a.to_square_meters(), 10.0);
     }
+
+    #[test]
+    fn test_gauge_and_absolute_pressure() {
+        let atmosphere = Pressure::kilopascals(100.0);
+        let gauge = GaugePressure::new(Pressure::bars(2.0));
+        let absolute = gauge.to_absolute(atmosphere);
+        assert_eq!(absolute.offset().to_kilopascals(), 300.0);
+        assert_eq!(absolute.offset().unit(), PressureUnit::Kilopascals);
+        assert_eq!(absolute.to_gauge(atmosphere), gauge);
+
+        let rise = absolute - AbsolutePressure::new(atmosphere);
+        assert_eq!(rise, Pressure::bars(2.0));
+    }
 }
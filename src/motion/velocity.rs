@@ -1,17 +1,42 @@
 //! Velocity quantity and units.
 
-use crate::core::Quantity;
+use crate::core::Rational;
+use crate::math;
 use crate::space::length::Length;
 use crate::time::Time;
-use std::ops::{Div, Mul};
 
 // Conversion factors to m/s
-const SECONDS_PER_HOUR: f64 = 3600.0;
-const MM_PER_M: f64 = 0.001;
-const KM_PER_M: f64 = 1000.0;
-const FT_PER_M: f64 = 0.3048;
-const MILE_PER_M: f64 = 1609.344;
-const NAUTICAL_MILE_PER_M: f64 = 1852.0;
+const SECONDS_PER_HOUR: Rational = Rational::integer(3600);
+const MM_PER_M: Rational = Rational::pow10(-3);
+const KM_PER_M: Rational = Rational::pow10(3);
+const FT_PER_M: Rational = Rational::new(3048, 10_000);
+const MILE_PER_M: Rational = Rational::new(1_609_344, 1000);
+const NAUTICAL_MILE_PER_M: Rational = Rational::integer(1852);
+
+/// Wind speed in m/s of force 1 on the Beaufort scale; force `B` is
+/// `0.836 * B^1.5` m/s.
+const BEAUFORT_SCALE_FACTOR: f64 = 0.836;
+
+// The power law is applied to the magnitude and the sign kept, so negative
+// velocities map to negative forces instead of NaN.
+
+fn beaufort_to_meters_per_second(force: f64) -> f64 {
+    let speed = BEAUFORT_SCALE_FACTOR * math::powf(math::abs(force), 1.5);
+    if force < 0.0 {
+        -speed
+    } else {
+        speed
+    }
+}
+
+fn meters_per_second_to_beaufort(speed: f64) -> f64 {
+    let force = math::powf(math::abs(speed) / BEAUFORT_SCALE_FACTOR, 2.0 / 3.0);
+    if speed < 0.0 {
+        -force
+    } else {
+        force
+    }
+}
 crate::quantity! {
     /// A quantity of velocity (rate of change of position).
     ///
@@ -36,6 +61,7 @@ crate::quantity! {
         name: "Velocity";
         primary: MetersPerSecond;
         si: MetersPerSecond;
+        dimensions: [1, 0, -1, 0, 0, 0, 0];
 
         units {
             /// Meters per second (m/s) - SI unit
@@ -65,7 +91,8 @@ crate::quantity! {
             /// Kilometers per hour (km/h)
             KilometersPerHour {
                 symbol: "km/h",
-                factor: KM_PER_M / SECONDS_PER_HOUR,
+                aliases: ["km/hr", "kph"],
+                factor: KM_PER_M.div(SECONDS_PER_HOUR),
                 ctor: kilometers_per_hour,
                 to: to_kilometers_per_hour,
                 si: true
@@ -81,7 +108,8 @@ crate::quantity! {
             /// Miles per hour (mph)
             MilesPerHour {
                 symbol: "mph",
-                factor: MILE_PER_M / SECONDS_PER_HOUR,
+                aliases: ["mi/h"],
+                factor: MILE_PER_M.div(SECONDS_PER_HOUR),
                 ctor: miles_per_hour,
                 to: to_miles_per_hour,
                 si: false
@@ -89,10 +117,24 @@ crate::quantity! {
             /// Knots (kn) - nautical miles per hour
             Knots {
                 symbol: "kn",
-                factor: NAUTICAL_MILE_PER_M / SECONDS_PER_HOUR,
+                aliases: ["kt"],
+                factor: NAUTICAL_MILE_PER_M.div(SECONDS_PER_HOUR),
                 ctor: knots,
                 to: to_knots,
                 si: false
+            },
+            /// Beaufort wind force (Bft). Conversions use the empirical
+            /// relation `v = 0.836 B^1.5` m/s, so values are continuous;
+            /// round to get the force number. Negative values keep their
+            /// sign.
+            Beaufort {
+                symbol: "Bft",
+                name: ("Beaufort", "Beaufort"),
+                factor: 1.0,
+                convert: (beaufort_to_meters_per_second, meters_per_second_to_beaufort),
+                ctor: beaufort,
+                to: to_beaufort,
+                si: false
             }
         }
     }
@@ -104,29 +146,10 @@ impl Velocity {
         Self::new(mps, VelocityUnit::MetersPerSecond)
     }
 }
-
-// Velocity * Time = Length
-impl Mul<Time> for Velocity {
-    type Output = Length;
-
-    fn mul(self, rhs: Time) -> Self::Output {
-        let meters = self.to_meters_per_second() * rhs.to_seconds();
-        Length::meters(meters)
-    }
-}
-
-// Length / Time = Velocity
-impl Div<Time> for Length {
-    type Output = Velocity;
-
-    fn div(self, rhs: Time) -> Self::Output {
-        Velocity::from_length_and_time(self, rhs)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::Quantity;
+    use crate::core::{Dimension, Quantity};
 
     #[test]
     fn test_velocity_creation() {
@@ -173,4 +196,35 @@ mod tests {
         // 1 knot = 1852/3600 m/s ≈ 0.5144 m/s
         assert!((v.to_meters_per_second() - 0.5144).abs() < 0.001);
     }
+
+    #[test]
+    fn test_exact_factors() {
+        assert!(VelocityUnit::FeetPerSecond.is_exact());
+        assert!(VelocityUnit::MilesPerHour.is_exact());
+        assert!(VelocityUnit::Knots.is_exact());
+        // 15 mph is exactly 22 ft/s
+        assert_eq!(Velocity::miles_per_hour(15.0).to_feet_per_second(), 22.0);
+        // ft/s to mph multiplies by 15/22 and rounds once
+        assert_eq!(
+            Velocity::feet_per_second(0.1).to_miles_per_hour(),
+            0.06818181818181819
+        );
+    }
+
+    #[test]
+    fn test_beaufort_conversion() {
+        // Force 12 (hurricane) starts at about 32.7 m/s
+        let v = Velocity::beaufort(12.0);
+        assert!((v.to_meters_per_second() - 34.75).abs() < 0.01);
+        assert_eq!(Velocity::knots(40.0).to_beaufort().round(), 8.0);
+        assert_eq!(Velocity::beaufort(0.0).to_meters_per_second(), 0.0);
+        assert_eq!(
+            Velocity::beaufort(-12.0).to_meters_per_second(),
+            -v.to_meters_per_second()
+        );
+        assert!((Velocity::meters_per_second(-12.29).to_beaufort() + 6.0).abs() < 0.01);
+
+        let parsed = VelocityDimension::parse("6 Bft").unwrap();
+        assert!((parsed.to_meters_per_second() - 12.29).abs() < 0.01);
+    }
 }
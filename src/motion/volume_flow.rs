@@ -0,0 +1,195 @@
+//! Volume flow quantity and units.
+
+use crate::core::Rational;
+use crate::space::volume::{CUBIC_FOOT_TO_CUBIC_METER, US_GALLON_TO_CUBIC_METER};
+
+// Conversion factors to m³/s
+const LITER_TO_CUBIC_METER: Rational = Rational::pow10(-3);
+const SECONDS_PER_MINUTE: Rational = Rational::integer(60);
+const SECONDS_PER_HOUR: Rational = Rational::integer(3600);
+const SECONDS_PER_DAY: Rational = Rational::integer(86400);
+crate::quantity! {
+    /// A quantity of volume flow (volumetric flow rate).
+    ///
+    /// Volume flow is the volume of fluid passing through a surface per unit time.
+    /// Q = V / t
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    ///
+    /// let flow = VolumeFlow::liters_per_minute(12.0);
+    /// let time = Time::minutes(5.0);
+    ///
+    /// // Volume = VolumeFlow * Time
+    /// let volume = flow * time;
+    /// assert!((volume.to_liters() - 60.0).abs() < 1e-10);
+    /// ```
+    pub quantity VolumeFlow {
+        unit: VolumeFlowUnit;
+        dimension: VolumeFlowDimension;
+        conversions: VolumeFlowConversions;
+        name: "VolumeFlow";
+        primary: CubicMetersPerSecond;
+        si: CubicMetersPerSecond;
+        dimensions: [3, 0, -1, 0, 0, 0, 0];
+
+        units {
+            /// Cubic meters per second (m³/s) - SI unit
+            CubicMetersPerSecond {
+                symbol: "m³/s",
+                factor: 1.0,
+                ctor: cubic_meters_per_second,
+                to: to_cubic_meters_per_second,
+                si: true
+            },
+            /// Milliliters per second (mL/s)
+            MillilitersPerSecond {
+                symbol: "mL/s",
+                factor: LITER_TO_CUBIC_METER.mul(Rational::pow10(-3)),
+                ctor: milliliters_per_second,
+                to: to_milliliters_per_second,
+                si: true
+            },
+            /// Liters per second (L/s)
+            LitersPerSecond {
+                symbol: "L/s",
+                factor: LITER_TO_CUBIC_METER,
+                ctor: liters_per_second,
+                to: to_liters_per_second,
+                si: true
+            },
+            /// Liters per minute (L/min)
+            LitersPerMinute {
+                symbol: "L/min",
+                factor: LITER_TO_CUBIC_METER.div(SECONDS_PER_MINUTE),
+                ctor: liters_per_minute,
+                to: to_liters_per_minute,
+                si: false
+            },
+            /// Liters per hour (L/h)
+            LitersPerHour {
+                symbol: "L/h",
+                factor: LITER_TO_CUBIC_METER.div(SECONDS_PER_HOUR),
+                ctor: liters_per_hour,
+                to: to_liters_per_hour,
+                si: false
+            },
+            /// Liters per day (L/d)
+            LitersPerDay {
+                symbol: "L/d",
+                factor: LITER_TO_CUBIC_METER.div(SECONDS_PER_DAY),
+                ctor: liters_per_day,
+                to: to_liters_per_day,
+                si: false
+            },
+            /// US gallons per second (gal/s)
+            GallonsPerSecond {
+                symbol: "gal/s",
+                factor: US_GALLON_TO_CUBIC_METER,
+                ctor: gallons_per_second,
+                to: to_gallons_per_second,
+                si: false
+            },
+            /// US gallons per minute (GPM)
+            GallonsPerMinute {
+                symbol: "GPM",
+                factor: US_GALLON_TO_CUBIC_METER.div(SECONDS_PER_MINUTE),
+                ctor: gallons_per_minute,
+                to: to_gallons_per_minute,
+                si: false
+            },
+            /// US gallons per hour (GPH)
+            GallonsPerHour {
+                symbol: "GPH",
+                factor: US_GALLON_TO_CUBIC_METER.div(SECONDS_PER_HOUR),
+                ctor: gallons_per_hour,
+                to: to_gallons_per_hour,
+                si: false
+            },
+            /// US gallons per day (GPD)
+            GallonsPerDay {
+                symbol: "GPD",
+                factor: US_GALLON_TO_CUBIC_METER.div(SECONDS_PER_DAY),
+                ctor: gallons_per_day,
+                to: to_gallons_per_day,
+                si: false
+            },
+            /// Cubic feet per second (ft³/s)
+            CubicFeetPerSecond {
+                symbol: "ft³/s",
+                factor: CUBIC_FOOT_TO_CUBIC_METER,
+                ctor: cubic_feet_per_second,
+                to: to_cubic_feet_per_second,
+                si: false
+            },
+            /// Cubic feet per minute (CFM)
+            CubicFeetPerMinute {
+                symbol: "CFM",
+                factor: CUBIC_FOOT_TO_CUBIC_METER.div(SECONDS_PER_MINUTE),
+                ctor: cubic_feet_per_minute,
+                to: to_cubic_feet_per_minute,
+                si: false
+            },
+            /// Cubic feet per hour (ft³/h)
+            CubicFeetPerHour {
+                symbol: "ft³/h",
+                factor: CUBIC_FOOT_TO_CUBIC_METER.div(SECONDS_PER_HOUR),
+                ctor: cubic_feet_per_hour,
+                to: to_cubic_feet_per_hour,
+                si: false
+            }
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Quantity;
+    use crate::space::Volume;
+    use crate::time::Time;
+
+    #[test]
+    fn test_volume_flow_creation() {
+        let q = VolumeFlow::cubic_meters_per_second(2.0);
+        assert_eq!(q.value(), 2.0);
+        assert_eq!(q.unit(), VolumeFlowUnit::CubicMetersPerSecond);
+    }
+
+    #[test]
+    fn test_volume_flow_conversions() {
+        let q = VolumeFlow::liters_per_second(1.0);
+        assert!((q.to_liters_per_minute() - 60.0).abs() < 1e-9);
+        assert!((q.to_liters_per_hour() - 3600.0).abs() < 1e-9);
+        assert!((q.to_milliliters_per_second() - 1000.0).abs() < 1e-9);
+
+        let gpm = VolumeFlow::gallons_per_minute(1.0);
+        assert!((gpm.to_liters_per_minute() - 3.785411784).abs() < 1e-9);
+
+        let cfm = VolumeFlow::cubic_feet_per_minute(60.0);
+        assert!((cfm.to_cubic_feet_per_second() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_volume_flow_times_time() {
+        let q = VolumeFlow::liters_per_hour(30.0);
+        let v = q * Time::hours(2.0);
+        assert!((v.to_liters() - 60.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_volume_over_time() {
+        let q = Volume::cubic_meters(9.0) / Time::seconds(3.0);
+        assert!((q.to_cubic_meters_per_second() - 3.0).abs() < 1e-12);
+
+        let t = Volume::liters(10.0) / VolumeFlow::liters_per_minute(2.0);
+        assert!((t.to_minutes() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_volume_flow_dsl() {
+        let q = 5.0.gallons_per_minute();
+        assert_eq!(q.to_gallons_per_minute(), 5.0);
+    }
+}
@@ -1,6 +1,4 @@
 //! Illuminance quantity and units.
-use crate::core::Quantity;
-use std::ops::Mul;
 crate::quantity! {
     /// A quantity of illuminance.
     ///
@@ -25,6 +23,8 @@ crate::quantity! {
         name: "Illuminance";
         primary: Lux;
         si: Lux;
+        dimensions: [-2, 0, 0, 0, 0, 0, 1];
+        angle: 2;
 
         units {
             /// Lux (lx) - SI unit (lm/m²)
@@ -38,55 +38,12 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::luminous_exposure::{LuminousExposure, LuminousExposureUnit};
-use super::luminous_flux::{LuminousFlux, LuminousFluxUnit};
-use crate::space::Area;
-use crate::time::Time;
-
-// Illuminance * Area = LuminousFlux
-impl Mul<Area> for Illuminance {
-    type Output = LuminousFlux;
-
-    fn mul(self, rhs: Area) -> Self::Output {
-        let lumens = self.to_lux() * rhs.to_square_meters();
-        LuminousFlux::new(lumens, LuminousFluxUnit::Lumens)
-    }
-}
-
-// Area * Illuminance = LuminousFlux
-impl Mul<Illuminance> for Area {
-    type Output = LuminousFlux;
-
-    fn mul(self, rhs: Illuminance) -> Self::Output {
-        let lumens = rhs.to_lux() * self.to_square_meters();
-        LuminousFlux::new(lumens, LuminousFluxUnit::Lumens)
-    }
-}
-
-// Illuminance * Time = LuminousExposure
-impl Mul<Time> for Illuminance {
-    type Output = LuminousExposure;
-
-    fn mul(self, rhs: Time) -> Self::Output {
-        let lx_s = self.to_lux() * rhs.to_seconds();
-        LuminousExposure::new(lx_s, LuminousExposureUnit::LuxSeconds)
-    }
-}
-
-// Time * Illuminance = LuminousExposure
-impl Mul<Illuminance> for Time {
-    type Output = LuminousExposure;
-
-    fn mul(self, rhs: Illuminance) -> Self::Output {
-        let lx_s = rhs.to_lux() * self.to_seconds();
-        LuminousExposure::new(lx_s, LuminousExposureUnit::LuxSeconds)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::space::Area;
+    use crate::time::Time;
 
     #[test]
     fn test_illuminance_creation() {
@@ -1,6 +1,4 @@
 //! Luminance quantity and units.
-use crate::core::Quantity;
-use std::ops::Mul;
 crate::quantity! {
     /// A quantity of luminance.
     ///
@@ -26,6 +24,7 @@ crate::quantity! {
         name: "Luminance";
         primary: CandelasPerSquareMeter;
         si: CandelasPerSquareMeter;
+        dimensions: [-2, 0, 0, 0, 0, 0, 1];
 
         units {
             /// Candelas per square meter (cd/m²) - SI unit
@@ -39,33 +38,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::luminous_intensity::{LuminousIntensity, LuminousIntensityUnit};
-use crate::space::Area;
-
-// Luminance * Area = LuminousIntensity
-impl Mul<Area> for Luminance {
-    type Output = LuminousIntensity;
-
-    fn mul(self, rhs: Area) -> Self::Output {
-        let cd = self.to_candelas_per_square_meter() * rhs.to_square_meters();
-        LuminousIntensity::new(cd, LuminousIntensityUnit::Candelas)
-    }
-}
-
-// Area * Luminance = LuminousIntensity
-impl Mul<Luminance> for Area {
-    type Output = LuminousIntensity;
-
-    fn mul(self, rhs: Luminance) -> Self::Output {
-        let cd = rhs.to_candelas_per_square_meter() * self.to_square_meters();
-        LuminousIntensity::new(cd, LuminousIntensityUnit::Candelas)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::space::Area;
 
     #[test]
     fn test_luminance_creation() {
@@ -1,6 +1,4 @@
 //! Luminous energy quantity and units.
-use crate::core::Quantity;
-use std::ops::Div;
 crate::quantity! {
     /// A quantity of luminous energy.
     ///
@@ -25,6 +23,8 @@ crate::quantity! {
         name: "LuminousEnergy";
         primary: LumenSeconds;
         si: LumenSeconds;
+        dimensions: [0, 0, 1, 0, 0, 0, 1];
+        angle: 2;
 
         units {
             /// Lumen-seconds (lm·s) - SI unit
@@ -38,33 +38,12 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::luminous_flux::{LuminousFlux, LuminousFluxUnit};
-use crate::time::{Time, TimeUnit};
-
-// LuminousEnergy / Time = LuminousFlux
-impl Div<Time> for LuminousEnergy {
-    type Output = LuminousFlux;
-
-    fn div(self, rhs: Time) -> Self::Output {
-        let lumens = self.to_lumen_seconds() / rhs.to_seconds();
-        LuminousFlux::new(lumens, LuminousFluxUnit::Lumens)
-    }
-}
-
-// LuminousEnergy / LuminousFlux = Time
-impl Div<LuminousFlux> for LuminousEnergy {
-    type Output = Time;
-
-    fn div(self, rhs: LuminousFlux) -> Self::Output {
-        let seconds = self.to_lumen_seconds() / rhs.to_lumens();
-        Time::new(seconds, TimeUnit::Seconds)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::photo::LuminousFlux;
+    use crate::time::Time;
 
     #[test]
     fn test_luminous_energy_creation() {
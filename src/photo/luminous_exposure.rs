@@ -1,6 +1,4 @@
 //! Luminous exposure quantity and units.
-use crate::core::Quantity;
-use std::ops::Div;
 crate::quantity! {
     /// A quantity of luminous exposure.
     ///
@@ -25,6 +23,8 @@ crate::quantity! {
         name: "LuminousExposure";
         primary: LuxSeconds;
         si: LuxSeconds;
+        dimensions: [-2, 0, 1, 0, 0, 0, 1];
+        angle: 2;
 
         units {
             /// Lux-seconds (lx·s) - SI unit
@@ -38,33 +38,12 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::illuminance::{Illuminance, IlluminanceUnit};
-use crate::time::{Time, TimeUnit};
-
-// LuminousExposure / Time = Illuminance
-impl Div<Time> for LuminousExposure {
-    type Output = Illuminance;
-
-    fn div(self, rhs: Time) -> Self::Output {
-        let lux = self.to_lux_seconds() / rhs.to_seconds();
-        Illuminance::new(lux, IlluminanceUnit::Lux)
-    }
-}
-
-// LuminousExposure / Illuminance = Time
-impl Div<Illuminance> for LuminousExposure {
-    type Output = Time;
-
-    fn div(self, rhs: Illuminance) -> Self::Output {
-        let seconds = self.to_lux_seconds() / rhs.to_lux();
-        Time::new(seconds, TimeUnit::Seconds)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::photo::Illuminance;
+    use crate::time::Time;
 
     #[test]
     fn test_luminous_exposure_creation() {
@@ -1,6 +1,4 @@
 //! Luminous flux quantity and units.
-use crate::core::Quantity;
-use std::ops::{Div, Mul};
 crate::quantity! {
     /// A quantity of luminous flux.
     ///
@@ -26,6 +24,8 @@ crate::quantity! {
         name: "LuminousFlux";
         primary: Lumens;
         si: Lumens;
+        dimensions: [0, 0, 0, 0, 0, 0, 1];
+        angle: 2;
 
         units {
             /// Lumens (lm) - SI unit
@@ -39,76 +39,13 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::illuminance::{Illuminance, IlluminanceUnit};
-use super::luminous_energy::{LuminousEnergy, LuminousEnergyUnit};
-use super::luminous_intensity::{LuminousIntensity, LuminousIntensityUnit};
-use crate::space::{Area, AreaUnit, SolidAngle, SolidAngleUnit};
-use crate::time::Time;
-
-// LuminousFlux / Area = Illuminance
-impl Div<Area> for LuminousFlux {
-    type Output = Illuminance;
-
-    fn div(self, rhs: Area) -> Self::Output {
-        let lux = self.to_lumens() / rhs.to_square_meters();
-        Illuminance::new(lux, IlluminanceUnit::Lux)
-    }
-}
-
-// LuminousFlux / Illuminance = Area
-impl Div<Illuminance> for LuminousFlux {
-    type Output = Area;
-
-    fn div(self, rhs: Illuminance) -> Self::Output {
-        let m2 = self.to_lumens() / rhs.to_lux();
-        Area::new(m2, AreaUnit::SquareMeters)
-    }
-}
-
-// LuminousFlux / SolidAngle = LuminousIntensity
-impl Div<SolidAngle> for LuminousFlux {
-    type Output = LuminousIntensity;
-
-    fn div(self, rhs: SolidAngle) -> Self::Output {
-        let cd = self.to_lumens() / rhs.to_steradians();
-        LuminousIntensity::new(cd, LuminousIntensityUnit::Candelas)
-    }
-}
-
-// LuminousFlux / LuminousIntensity = SolidAngle
-impl Div<LuminousIntensity> for LuminousFlux {
-    type Output = SolidAngle;
-
-    fn div(self, rhs: LuminousIntensity) -> Self::Output {
-        let sr = self.to_lumens() / rhs.to_candelas();
-        SolidAngle::new(sr, SolidAngleUnit::Steradians)
-    }
-}
-
-// LuminousFlux * Time = LuminousEnergy
-impl Mul<Time> for LuminousFlux {
-    type Output = LuminousEnergy;
-
-    fn mul(self, rhs: Time) -> Self::Output {
-        let lm_s = self.to_lumens() * rhs.to_seconds();
-        LuminousEnergy::new(lm_s, LuminousEnergyUnit::LumenSeconds)
-    }
-}
-
-// Time * LuminousFlux = LuminousEnergy
-impl Mul<LuminousFlux> for Time {
-    type Output = LuminousEnergy;
-
-    fn mul(self, rhs: LuminousFlux) -> Self::Output {
-        let lm_s = rhs.to_lumens() * self.to_seconds();
-        LuminousEnergy::new(lm_s, LuminousEnergyUnit::LumenSeconds)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::space::Area;
+    use crate::space::SolidAngle;
+    use crate::time::Time;
 
     #[test]
     fn test_luminous_flux_creation() {
@@ -1,6 +1,4 @@
 //! Luminous intensity quantity and units.
-use crate::core::Quantity;
-use std::ops::{Div, Mul};
 crate::quantity! {
     /// A quantity of luminous intensity.
     ///
@@ -26,6 +24,7 @@ crate::quantity! {
         name: "LuminousIntensity";
         primary: Candelas;
         si: Candelas;
+        dimensions: [0, 0, 0, 0, 0, 0, 1];
 
         units {
             /// Candelas (cd) - SI unit
@@ -39,54 +38,12 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::luminance::{Luminance, LuminanceUnit};
-use super::luminous_flux::{LuminousFlux, LuminousFluxUnit};
-use crate::space::{Area, AreaUnit, SolidAngle};
-
-// LuminousIntensity * SolidAngle = LuminousFlux
-impl Mul<SolidAngle> for LuminousIntensity {
-    type Output = LuminousFlux;
-
-    fn mul(self, rhs: SolidAngle) -> Self::Output {
-        let lumens = self.to_candelas() * rhs.to_steradians();
-        LuminousFlux::new(lumens, LuminousFluxUnit::Lumens)
-    }
-}
-
-// SolidAngle * LuminousIntensity = LuminousFlux
-impl Mul<LuminousIntensity> for SolidAngle {
-    type Output = LuminousFlux;
-
-    fn mul(self, rhs: LuminousIntensity) -> Self::Output {
-        let lumens = rhs.to_candelas() * self.to_steradians();
-        LuminousFlux::new(lumens, LuminousFluxUnit::Lumens)
-    }
-}
-
-// LuminousIntensity / Area = Luminance
-impl Div<Area> for LuminousIntensity {
-    type Output = Luminance;
-
-    fn div(self, rhs: Area) -> Self::Output {
-        let cdpm2 = self.to_candelas() / rhs.to_square_meters();
-        Luminance::new(cdpm2, LuminanceUnit::CandelasPerSquareMeter)
-    }
-}
-
-// LuminousIntensity / Luminance = Area
-impl Div<Luminance> for LuminousIntensity {
-    type Output = Area;
-
-    fn div(self, rhs: Luminance) -> Self::Output {
-        let m2 = self.to_candelas() / rhs.to_candelas_per_square_meter();
-        Area::new(m2, AreaUnit::SquareMeters)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::space::Area;
+    use crate::space::SolidAngle;
 
     #[test]
     fn test_luminous_intensity_creation() {
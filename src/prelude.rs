@@ -9,14 +9,43 @@
 // Core traits
 pub use crate::core::Dimension;
 pub use crate::core::Quantity;
+pub use crate::core::Scalar;
 pub use crate::core::UnitOfMeasure;
 
 // Dimensionless
 pub use crate::core::dimensionless::DimensionlessConversions;
 pub use crate::core::{Dimensionless, DimensionlessUnit};
 
+// Runtime-dimensioned quantities
+pub use crate::core::DynQuantity;
+
+// Quantities with uncertainty or guaranteed bounds
+pub use crate::core::{Interval, Measured};
+
+// Totally ordered quantities
+pub use crate::core::OrderedQuantity;
+
+// Unit selection
+pub use crate::core::UnitPolicy;
+
+// Composite units
+pub use crate::core::{CompositeUnit, Rounding};
+
+// Unit matching for parsing
+pub use crate::core::UnitMatching;
+
+// Locale-specific number formats
+pub use crate::core::NumberFormat;
+
+// Logarithmic levels
+pub use crate::core::{Gain, Level, LevelReference};
+
+// Affine points and deltas
+pub use crate::core::affine::{AffinePoint, Frame, Point};
+
 // Error types
-pub use crate::core::error::{QuantityError, QuantityParseError};
+#[cfg(feature = "alloc")]
+pub use crate::core::error::{ParseErrorKind, QuantityError, QuantityParseError};
 
 // Metric and binary prefixes
 pub use crate::systems::binary::*;
@@ -24,16 +53,17 @@ pub use crate::systems::metric::*;
 
 // Time quantities
 pub use crate::time::time::TimeConversions;
-pub use crate::time::{Frequency, FrequencyUnit, Time, TimeUnit};
+pub use crate::time::{Frequency, FrequencyUnit, Time, TimeDerivative, TimeIntegral, TimeUnit};
 
 // Space quantities
 pub use crate::space::angle::AngleConversions;
 pub use crate::space::area::AreaConversions;
+pub use crate::space::fuel_economy::FuelEconomyConversions;
 pub use crate::space::length::LengthConversions;
 pub use crate::space::volume::VolumeConversions;
 pub use crate::space::{
-    Angle, AngleUnit, Area, AreaUnit, Length, LengthUnit, SolidAngle, SolidAngleUnit, Volume,
-    VolumeUnit,
+    Angle, AngleUnit, Area, AreaUnit, FuelEconomy, FuelEconomyUnit, Length, LengthUnit, SolidAngle,
+    SolidAngleUnit, Volume, VolumeUnit,
 };
 
 // Mass quantities
@@ -68,8 +98,12 @@ pub use crate::information::{DataRate, DataRateUnit, Information, InformationUni
 
 // Thermal quantities
 pub use crate::thermal::temperature::TemperatureConversions;
+pub use crate::thermal::temperature_delta::TemperatureDeltaConversions;
 pub use crate::thermal::thermal_capacity::ThermalCapacityConversions;
-pub use crate::thermal::{Temperature, TemperatureScale, ThermalCapacity, ThermalCapacityUnit};
+pub use crate::thermal::{
+    Temperature, TemperatureDelta, TemperatureDeltaUnit, TemperatureScale, ThermalCapacity,
+    ThermalCapacityUnit,
+};
 
 // Motion quantities
 pub use crate::motion::acceleration::AccelerationConversions;
@@ -77,9 +111,10 @@ pub use crate::motion::force::ForceConversions;
 pub use crate::motion::momentum::MomentumConversions;
 pub use crate::motion::pressure::PressureConversions;
 pub use crate::motion::velocity::VelocityConversions;
+pub use crate::motion::volume_flow::VolumeFlowConversions;
 pub use crate::motion::{
     Acceleration, AccelerationUnit, Force, ForceUnit, Momentum, MomentumUnit, Pressure,
-    PressureUnit, Velocity, VelocityUnit,
+    PressureUnit, Velocity, VelocityUnit, VolumeFlow, VolumeFlowUnit,
 };
 
 // Electro quantities
@@ -97,10 +132,9 @@ pub use crate::electro::resistivity::ResistivityConversions;
 pub use crate::electro::{
     Capacitance, CapacitanceUnit, Conductivity, ConductivityUnit, ElectricCharge,
     ElectricChargeUnit, ElectricCurrent, ElectricCurrentUnit, ElectricPotential,
-    ElectricPotentialUnit, ElectricalConductance, ElectricalConductanceUnit,
-    ElectricalResistance, ElectricalResistanceUnit, Inductance, InductanceUnit, MagneticFlux,
-    MagneticFluxDensity, MagneticFluxDensityUnit, MagneticFluxUnit, Resistivity,
-    ResistivityUnit,
+    ElectricPotentialUnit, ElectricalConductance, ElectricalConductanceUnit, ElectricalResistance,
+    ElectricalResistanceUnit, Inductance, InductanceUnit, MagneticFlux, MagneticFluxDensity,
+    MagneticFluxDensityUnit, MagneticFluxUnit, Resistivity, ResistivityUnit,
 };
 
 // Radio quantities
@@ -119,7 +153,9 @@ pub use crate::radio::{
 };
 
 // Market types
+#[cfg(feature = "alloc")]
 pub use crate::market::money::MoneyConversions;
+#[cfg(feature = "alloc")]
 pub use crate::market::{Currency, CurrencyExchangeRate, Money, Price};
 
 // Photo quantities
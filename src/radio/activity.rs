@@ -24,6 +24,7 @@ crate::quantity! {
         name: "Activity";
         primary: Becquerels;
         si: Becquerels;
+        dimensions: [0, 0, -1, 0, 0, 0, 0];
 
         units {
             /// Becquerels (Bq) - SI unit
@@ -1,7 +1,9 @@
 //! Dose quantity and units.
 
+use crate::core::Rational;
+
 // Conversion factor
-const SIEVERT_TO_REM: f64 = 100.0;
+const SIEVERT_TO_REM: Rational = Rational::integer(100);
 crate::quantity! {
     /// A quantity of radiation dose.
     ///
@@ -28,6 +30,7 @@ crate::quantity! {
         name: "Dose";
         primary: Sieverts;
         si: Sieverts;
+        dimensions: [2, 0, -2, 0, 0, 0, 0];
 
         units {
             /// Sieverts (Sv) - SI unit
@@ -41,7 +44,7 @@ crate::quantity! {
             /// Rems (rem)
             Rems {
                 symbol: "rem",
-                factor: 1.0 / SIEVERT_TO_REM,
+                factor: Rational::integer(1).div(SIEVERT_TO_REM),
                 ctor: rems,
                 to: to_rems,
                 si: false
@@ -1,6 +1,4 @@
 //! Irradiance quantity and units.
-use crate::core::Quantity;
-use std::ops::{Div, Mul};
 crate::quantity! {
     /// A quantity of irradiance.
     ///
@@ -26,6 +24,7 @@ crate::quantity! {
         name: "Irradiance";
         primary: WattsPerSquareMeter;
         si: WattsPerSquareMeter;
+        dimensions: [0, 1, -3, 0, 0, 0, 0];
 
         units {
             /// Watts per square meter (W/m²) - SI unit
@@ -39,43 +38,12 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use crate::energy::{Power, PowerUnit};
-use crate::space::{Area, AreaUnit};
-
-// Irradiance * Area = Power
-impl Mul<Area> for Irradiance {
-    type Output = Power;
-
-    fn mul(self, rhs: Area) -> Self::Output {
-        let watts = self.to_watts_per_square_meter() * rhs.to_square_meters();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
-
-// Area * Irradiance = Power
-impl Mul<Irradiance> for Area {
-    type Output = Power;
-
-    fn mul(self, rhs: Irradiance) -> Self::Output {
-        let watts = rhs.to_watts_per_square_meter() * self.to_square_meters();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
-
-// Power / Irradiance = Area
-impl Div<Irradiance> for Power {
-    type Output = Area;
-
-    fn div(self, rhs: Irradiance) -> Self::Output {
-        let m2 = self.to_watts() / rhs.to_watts_per_square_meter();
-        Area::new(m2, AreaUnit::SquareMeters)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::energy::Power;
+    use crate::space::Area;
 
     #[test]
     fn test_irradiance_creation() {
@@ -21,6 +21,7 @@ crate::quantity! {
         name: "ParticleFlux";
         primary: BecquerelsPerSquareMeterSecond;
         si: BecquerelsPerSquareMeterSecond;
+        dimensions: [-2, 0, -2, 0, 0, 0, 0];
 
         units {
             /// Becquerels per square meter per second (Bq/(m²·s)) - SI unit
@@ -1,6 +1,4 @@
 //! Radiance quantity and units.
-use crate::core::Quantity;
-use std::ops::{Div, Mul};
 crate::quantity! {
     /// A quantity of radiance.
     ///
@@ -22,6 +20,8 @@ crate::quantity! {
         name: "Radiance";
         primary: WattsPerSteradianPerSquareMeter;
         si: WattsPerSteradianPerSquareMeter;
+        dimensions: [0, 1, -3, 0, 0, 0, 0];
+        angle: -2;
 
         units {
             /// Watts per steradian per square meter (W/(sr·m²)) - SI unit
@@ -35,43 +35,12 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use super::radiant_intensity::{RadiantIntensity, RadiantIntensityUnit};
-use crate::space::{Area, AreaUnit};
-
-// Radiance * Area = RadiantIntensity
-impl Mul<Area> for Radiance {
-    type Output = RadiantIntensity;
-
-    fn mul(self, rhs: Area) -> Self::Output {
-        let wsr = self.to_watts_per_steradian_per_square_meter() * rhs.to_square_meters();
-        RadiantIntensity::new(wsr, RadiantIntensityUnit::WattsPerSteradian)
-    }
-}
-
-// Area * Radiance = RadiantIntensity
-impl Mul<Radiance> for Area {
-    type Output = RadiantIntensity;
-
-    fn mul(self, rhs: Radiance) -> Self::Output {
-        let wsr = rhs.to_watts_per_steradian_per_square_meter() * self.to_square_meters();
-        RadiantIntensity::new(wsr, RadiantIntensityUnit::WattsPerSteradian)
-    }
-}
-
-// RadiantIntensity / Radiance = Area
-impl Div<Radiance> for RadiantIntensity {
-    type Output = Area;
-
-    fn div(self, rhs: Radiance) -> Self::Output {
-        let m2 = self.to_watts_per_steradian() / rhs.to_watts_per_steradian_per_square_meter();
-        Area::new(m2, AreaUnit::SquareMeters)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::radio::RadiantIntensity;
+    use crate::space::Area;
 
     #[test]
     fn test_radiance_creation() {
@@ -1,6 +1,4 @@
 //! Radiant intensity quantity and units.
-use crate::core::Quantity;
-use std::ops::{Div, Mul};
 crate::quantity! {
     /// A quantity of radiant intensity.
     ///
@@ -26,6 +24,8 @@ crate::quantity! {
         name: "RadiantIntensity";
         primary: WattsPerSteradian;
         si: WattsPerSteradian;
+        dimensions: [2, 1, -3, 0, 0, 0, 0];
+        angle: -2;
 
         units {
             /// Watts per steradian (W/sr) - SI unit
@@ -39,43 +39,12 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use crate::energy::{Power, PowerUnit};
-use crate::space::{SolidAngle, SolidAngleUnit};
-
-// RadiantIntensity * SolidAngle = Power
-impl Mul<SolidAngle> for RadiantIntensity {
-    type Output = Power;
-
-    fn mul(self, rhs: SolidAngle) -> Self::Output {
-        let watts = self.to_watts_per_steradian() * rhs.to_steradians();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
-
-// SolidAngle * RadiantIntensity = Power
-impl Mul<RadiantIntensity> for SolidAngle {
-    type Output = Power;
-
-    fn mul(self, rhs: RadiantIntensity) -> Self::Output {
-        let watts = rhs.to_watts_per_steradian() * self.to_steradians();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
-
-// Power / RadiantIntensity = SolidAngle
-impl Div<RadiantIntensity> for Power {
-    type Output = SolidAngle;
-
-    fn div(self, rhs: RadiantIntensity) -> Self::Output {
-        let sr = self.to_watts() / rhs.to_watts_per_steradian();
-        SolidAngle::new(sr, SolidAngleUnit::Steradians)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::energy::Power;
+    use crate::space::SolidAngle;
 
     #[test]
     fn test_radiant_intensity_creation() {
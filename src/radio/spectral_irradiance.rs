@@ -20,6 +20,7 @@ crate::quantity! {
         name: "SpectralIrradiance";
         primary: WattsPerCubicMeter;
         si: WattsPerCubicMeter;
+        dimensions: [-1, 1, -3, 0, 0, 0, 0];
 
         units {
             /// Watts per cubic meter (W/m³) - SI unit
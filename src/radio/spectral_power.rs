@@ -1,6 +1,4 @@
 //! Spectral power quantity and units.
-use crate::core::Quantity;
-use std::ops::{Div, Mul};
 crate::quantity! {
     /// A quantity of spectral power.
     ///
@@ -26,6 +24,7 @@ crate::quantity! {
         name: "SpectralPower";
         primary: WattsPerMeter;
         si: WattsPerMeter;
+        dimensions: [1, 1, -3, 0, 0, 0, 0];
 
         units {
             /// Watts per meter (W/m) - SI unit
@@ -39,44 +38,12 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity operations
-use crate::energy::{Power, PowerUnit};
-use crate::space::{Length, LengthUnit};
-
-// SpectralPower * Length = Power
-impl Mul<Length> for SpectralPower {
-    type Output = Power;
-
-    fn mul(self, rhs: Length) -> Self::Output {
-        let watts = self.to_watts_per_meter() * rhs.to_meters();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
-
-// Length * SpectralPower = Power
-impl Mul<SpectralPower> for Length {
-    type Output = Power;
-
-    fn mul(self, rhs: SpectralPower) -> Self::Output {
-        let watts = rhs.to_watts_per_meter() * self.to_meters();
-        Power::new(watts, PowerUnit::Watts)
-    }
-}
-
-// SpectralPower / Power = 1/Length
-// Power / SpectralPower = Length
-impl Div<SpectralPower> for Power {
-    type Output = Length;
-
-    fn div(self, rhs: SpectralPower) -> Self::Output {
-        let meters = self.to_watts() / rhs.to_watts_per_meter();
-        Length::new(meters, LengthUnit::Meters)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::energy::Power;
+    use crate::space::Length;
 
     #[test]
     fn test_spectral_power_creation() {
@@ -0,0 +1,243 @@
+//! Cross-quantity relations.
+//!
+//! Every operator between two different quantity types is declared here,
+//! in one table, so that each relation is generated in all of its
+//! directions and checked against the base-dimension signatures of the
+//! quantities involved. See [`relation!`](crate::relation) for the syntax.
+
+use crate::electro::{
+    Capacitance, Conductivity, ElectricCharge, ElectricCurrent, ElectricPotential,
+    ElectricalConductance, ElectricalResistance, Inductance, MagneticFlux, MagneticFluxDensity,
+    Resistivity,
+};
+use crate::energy::{
+    Energy, EnergyDensity, MolarEnergy, Power, PowerDensity, PowerRamp, SpecificEnergy,
+};
+use crate::information::{DataRate, Information};
+use crate::mass::{AreaDensity, ChemicalAmount, Density, Mass, MomentOfInertia};
+use crate::motion::{Acceleration, Force, Momentum, Pressure, Velocity, VolumeFlow};
+use crate::photo::{
+    Illuminance, Luminance, LuminousEnergy, LuminousExposure, LuminousFlux, LuminousIntensity,
+};
+use crate::radio::{Irradiance, Radiance, RadiantIntensity, SpectralPower};
+use crate::space::{Area, FuelEconomy, Length, SolidAngle, Volume};
+use crate::thermal::{TemperatureDelta, ThermalCapacity};
+use crate::time::Time;
+
+/// The base dimensions produced by a relation and the ones its output
+/// declares, and the result of its operator for sample inputs next to the
+/// same computation through `DynQuantity`.
+#[cfg(test)]
+struct RelationCheck {
+    relation: &'static str,
+    actual: crate::core::BaseDimensions,
+    expected: crate::core::BaseDimensions,
+    typed: crate::core::DynQuantity,
+    dynamic: crate::core::DynQuantity,
+}
+
+/// Expands the relation table into operators and, for tests, a list of
+/// dimension checks.
+macro_rules! relations {
+    ($($entry:tt)*) => {
+        crate::relation! { $($entry)* }
+
+        #[cfg(test)]
+        #[allow(clippy::vec_init_then_push)]
+        fn relation_checks() -> Vec<RelationCheck> {
+            let mut checks = Vec::new();
+            relation_checks!(checks; $($entry)*);
+            checks
+        }
+    };
+}
+
+#[cfg(test)]
+macro_rules! relation_checks {
+    ($checks:ident;) => {};
+    ($checks:ident; product $lhs:ident * $rhs:ident => $output:ident; $($rest:tt)*) => {
+        relation_checks!($checks; $lhs * $rhs => $output; $($rest)*);
+    };
+    ($checks:ident; quotient $lhs:ident / $rhs:ident => $output:ident; $($rest:tt)*) => {
+        $checks.push(RelationCheck {
+            relation: stringify!($lhs / $rhs => $output),
+            actual: $lhs::base_dimensions() / $rhs::base_dimensions(),
+            expected: $output::base_dimensions(),
+            typed: (<$lhs>::__from_coherent(6.0) / <$rhs>::__from_coherent(4.0)).into(),
+            dynamic: crate::core::DynQuantity::from(<$lhs>::__from_coherent(6.0))
+                / crate::core::DynQuantity::from(<$rhs>::__from_coherent(4.0)),
+        });
+        relation_checks!($checks; $($rest)*);
+    };
+    ($checks:ident; $base:ident ^ 2 => $output:ident; $($rest:tt)*) => {
+        $checks.push(RelationCheck {
+            relation: stringify!($base ^ 2 => $output),
+            actual: $base::base_dimensions().powi(2),
+            expected: $output::base_dimensions(),
+            typed: (<$base>::__from_coherent(3.0) * <$base>::__from_coherent(3.0)).into(),
+            dynamic: crate::core::DynQuantity::from(<$base>::__from_coherent(3.0)).powi(2),
+        });
+        relation_checks!($checks; $($rest)*);
+    };
+    ($checks:ident; $lhs:ident * $rhs:ident => $output:ident; $($rest:tt)*) => {
+        $checks.push(RelationCheck {
+            relation: stringify!($lhs * $rhs => $output),
+            actual: $lhs::base_dimensions() * $rhs::base_dimensions(),
+            expected: $output::base_dimensions(),
+            typed: (<$lhs>::__from_coherent(6.0) * <$rhs>::__from_coherent(4.0)).into(),
+            dynamic: crate::core::DynQuantity::from(<$lhs>::__from_coherent(6.0))
+                * crate::core::DynQuantity::from(<$rhs>::__from_coherent(4.0)),
+        });
+        relation_checks!($checks; $($rest)*);
+    };
+}
+
+relations! {
+    // Space
+    Length ^ 2 => Area;
+    Area * Length => Volume;
+    FuelEconomy * Volume => Length;
+
+    // Mass
+    Density * Volume => Mass;
+    AreaDensity * Area => Mass;
+    Mass * Area => MomentOfInertia;
+
+    // Motion
+    Velocity * Time => Length;
+    Acceleration * Time => Velocity;
+    Mass * Acceleration => Force;
+    Mass * Velocity => Momentum;
+    Force * Time => Momentum;
+    Pressure * Area => Force;
+    VolumeFlow * Time => Volume;
+
+    // Energy
+    Power * Time => Energy;
+    PowerRamp * Time => Power;
+    Force * Length => Energy;
+    Force * Velocity => Power;
+    product Pressure * Volume => Energy;
+    quotient Energy / Pressure => Volume;
+    Mass * SpecificEnergy => Energy;
+    EnergyDensity * Volume => Energy;
+    MolarEnergy * ChemicalAmount => Energy;
+    PowerDensity * Volume => Power;
+    ThermalCapacity * TemperatureDelta => Energy;
+
+    // Electro
+    ElectricCurrent * Time => ElectricCharge;
+    ElectricCurrent * ElectricalResistance => ElectricPotential;
+    ElectricalConductance * ElectricPotential => ElectricCurrent;
+    ElectricCurrent * ElectricPotential => Power;
+    ElectricCharge * ElectricPotential => Energy;
+    Capacitance * ElectricPotential => ElectricCharge;
+    ElectricalResistance * Capacitance => Time;
+    ElectricPotential * Time => MagneticFlux;
+    Inductance * ElectricCurrent => MagneticFlux;
+    MagneticFluxDensity * Area => MagneticFlux;
+    ElectricalResistance * Length => Resistivity;
+    Conductivity * Length => ElectricalConductance;
+
+    // Information
+    DataRate * Time => Information;
+
+    // Photo
+    LuminousIntensity * SolidAngle => LuminousFlux;
+    Luminance * Area => LuminousIntensity;
+    Illuminance * Area => LuminousFlux;
+    Illuminance * Time => LuminousExposure;
+    LuminousFlux * Time => LuminousEnergy;
+
+    // Radio
+    Irradiance * Area => Power;
+    Radiance * Area => RadiantIntensity;
+    RadiantIntensity * SolidAngle => Power;
+    SpectralPower * Length => Power;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Quantity;
+    use crate::energy::{EnergyUnit, PowerRampUnit};
+
+    #[test]
+    fn test_relations_match_base_dimensions() {
+        let checks = relation_checks();
+        assert!(!checks.is_empty());
+        let wrong: Vec<String> = checks
+            .iter()
+            .filter(|check| {
+                check.actual != check.expected
+                    || check.typed.dims() != check.dynamic.dims()
+                    || (check.typed.value() - check.dynamic.value()).abs()
+                        > 1e-12 * check.dynamic.value().abs()
+            })
+            .map(|check| {
+                format!(
+                    "{}: got {} ({}), expected {} ({})",
+                    check.relation, check.actual, check.typed, check.expected, check.dynamic
+                )
+            })
+            .collect();
+        assert!(
+            wrong.is_empty(),
+            "dimensionally wrong relations: {:#?}",
+            wrong
+        );
+    }
+
+    #[test]
+    fn test_output_in_si_unit() {
+        let energy = Power::watts(100.0) * Time::seconds(10.0);
+        assert_eq!(energy.unit(), EnergyUnit::Joules);
+        assert_eq!(energy.to_string(), "1000 J");
+
+        let energy = Power::kilowatts(1.0) * Time::hours(2.0);
+        assert_eq!(energy.to_joules(), 7.2e6);
+
+        let ramp = Power::watts(7200.0) / Time::hours(1.0);
+        assert_eq!(ramp.unit(), PowerRampUnit::WattsPerHour);
+        assert!((ramp.to_watts_per_hour() - 7200.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_all_directions_generated() {
+        let force = Mass::kilograms(2.0) * Acceleration::meters_per_second_squared(3.0);
+        assert!((force.to_newtons() - 6.0).abs() < 1e-12);
+        let force = Acceleration::meters_per_second_squared(3.0) * Mass::kilograms(2.0);
+        assert!((force.to_newtons() - 6.0).abs() < 1e-12);
+        let mass = force / Acceleration::meters_per_second_squared(3.0);
+        assert!((mass.to_kilograms() - 2.0).abs() < 1e-12);
+        let acceleration = force / Mass::kilograms(2.0);
+        assert!((acceleration.to_meters_per_second_squared() - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rc_time_constant() {
+        let tau = ElectricalResistance::kilohms(10.0) * Capacitance::microfarads(100.0);
+        assert!((tau.to_seconds() - 1.0).abs() < 1e-12);
+        let capacitance = Time::seconds(1.0) / ElectricalResistance::kilohms(10.0);
+        assert!((capacitance.to_microfarads() - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pressure_volume_work() {
+        let work = Pressure::kilopascals(100.0) * Volume::cubic_meters(0.5);
+        assert!((work.to_kilojoules() - 50.0).abs() < 1e-9);
+        let volume = Energy::kilojoules(50.0) / Pressure::kilopascals(100.0);
+        assert!((volume.to_cubic_meters() - 0.5).abs() < 1e-12);
+        // Energy / Volume keeps its meaning as an energy density
+        let density: EnergyDensity = Energy::joules(10.0) / Volume::cubic_meters(2.0);
+        assert!((density.to_joules_per_cubic_meter() - 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_mechanical_power_and_impulse() {
+        let power = Force::newtons(10.0) * Velocity::meters_per_second(3.0);
+        assert!((power.to_watts() - 30.0).abs() < 1e-12);
+        let impulse = Force::newtons(4.0) * Time::seconds(2.5);
+        assert!((impulse.to_newton_seconds() - 10.0).abs() < 1e-12);
+    }
+}
@@ -1,6 +1,9 @@
 //! Angle quantity and units.
 
-use std::f64::consts::PI;
+use crate::core::CompositeUnit;
+use crate::math;
+use core::f64::consts::PI;
+
 crate::quantity! {
     /// A quantity of angle.
     ///
@@ -25,6 +28,8 @@ crate::quantity! {
         name: "Angle";
         primary: Radians;
         si: Radians;
+        dimensions: [0, 0, 0, 0, 0, 0, 0];
+        angle: 1;
 
         units {
             /// Radians (rad) - SI unit
@@ -38,6 +43,7 @@ crate::quantity! {
             /// Degrees (°)
             Degrees {
                 symbol: "°",
+                aliases: ["deg"],
                 factor: PI / 180.0,
                 ctor: degrees,
                 to: to_degrees,
@@ -59,17 +65,19 @@ crate::quantity! {
                 to: to_turns,
                 si: false
             },
-            /// Arc minutes (')
+            /// Arc minutes (′)
             ArcMinutes {
-                symbol: "'",
+                symbol: "′",
+                aliases: ["'", "arcmin"],
                 factor: PI / (180.0 * 60.0),
                 ctor: arc_minutes,
                 to: to_arc_minutes,
                 si: false
             },
-            /// Arc seconds ('')
+            /// Arc seconds (″)
             ArcSeconds {
-                symbol: "''",
+                symbol: "″",
+                aliases: ["''", "\"", "arcsec"],
                 factor: PI / (180.0 * 3600.0),
                 ctor: arc_seconds,
                 to: to_arc_seconds,
@@ -78,41 +86,53 @@ crate::quantity! {
         }
     }
 }
+
+impl CompositeUnit<AngleUnit> {
+    /// Degrees, minutes and seconds of arc, as in `12° 30′ 15″`, keeping
+    /// components that are zero.
+    pub const DMS: Self = CompositeUnit::new(&[
+        AngleUnit::Degrees,
+        AngleUnit::ArcMinutes,
+        AngleUnit::ArcSeconds,
+    ])
+    .keep_zeros();
+}
+
 impl Angle {
     // Trigonometric functions
     /// Returns the sine of this angle.
     pub fn sin(&self) -> f64 {
-        self.to_radians().sin()
+        math::sin(self.to_radians())
     }
 
     /// Returns the cosine of this angle.
     pub fn cos(&self) -> f64 {
-        self.to_radians().cos()
+        math::cos(self.to_radians())
     }
 
     /// Returns the tangent of this angle.
     pub fn tan(&self) -> f64 {
-        self.to_radians().tan()
+        math::tan(self.to_radians())
     }
 
     /// Creates an angle from its sine value.
     pub fn asin(value: f64) -> Self {
-        Self::radians(value.asin())
+        Self::radians(math::asin(value))
     }
 
     /// Creates an angle from its cosine value.
     pub fn acos(value: f64) -> Self {
-        Self::radians(value.acos())
+        Self::radians(math::acos(value))
     }
 
     /// Creates an angle from its tangent value.
     pub fn atan(value: f64) -> Self {
-        Self::radians(value.atan())
+        Self::radians(math::atan(value))
     }
 
     /// Creates an angle from atan2(y, x).
     pub fn atan2(y: f64, x: f64) -> Self {
-        Self::radians(y.atan2(x))
+        Self::radians(math::atan2(y, x))
     }
 }
 #[cfg(test)]
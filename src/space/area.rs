@@ -1,17 +1,31 @@
 //! Area quantity and units.
 
-use super::length::Length;
-use super::volume::{Volume, VolumeUnit};
-use crate::core::Quantity;
-use crate::systems::metric::{CENTI, HECTO, KILO, MILLI};
-use std::ops::Mul;
+use crate::core::Rational;
+use crate::math;
+use core::f64::consts::PI;
 
 /// Conversion factors
-const SQ_FOOT_TO_SQ_METER: f64 = 0.09290304;
-const SQ_YARD_TO_SQ_METER: f64 = 0.83612736;
-const SQ_MILE_TO_SQ_METER: f64 = 2_589_988.110336;
-const ACRE_TO_SQ_METER: f64 = 4046.8564224;
-const SQ_INCH_TO_SQ_METER: f64 = 0.00064516;
+const SQ_FOOT_TO_SQ_METER: Rational = Rational::new(9_290_304, 100_000_000);
+const SQ_YARD_TO_SQ_METER: Rational = Rational::new(83_612_736, 100_000_000);
+const SQ_MILE_TO_SQ_METER: Rational = Rational::new(2_589_988_110_336, 1_000_000);
+const ACRE_TO_SQ_METER: Rational = Rational::new(40_468_564_224, 10_000_000);
+const SQ_INCH_TO_SQ_METER: Rational = Rational::new(64_516, 100_000_000);
+
+/// Diameter of 36 AWG wire in meters. The diameter grows by a factor of 92
+/// every 39 gauges down to 0000 AWG (gauge -3).
+const AWG_36_DIAMETER: f64 = 0.000127;
+
+/// Cross-sectional area in m² of a round wire of the given gauge.
+fn awg_to_square_meters(gauge: f64) -> f64 {
+    let diameter = AWG_36_DIAMETER * math::powf(92.0, (36.0 - gauge) / 39.0);
+    PI / 4.0 * diameter * diameter
+}
+
+/// Gauge of a round wire with the given cross-sectional area in m².
+fn square_meters_to_awg(area: f64) -> f64 {
+    let diameter = math::sqrt(4.0 * area / PI);
+    36.0 - 39.0 * math::ln(diameter / AWG_36_DIAMETER) / math::ln(92.0)
+}
 crate::quantity! {
     /// A quantity of area.
     ///
@@ -35,12 +49,14 @@ crate::quantity! {
         name: "Area";
         primary: SquareMeters;
         si: SquareMeters;
+        dimensions: [2, 0, 0, 0, 0, 0, 0];
+        families: [METRIC, IMPERIAL];
 
         units {
             /// Square millimeters (mm²)
             SquareMillimeters {
                 symbol: "mm²",
-                factor: MILLI * MILLI,
+                factor: Rational::pow10(-6),
                 ctor: square_millimeters,
                 to: to_square_millimeters,
                 si: true
@@ -48,7 +64,7 @@ crate::quantity! {
             /// Square centimeters (cm²)
             SquareCentimeters {
                 symbol: "cm²",
-                factor: CENTI * CENTI,
+                factor: Rational::pow10(-4),
                 ctor: square_centimeters,
                 to: to_square_centimeters,
                 si: true
@@ -56,6 +72,7 @@ crate::quantity! {
             /// Square meters (m²) - SI derived unit
             SquareMeters {
                 symbol: "m²",
+                aliases: ["square metre", "square metres"],
                 factor: 1.0,
                 ctor: square_meters,
                 to: to_square_meters,
@@ -64,7 +81,8 @@ crate::quantity! {
             /// Square kilometers (km²)
             SquareKilometers {
                 symbol: "km²",
-                factor: KILO * KILO,
+                aliases: ["square kilometre", "square kilometres"],
+                factor: Rational::pow10(6),
                 ctor: square_kilometers,
                 to: to_square_kilometers,
                 si: true
@@ -72,7 +90,7 @@ crate::quantity! {
             /// Hectares (ha) - 10,000 m²
             Hectares {
                 symbol: "ha",
-                factor: HECTO * HECTO,
+                factor: Rational::pow10(4),
                 ctor: hectares,
                 to: to_hectares,
                 si: true
@@ -116,25 +134,45 @@ crate::quantity! {
                 ctor: acres,
                 to: to_acres,
                 si: false
+            },
+            /// American Wire Gauge (AWG), the cross-section of a round wire.
+            /// Larger gauges are thinner wires; 0 AWG, 00 AWG, 000 AWG and
+            /// 0000 AWG are gauges 0, -1, -2 and -3.
+            AmericanWireGauge {
+                symbol: "AWG",
+                name: ("gauge", "gauge"),
+                factor: 1.0,
+                convert: (awg_to_square_meters, square_meters_to_awg),
+                ctor: american_wire_gauge,
+                to: to_american_wire_gauge,
+                si: false
             }
         }
     }
 }
 
-// Area * Length = Volume
-impl Mul<Length> for Area {
-    type Output = Volume;
+impl AreaUnit {
+    /// The metric units of area commonly used for rescaling: mm², cm², m² and km².
+    pub const METRIC: &'static [AreaUnit] = &[
+        AreaUnit::SquareMillimeters,
+        AreaUnit::SquareCentimeters,
+        AreaUnit::SquareMeters,
+        AreaUnit::SquareKilometers,
+    ];
 
-    fn mul(self, rhs: Length) -> Self::Output {
-        let volume_meters_cu = self.to_square_meters() * rhs.to_meters();
-        Volume::new(volume_meters_cu, VolumeUnit::CubicMeters)
-    }
+    /// The imperial units of area commonly used for rescaling: in², ft², ac and mi².
+    pub const IMPERIAL: &'static [AreaUnit] = &[
+        AreaUnit::SquareInches,
+        AreaUnit::SquareFeet,
+        AreaUnit::Acres,
+        AreaUnit::SquareMiles,
+    ];
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::Quantity;
+    use crate::core::{Dimension, Quantity};
 
     #[test]
     fn test_area_creation() {
@@ -166,4 +204,21 @@ mod tests {
         // 1 acre ≈ 43,560 square feet
         assert!((a.to_square_feet() - 43560.0).abs() < 1.0);
     }
+
+    #[test]
+    fn test_american_wire_gauge() {
+        // 12 AWG is 3.309 mm²
+        let a = Area::american_wire_gauge(12.0);
+        assert!((a.to_square_millimeters() - 3.309).abs() < 1e-3);
+        assert!((Area::american_wire_gauge(-3.0).to_square_millimeters() - 107.2).abs() < 0.1);
+        assert!((a.to_american_wire_gauge() - 12.0).abs() < 1e-9);
+
+        // Two 12 AWG wires carry the copper of a 9 AWG wire
+        let doubled = a + a;
+        assert_eq!(doubled.unit(), AreaUnit::SquareMeters);
+        assert!((doubled.to_american_wire_gauge() - 9.0).abs() < 0.02);
+
+        let parsed = AreaDimension::parse("14 AWG").unwrap();
+        assert!((parsed.to_square_millimeters() - 2.081).abs() < 1e-3);
+    }
 }
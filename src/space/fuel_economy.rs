@@ -0,0 +1,145 @@
+//! Fuel economy quantity and units.
+
+use crate::core::Rational;
+
+/// Conversion factors to km/L
+const KILOMETERS_PER_MILE: Rational = Rational::new(1_609_344, 1_000_000);
+const LITERS_PER_US_GALLON: Rational = Rational::new(3_785_411_784, 1_000_000_000);
+const LITERS_PER_IMPERIAL_GALLON: Rational = Rational::new(454_609, 100_000);
+
+/// Converts fuel consumption in L/100 km to km/L and back; the conversion
+/// is its own inverse.
+fn liters_per_100_kilometers(value: f64) -> f64 {
+    100.0 / value
+}
+
+crate::quantity! {
+    /// A quantity of fuel economy (distance travelled per volume of fuel).
+    ///
+    /// Fuel consumption in liters per 100 kilometers is the reciprocal of
+    /// distance per volume, so it is a non-linear unit: a lower value means
+    /// a more economical vehicle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    ///
+    /// let economy = FuelEconomy::miles_per_gallon(30.0);
+    /// assert!((economy.to_liters_per_100_kilometers() - 7.84).abs() < 0.01);
+    ///
+    /// let fuel = Length::kilometers(500.0) / economy;
+    /// assert!((fuel.to_liters() - 39.2).abs() < 0.01);
+    /// ```
+    pub quantity FuelEconomy {
+        unit: FuelEconomyUnit;
+        dimension: FuelEconomyDimension;
+        conversions: FuelEconomyConversions;
+        name: "FuelEconomy";
+        primary: KilometersPerLiter;
+        si: MetersPerCubicMeter;
+        dimensions: [-2, 0, 0, 0, 0, 0, 0];
+
+        units {
+            /// Meters per cubic meter (m/m³)
+            MetersPerCubicMeter {
+                symbol: "m/m³",
+                factor: Rational::pow10(-6),
+                ctor: meters_per_cubic_meter,
+                to: to_meters_per_cubic_meter,
+                si: true
+            },
+            /// Kilometers per liter (km/L)
+            KilometersPerLiter {
+                symbol: "km/L",
+                factor: 1.0,
+                ctor: kilometers_per_liter,
+                to: to_kilometers_per_liter,
+                si: false
+            },
+            /// Miles per US gallon (mpg)
+            MilesPerGallon {
+                symbol: "mpg",
+                factor: KILOMETERS_PER_MILE.div(LITERS_PER_US_GALLON),
+                ctor: miles_per_gallon,
+                to: to_miles_per_gallon,
+                si: false
+            },
+            /// Miles per imperial gallon (mpg (imp))
+            MilesPerImperialGallon {
+                symbol: "mpg (imp)",
+                name: "miles per imperial gallon",
+                factor: KILOMETERS_PER_MILE.div(LITERS_PER_IMPERIAL_GALLON),
+                ctor: miles_per_imperial_gallon,
+                to: to_miles_per_imperial_gallon,
+                si: false
+            },
+            /// Liters per 100 kilometers (L/100km), the reciprocal of km/L
+            LitersPer100Kilometers {
+                symbol: "L/100km",
+                factor: 1.0,
+                convert: (liters_per_100_kilometers, liters_per_100_kilometers),
+                ctor: liters_per_100_kilometers,
+                to: to_liters_per_100_kilometers,
+                si: false
+            }
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Dimension, Quantity};
+    use crate::space::{Length, Volume};
+
+    #[test]
+    fn test_fuel_economy_creation() {
+        let e = FuelEconomy::kilometers_per_liter(15.0);
+        assert_eq!(e.value(), 15.0);
+        assert_eq!(e.unit(), FuelEconomyUnit::KilometersPerLiter);
+        assert_eq!(e.to_meters_per_cubic_meter(), 15e6);
+    }
+
+    #[test]
+    fn test_reciprocal_conversion() {
+        let e = FuelEconomy::liters_per_100_kilometers(5.0);
+        assert_eq!(e.to_kilometers_per_liter(), 20.0);
+        assert!((e.to_miles_per_gallon() - 47.04).abs() < 0.01);
+        assert!(
+            (FuelEconomy::miles_per_imperial_gallon(50.0).to_liters_per_100_kilometers() - 5.65)
+                .abs()
+                < 0.01
+        );
+    }
+
+    #[test]
+    fn test_relations() {
+        let e = Length::kilometers(600.0) / Volume::liters(40.0);
+        assert!((e.to_liters_per_100_kilometers() - 6.666_666_666_7).abs() < 1e-9);
+
+        let range = FuelEconomy::liters_per_100_kilometers(8.0) * Volume::liters(50.0);
+        assert!((range.to_kilometers() - 625.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_arithmetic_uses_distance_per_volume() {
+        // Averaging 5 and 10 L/100km as distance per volume gives 15 km/L
+        let mean = (FuelEconomy::liters_per_100_kilometers(5.0)
+            + FuelEconomy::liters_per_100_kilometers(10.0))
+            / 2.0;
+        assert_eq!(mean.unit(), FuelEconomyUnit::KilometersPerLiter);
+        assert!((mean.value() - 15.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse() {
+        let e = FuelEconomyDimension::parse("6.5 L/100km").unwrap();
+        assert_eq!(e.unit(), FuelEconomyUnit::LitersPer100Kilometers);
+        assert_eq!(e.value(), 6.5);
+
+        let e: FuelEconomy = "30 mpg".parse().unwrap();
+        assert_eq!(e.unit(), FuelEconomyUnit::MilesPerGallon);
+        let e: FuelEconomy = "40 mpg (imp)".parse().unwrap();
+        assert_eq!(e.unit(), FuelEconomyUnit::MilesPerImperialGallon);
+    }
+}
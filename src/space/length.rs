@@ -1,18 +1,21 @@
 //! Length quantity and units.
 
-use super::area::{Area, AreaUnit};
-use super::volume::{Volume, VolumeUnit};
-use crate::core::Quantity;
-use crate::systems::metric::{CENTI, DECI, HECTO, KILO, MICRO, MILLI, NANO, PICO};
-use std::ops::Mul;
+use super::area::Area;
+use super::volume::Volume;
+use crate::core::affine::Point;
+use crate::core::{CompositeUnit, Rational};
 
-/// Conversion factors for imperial units.
-const FEET_TO_METERS: f64 = 0.3048;
-const YARDS_TO_METERS: f64 = 0.9144;
-const MILES_TO_METERS: f64 = 1609.344;
+/// Conversion factors for imperial units, exact since the 1959 international
+/// yard and pound agreement.
+const INCHES_TO_METERS: Rational = Rational::new(254, 10_000);
+const FEET_TO_METERS: Rational = INCHES_TO_METERS.mul(Rational::integer(12));
+const YARDS_TO_METERS: Rational = FEET_TO_METERS.mul(Rational::integer(3));
+const MILES_TO_METERS: Rational = FEET_TO_METERS.mul(Rational::integer(5280));
 const NAUTICAL_MILES_TO_METERS: f64 = 1852.0;
-const AU_TO_METERS: f64 = 1.495978707e11;
-const LIGHT_YEAR_TO_METERS: f64 = 9.4607304725808e15;
+/// Astronomical units, exact by IAU 2012 Resolution B2.
+const AU_TO_METERS: f64 = 149_597_870_700.0;
+/// Julian light-years of 365.25 days at exactly 299 792 458 m/s.
+const LIGHT_YEAR_TO_METERS: Rational = Rational::integer(9_460_730_472_580_800);
 const PARSEC_TO_METERS: f64 = 3.08567758149137e16;
 crate::quantity! {
     /// A quantity of length.
@@ -37,12 +40,14 @@ crate::quantity! {
         name: "Length";
         primary: Meters;
         si: Meters;
+        dimensions: [1, 0, 0, 0, 0, 0, 0];
+        families: [METRIC, IMPERIAL];
 
         units {
             /// Angstroms (Å) - 10^-10 meters
             Angstroms {
                 symbol: "Å",
-                factor: 100.0 * PICO,
+                factor: Rational::pow10(-10),
                 ctor: angstroms,
                 to: to_angstroms,
                 si: false
@@ -50,7 +55,8 @@ crate::quantity! {
             /// Nanometers (nm) - 10^-9 meters
             Nanometers {
                 symbol: "nm",
-                factor: NANO,
+                aliases: ["nanometre", "nanometres"],
+                factor: Rational::pow10(-9),
                 ctor: nanometers,
                 to: to_nanometers,
                 si: true
@@ -58,7 +64,8 @@ crate::quantity! {
             /// Micrometers/Microns (µm) - 10^-6 meters
             Micrometers {
                 symbol: "µm",
-                factor: MICRO,
+                aliases: ["micrometre", "micrometres", "micron", "microns"],
+                factor: Rational::pow10(-6),
                 ctor: micrometers,
                 to: to_micrometers,
                 si: true
@@ -66,7 +73,8 @@ crate::quantity! {
             /// Millimeters (mm) - 10^-3 meters
             Millimeters {
                 symbol: "mm",
-                factor: MILLI,
+                aliases: ["millimetre", "millimetres"],
+                factor: Rational::pow10(-3),
                 ctor: millimeters,
                 to: to_millimeters,
                 si: true
@@ -74,7 +82,8 @@ crate::quantity! {
             /// Centimeters (cm) - 10^-2 meters
             Centimeters {
                 symbol: "cm",
-                factor: CENTI,
+                aliases: ["centimetre", "centimetres"],
+                factor: Rational::pow10(-2),
                 ctor: centimeters,
                 to: to_centimeters,
                 si: true
@@ -82,7 +91,8 @@ crate::quantity! {
             /// Decimeters (dm) - 10^-1 meters
             Decimeters {
                 symbol: "dm",
-                factor: DECI,
+                aliases: ["decimetre", "decimetres"],
+                factor: Rational::pow10(-1),
                 ctor: decimeters,
                 to: to_decimeters,
                 si: true
@@ -90,6 +100,7 @@ crate::quantity! {
             /// Meters (m) - SI base unit
             Meters {
                 symbol: "m",
+                aliases: ["metre", "metres"],
                 factor: 1.0,
                 ctor: meters,
                 to: to_meters,
@@ -98,7 +109,8 @@ crate::quantity! {
             /// Hectometers (hm) - 10^2 meters
             Hectometers {
                 symbol: "hm",
-                factor: HECTO,
+                aliases: ["hectometre", "hectometres"],
+                factor: Rational::pow10(2),
                 ctor: hectometers,
                 to: to_hectometers,
                 si: true
@@ -106,7 +118,8 @@ crate::quantity! {
             /// Kilometers (km) - 10^3 meters
             Kilometers {
                 symbol: "km",
-                factor: KILO,
+                aliases: ["kilometre", "kilometres"],
+                factor: Rational::pow10(3),
                 ctor: kilometers,
                 to: to_kilometers,
                 si: true
@@ -114,7 +127,7 @@ crate::quantity! {
             /// Inches (in) - 0.0254 meters
             Inches {
                 symbol: "in",
-                factor: FEET_TO_METERS / 12.0,
+                factor: INCHES_TO_METERS,
                 ctor: inches,
                 to: to_inches,
                 si: false
@@ -178,6 +191,28 @@ crate::quantity! {
         }
     }
 }
+
+impl LengthUnit {
+    /// The metric units of length commonly used for rescaling: nm, µm, mm, cm, m and km.
+    pub const METRIC: &'static [LengthUnit] = &[
+        LengthUnit::Nanometers,
+        LengthUnit::Micrometers,
+        LengthUnit::Millimeters,
+        LengthUnit::Centimeters,
+        LengthUnit::Meters,
+        LengthUnit::Kilometers,
+    ];
+
+    /// The imperial units of length commonly used for rescaling: in, ft and mi.
+    pub const IMPERIAL: &'static [LengthUnit] =
+        &[LengthUnit::Inches, LengthUnit::Feet, LengthUnit::Miles];
+}
+
+impl CompositeUnit<LengthUnit> {
+    /// Feet and inches, as in `5 ft 11 in`.
+    pub const FEET_INCHES: Self = CompositeUnit::new(&[LengthUnit::Feet, LengthUnit::Inches]);
+}
+
 impl Length {
     /// Returns the squared length (this * this).
     pub fn squared(self) -> Area {
@@ -189,25 +224,11 @@ impl Length {
         self.squared() * self
     }
 }
-// Length * Length = Area
-impl Mul<Length> for Length {
-    type Output = Area;
 
-    fn mul(self, rhs: Length) -> Self::Output {
-        let area_meters_sq = self.to_meters() * rhs.to_meters();
-        Area::new(area_meters_sq, AreaUnit::SquareMeters)
-    }
-}
-
-// Length * Area = Volume
-impl Mul<Area> for Length {
-    type Output = Volume;
-
-    fn mul(self, rhs: Area) -> Self::Output {
-        let volume_meters_cu = self.to_meters() * rhs.to_square_meters();
-        Volume::new(volume_meters_cu, VolumeUnit::CubicMeters)
-    }
-}
+/// A position along an axis, measured as a [`Length`] from an origin.
+///
+/// Subtracting two positions gives the displacement between them.
+pub type Position = Point<Length>;
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,4 +306,58 @@ mod tests {
         // 1 AU is approximately 149.6 million km
         assert!((au.to_kilometers() / 1e8 - 1.496).abs() < 0.001);
     }
+
+    #[test]
+    fn test_exact_factors() {
+        assert!(LengthUnit::Inches.is_exact());
+        assert!(LengthUnit::Miles.is_exact());
+        assert!(LengthUnit::LightYears.is_exact());
+        assert!(!LengthUnit::Parsecs.is_exact());
+        assert_eq!(
+            LengthUnit::Inches.exact_factor(),
+            Some(Rational::new(127, 5000))
+        );
+
+        // Converting whole inches to centimeters multiplies by 254/100 exactly
+        // and rounds once, matching `v * 254.0 / 100.0`.
+        for i in 1..10_000 {
+            let v = i as f64;
+            assert_eq!(Length::inches(v).to_centimeters(), v * 254.0 / 100.0);
+        }
+        assert_eq!(Length::inches(12.0), Length::feet(1.0));
+        assert_eq!(Length::yards(1760.0).to_miles(), 1.0);
+    }
+
+    #[test]
+    fn test_hash_and_storage() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash(length: Length) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            length.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let km = Length::kilometers(1.0);
+        assert_eq!(km.to_primary(), 1000.0);
+        assert_eq!(hash(km), hash(Length::meters(1000.0)));
+        assert_eq!(hash(Length::meters(0.0)), hash(Length::meters(-0.0)));
+
+        // The value is stored in meters and read back in the unit it was
+        // created in
+        let l = Length::inches(0.1);
+        assert_eq!(l.value(), 0.1);
+        assert_eq!(l.unit(), LengthUnit::Inches);
+        assert_eq!(std::mem::size_of::<Length>(), 16);
+
+        let sum = Length::feet(1.0) + Length::inches(6.0);
+        assert_eq!(sum.unit(), LengthUnit::Feet);
+        assert_eq!(sum.to_primary(), 0.3048 + 0.1524);
+        assert!((sum.value() - 1.5).abs() < 1e-12);
+
+        let from_primary = Length::from_primary(1609.344, LengthUnit::Miles);
+        assert_eq!(from_primary.value(), 1.0);
+        assert_eq!(from_primary, Length::miles(1.0));
+    }
 }
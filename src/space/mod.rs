@@ -6,6 +6,7 @@
 //! - [`Volume`] - A quantity representing a volume (length cubed)
 //! - [`Angle`] - A quantity representing a plane angle
 //! - [`SolidAngle`] - A quantity representing a solid angle
+//! - [`FuelEconomy`] - A quantity representing distance per volume of fuel
 //!
 //! # Example
 //!
@@ -23,12 +24,14 @@
 
 pub mod angle;
 pub mod area;
+pub mod fuel_economy;
 pub mod length;
 pub mod solid_angle;
 pub mod volume;
 
 pub use angle::{Angle, AngleConversions, AngleUnit};
 pub use area::{Area, AreaConversions, AreaUnit};
-pub use length::{Length, LengthConversions, LengthUnit};
+pub use fuel_economy::{FuelEconomy, FuelEconomyConversions, FuelEconomyUnit};
+pub use length::{Length, LengthConversions, LengthUnit, Position};
 pub use solid_angle::{SolidAngle, SolidAngleConversions, SolidAngleUnit};
 pub use volume::{Volume, VolumeConversions, VolumeUnit};
@@ -1,6 +1,6 @@
 //! Solid angle quantity and units.
 
-use std::f64::consts::PI;
+use core::f64::consts::PI;
 crate::quantity! {
     /// A quantity of solid angle.
     ///
@@ -23,6 +23,8 @@ crate::quantity! {
         name: "SolidAngle";
         primary: Steradians;
         si: Steradians;
+        dimensions: [0, 0, 0, 0, 0, 0, 0];
+        angle: 2;
 
         units {
             /// Steradians (sr) - SI unit
@@ -1,16 +1,18 @@
 //! Volume quantity and units.
 
-use crate::systems::metric::{CENTI, DECI, KILO, MILLI};
+use crate::core::Rational;
 
 /// Conversion factors
-const CUBIC_INCH_TO_CUBIC_METER: f64 = 1.6387064e-5;
-const CUBIC_FOOT_TO_CUBIC_METER: f64 = 0.028316846592;
-const CUBIC_YARD_TO_CUBIC_METER: f64 = 0.764554857984;
-const US_GALLON_TO_CUBIC_METER: f64 = 0.003785411784;
-const US_QUART_TO_CUBIC_METER: f64 = US_GALLON_TO_CUBIC_METER / 4.0;
-const US_PINT_TO_CUBIC_METER: f64 = US_GALLON_TO_CUBIC_METER / 8.0;
-const US_CUP_TO_CUBIC_METER: f64 = US_GALLON_TO_CUBIC_METER / 16.0;
-const US_FL_OZ_TO_CUBIC_METER: f64 = US_GALLON_TO_CUBIC_METER / 128.0;
+const CUBIC_INCH_TO_CUBIC_METER: Rational = Rational::new(16_387_064, 1_000_000_000_000);
+pub(crate) const CUBIC_FOOT_TO_CUBIC_METER: Rational =
+    Rational::new(28_316_846_592, 1_000_000_000_000);
+const CUBIC_YARD_TO_CUBIC_METER: Rational = Rational::new(764_554_857_984, 1_000_000_000_000);
+pub(crate) const US_GALLON_TO_CUBIC_METER: Rational =
+    CUBIC_INCH_TO_CUBIC_METER.mul(Rational::integer(231));
+const US_QUART_TO_CUBIC_METER: Rational = US_GALLON_TO_CUBIC_METER.div(Rational::integer(4));
+const US_PINT_TO_CUBIC_METER: Rational = US_GALLON_TO_CUBIC_METER.div(Rational::integer(8));
+const US_CUP_TO_CUBIC_METER: Rational = US_GALLON_TO_CUBIC_METER.div(Rational::integer(16));
+const US_FL_OZ_TO_CUBIC_METER: Rational = US_GALLON_TO_CUBIC_METER.div(Rational::integer(128));
 crate::quantity! {
     /// A quantity of volume.
     ///
@@ -34,12 +36,14 @@ crate::quantity! {
         name: "Volume";
         primary: CubicMeters;
         si: CubicMeters;
+        dimensions: [3, 0, 0, 0, 0, 0, 0];
+        families: [METRIC, LITERS, US_LIQUID];
 
         units {
             /// Cubic millimeters (mm³)
             CubicMillimeters {
                 symbol: "mm³",
-                factor: MILLI * MILLI * MILLI,
+                factor: Rational::pow10(-9),
                 ctor: cubic_millimeters,
                 to: to_cubic_millimeters,
                 si: true
@@ -47,7 +51,7 @@ crate::quantity! {
             /// Cubic centimeters (cm³) - same as milliliters
             CubicCentimeters {
                 symbol: "cm³",
-                factor: CENTI * CENTI * CENTI,
+                factor: Rational::pow10(-6),
                 ctor: cubic_centimeters,
                 to: to_cubic_centimeters,
                 si: true
@@ -55,6 +59,7 @@ crate::quantity! {
             /// Cubic meters (m³) - SI derived unit
             CubicMeters {
                 symbol: "m³",
+                aliases: ["cubic metre", "cubic metres"],
                 factor: 1.0,
                 ctor: cubic_meters,
                 to: to_cubic_meters,
@@ -63,7 +68,7 @@ crate::quantity! {
             /// Cubic kilometers (km³)
             CubicKilometers {
                 symbol: "km³",
-                factor: KILO * KILO * KILO,
+                factor: Rational::pow10(9),
                 ctor: cubic_kilometers,
                 to: to_cubic_kilometers,
                 si: true
@@ -71,7 +76,8 @@ crate::quantity! {
             /// Milliliters (mL)
             Milliliters {
                 symbol: "mL",
-                factor: CENTI * CENTI * CENTI,
+                aliases: ["ml", "millilitre", "millilitres"],
+                factor: Rational::pow10(-6),
                 ctor: milliliters,
                 to: to_milliliters,
                 si: true
@@ -79,7 +85,8 @@ crate::quantity! {
             /// Liters (L)
             Liters {
                 symbol: "L",
-                factor: DECI * DECI * DECI,
+                aliases: ["l", "litre", "litres"],
+                factor: Rational::pow10(-3),
                 ctor: liters,
                 to: to_liters,
                 si: true
@@ -111,6 +118,7 @@ crate::quantity! {
             /// US fluid ounces
             UsFluidOunces {
                 symbol: "fl oz",
+                name: "US fluid ounces",
                 factor: US_FL_OZ_TO_CUBIC_METER,
                 ctor: us_fluid_ounces,
                 to: to_us_fluid_ounces,
@@ -119,6 +127,7 @@ crate::quantity! {
             /// US cups
             UsCups {
                 symbol: "cup",
+                name: "US cups",
                 factor: US_CUP_TO_CUBIC_METER,
                 ctor: us_cups,
                 to: to_us_cups,
@@ -127,6 +136,7 @@ crate::quantity! {
             /// US pints
             UsPints {
                 symbol: "pt",
+                name: "US pints",
                 factor: US_PINT_TO_CUBIC_METER,
                 ctor: us_pints,
                 to: to_us_pints,
@@ -135,6 +145,7 @@ crate::quantity! {
             /// US quarts
             UsQuarts {
                 symbol: "qt",
+                name: "US quarts",
                 factor: US_QUART_TO_CUBIC_METER,
                 ctor: us_quarts,
                 to: to_us_quarts,
@@ -143,6 +154,7 @@ crate::quantity! {
             /// US gallons
             UsGallons {
                 symbol: "gal",
+                name: "US gallons",
                 factor: US_GALLON_TO_CUBIC_METER,
                 ctor: us_gallons,
                 to: to_us_gallons,
@@ -151,6 +163,28 @@ crate::quantity! {
         }
     }
 }
+
+impl VolumeUnit {
+    /// The cubic metric units of volume: mm³, cm³, m³ and km³.
+    pub const METRIC: &'static [VolumeUnit] = &[
+        VolumeUnit::CubicMillimeters,
+        VolumeUnit::CubicCentimeters,
+        VolumeUnit::CubicMeters,
+        VolumeUnit::CubicKilometers,
+    ];
+
+    /// The liter and the milliliter.
+    pub const LITERS: &'static [VolumeUnit] = &[VolumeUnit::Milliliters, VolumeUnit::Liters];
+
+    /// The US customary liquid units commonly used for rescaling: fl oz, cup, qt and gal.
+    pub const US_LIQUID: &'static [VolumeUnit] = &[
+        VolumeUnit::UsFluidOunces,
+        VolumeUnit::UsCups,
+        VolumeUnit::UsQuarts,
+        VolumeUnit::UsGallons,
+    ];
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,5 +219,8 @@ mod tests {
         let v = Volume::us_gallons(1.0);
         // 1 US gallon ≈ 3.785 liters
         assert!((v.to_liters() - 3.785).abs() < 0.01);
+        // Defined as exactly 231 cubic inches
+        assert_eq!(v.to_cubic_inches(), 231.0);
+        assert_eq!(Volume::us_fluid_ounces(128.0), v);
     }
 }
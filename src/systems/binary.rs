@@ -28,6 +28,8 @@
 //! | MiB (mebibyte) | 1,048,576 | MB (megabyte) | 1,000,000 |
 //! | GiB (gibibyte) | 1,073,741,824 | GB (gigabyte) | 1,000,000,000 |
 
+use crate::math;
+
 /// Kibi prefix: 2^10 = 1,024
 pub const KIBI: f64 = 1024.0;
 
@@ -89,7 +91,7 @@ pub const fn binary_symbol(power_of_1024: u32) -> Option<&'static str> {
 /// assert_eq!(binary_prefix(0), 1.0);
 /// ```
 pub fn binary_prefix(power_of_1024: u32) -> f64 {
-    KIBI.powi(power_of_1024 as i32)
+    math::powi(KIBI, power_of_1024 as i32)
 }
 
 #[cfg(test)]
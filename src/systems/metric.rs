@@ -16,6 +16,8 @@
 //! assert_eq!(mg_to_g, 0.025);
 //! ```
 
+use crate::math;
+
 /// Quecto prefix: 10^-30
 pub const QUECTO: f64 = 1e-30;
 
@@ -141,7 +143,7 @@ pub const fn metric_symbol(exponent: i32) -> Option<&'static str> {
 /// assert_eq!(metric_prefix(0), 1.0);
 /// ```
 pub fn metric_prefix(exponent: i32) -> f64 {
-    10f64.powi(exponent)
+    math::powi(10.0, exponent)
 }
 
 #[cfg(test)]
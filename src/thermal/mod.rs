@@ -1,6 +1,7 @@
 //! Thermal quantities and units.
 //!
-//! This module provides temperature and thermal capacity quantities.
+//! This module provides temperature, temperature difference and thermal
+//! capacity quantities.
 //!
 //! # Special Temperature Handling
 //!
@@ -10,11 +11,17 @@
 //! - **Scale conversions**: Adjust for zero offset (5°C = 41°F on a thermometer)
 //! - **Degree conversions**: No zero adjustment (5°C delta = 9°F delta)
 //!
-//! Addition and subtraction treat the right operand as a degree quantity
-//! (not a scale temperature), allowing mixed-scale expressions.
+//! A [`Temperature`] is a point on a scale and a [`TemperatureDelta`] is the
+//! difference between two of them: subtracting two temperatures gives a
+//! delta, adding a delta to a temperature gives a temperature, and adding
+//! two temperatures does not compile.
 
 pub mod temperature;
+pub mod temperature_delta;
 pub mod thermal_capacity;
 
-pub use temperature::{Temperature, TemperatureConversions, TemperatureScale};
+pub use temperature::{
+    Temperature, TemperatureConversions, TemperatureDimension, TemperatureScale,
+};
+pub use temperature_delta::{TemperatureDelta, TemperatureDeltaConversions, TemperatureDeltaUnit};
 pub use thermal_capacity::{ThermalCapacity, ThermalCapacityConversions, ThermalCapacityUnit};
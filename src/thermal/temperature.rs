@@ -6,14 +6,20 @@
 //! - **Scale conversions**: Account for zero offsets (e.g., 0°C = 273.15K)
 //! - **Degree conversions**: Only ratio differences (e.g., 1°C = 1.8°F in magnitude)
 
-use std::cmp::Ordering;
-use std::fmt;
-use std::ops::{Add, Mul, Neg, Sub};
+use super::temperature_delta::{TemperatureDelta, TemperatureDeltaUnit};
+use crate::core::affine::AffinePoint;
+use crate::core::{
+    BaseDimensions, Dimension, DynQuantity, Quantity, Rational, UnitName, UnitOfMeasure,
+};
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Sub};
 
 /// Temperature scales.
 ///
-/// Unlike other units, temperature scales have different zero points,
-/// requiring special conversion logic.
+/// Unlike most units, temperature scales have different zero points, so
+/// besides a conversion factor to Kelvin each scale has a
+/// [conversion offset](UnitOfMeasure::conversion_offset).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TemperatureScale {
     /// Kelvin (K) - SI absolute scale
@@ -45,14 +51,30 @@ impl TemperatureScale {
         }
     }
 
-    /// Returns `(ratio, offset)` for converting this scale to Kelvin:
-    /// `kelvin = value * ratio + offset`.
-    const fn to_kelvin_params(self) -> (f64, f64) {
+    /// Returns the size of one degree of this scale in kelvin.
+    pub fn conversion_factor(&self) -> f64 {
         match self {
-            TemperatureScale::Kelvin => (1.0, 0.0),
-            TemperatureScale::Celsius => (1.0, 273.15),
-            TemperatureScale::Fahrenheit => (5.0 / 9.0, 459.67 * 5.0 / 9.0),
-            TemperatureScale::Rankine => (5.0 / 9.0, 0.0),
+            TemperatureScale::Kelvin | TemperatureScale::Celsius => 1.0,
+            TemperatureScale::Fahrenheit | TemperatureScale::Rankine => 5.0 / 9.0,
+        }
+    }
+
+    /// Returns the zero point of this scale in kelvin.
+    pub fn conversion_offset(&self) -> f64 {
+        match self {
+            TemperatureScale::Kelvin | TemperatureScale::Rankine => 0.0,
+            TemperatureScale::Celsius => 273.15,
+            TemperatureScale::Fahrenheit => 459.67 * 5.0 / 9.0,
+        }
+    }
+
+    /// Returns the unit of a difference between two readings on this scale.
+    pub fn delta_unit(&self) -> TemperatureDeltaUnit {
+        match self {
+            TemperatureScale::Kelvin => TemperatureDeltaUnit::Kelvin,
+            TemperatureScale::Celsius => TemperatureDeltaUnit::CelsiusDegrees,
+            TemperatureScale::Fahrenheit => TemperatureDeltaUnit::FahrenheitDegrees,
+            TemperatureScale::Rankine => TemperatureDeltaUnit::RankineDegrees,
         }
     }
 }
@@ -63,6 +85,58 @@ impl fmt::Display for TemperatureScale {
     }
 }
 
+/// Kelvin is the primary unit. Conversions between scales account for their
+/// zero points; use the `to_*_degrees` methods of [`Temperature`] or
+/// [`TemperatureDelta`] for differences.
+impl UnitOfMeasure for TemperatureScale {
+    fn symbol(&self) -> &'static str {
+        TemperatureScale::symbol(self)
+    }
+
+    fn name(&self) -> UnitName {
+        match self {
+            TemperatureScale::Kelvin => UnitName::new("kelvins"),
+            TemperatureScale::Celsius => UnitName::from(("degrees Celsius", "degree Celsius")),
+            TemperatureScale::Fahrenheit => {
+                UnitName::from(("degrees Fahrenheit", "degree Fahrenheit"))
+            }
+            TemperatureScale::Rankine => UnitName::from(("degrees Rankine", "degree Rankine")),
+        }
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            TemperatureScale::Kelvin => &[],
+            TemperatureScale::Celsius => &["degC", "C", "Celsius"],
+            TemperatureScale::Fahrenheit => &["degF", "F", "Fahrenheit"],
+            TemperatureScale::Rankine => &["degR", "R", "Rankine"],
+        }
+    }
+
+    fn conversion_factor(&self) -> f64 {
+        TemperatureScale::conversion_factor(self)
+    }
+
+    fn conversion_offset(&self) -> f64 {
+        TemperatureScale::conversion_offset(self)
+    }
+
+    fn exact_factor(&self) -> Option<Rational> {
+        match self {
+            TemperatureScale::Kelvin | TemperatureScale::Celsius => Some(Rational::ONE),
+            TemperatureScale::Fahrenheit | TemperatureScale::Rankine => Some(Rational::new(5, 9)),
+        }
+    }
+
+    fn is_si(&self) -> bool {
+        matches!(self, TemperatureScale::Kelvin)
+    }
+
+    fn all_units() -> &'static [Self] {
+        TemperatureScale::ALL
+    }
+}
+
 /// A quantity of temperature.
 ///
 /// Temperature supports both scale and degree conversions.
@@ -74,12 +148,18 @@ impl fmt::Display for TemperatureScale {
 ///
 /// # Arithmetic
 ///
-/// Addition and subtraction treat the right operand as degrees (no offset):
+/// Temperature is an [`AffinePoint`]: subtracting two temperatures gives a
+/// [`TemperatureDelta`], and a delta can be added to or subtracted from a
+/// temperature. Adding two temperatures does not compile.
 /// ```rust
-/// use rquants::thermal::temperature::{Temperature, TemperatureScale};
+/// use rquants::prelude::*;
+///
+/// // 100°F - 5°C = 100°F - 41°F = 59 °F of difference
+/// let delta = Temperature::fahrenheit(100.0) - Temperature::celsius(5.0);
+/// assert!((delta.to_fahrenheit_degrees() - 59.0).abs() < 1e-10);
 ///
-/// // 100°F - 5°C (as degrees) = 100 - 9 = 91°F
-/// let t = Temperature::fahrenheit(100.0) - Temperature::celsius(5.0);
+/// // 100°F - 5 °C of difference = 100 - 9 = 91°F
+/// let t = Temperature::fahrenheit(100.0) - TemperatureDelta::celsius_degrees(5.0);
 /// assert!((t.value() - 91.0).abs() < 1e-10);
 /// ```
 ///
@@ -208,22 +288,22 @@ impl Temperature {
             return *self;
         }
 
-        let (source_ratio, source_offset) = self.scale.to_kelvin_params();
-        let (target_ratio, target_offset) = target.to_kelvin_params();
         let converted = if with_offset {
-            ((self.value * source_ratio + source_offset) - target_offset) / target_ratio
+            self.scale.convert_to(self.value, &target)
         } else {
-            self.value * source_ratio / target_ratio
+            self.value * self.scale.conversion_factor() / target.conversion_factor()
         };
         Temperature::new(converted, target)
     }
 }
 
+/// Readings in degrees are written without a space, as in `20°C`; use
+/// [`Quantity::display`] for the SI style `20 °C`.
 impl fmt::Display for Temperature {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.scale {
-            TemperatureScale::Kelvin => write!(f, "{} {}", self.value, self.scale.symbol()),
-            _ => write!(f, "{}{}", self.value, self.scale.symbol()),
+            TemperatureScale::Kelvin => fmt::Display::fmt(&self.display(), f),
+            _ => fmt::Display::fmt(&self.display().compact(), f),
         }
     }
 }
@@ -240,61 +320,132 @@ impl PartialOrd for Temperature {
     }
 }
 
-// Addition treats right operand as degrees (not scale)
-impl Add for Temperature {
-    type Output = Temperature;
+/// Temperature is a quantity whose units are [`TemperatureScale`]s, so it
+/// works with generic code such as [`QuantityRange`](crate::core::quantity_range::QuantityRange)
+/// and [`Dimension::parse`]. It deliberately does not implement `Sum`:
+/// adding two readings has no meaning (see [`AffinePoint`]).
+impl Quantity for Temperature {
+    type Unit = TemperatureScale;
+
+    fn new(value: f64, unit: Self::Unit) -> Self {
+        Temperature::new(value, unit)
+    }
 
-    fn add(self, rhs: Self) -> Self::Output {
-        let rhs_degrees = rhs.convert(self.scale, false).value;
-        Temperature::new(self.value + rhs_degrees, self.scale)
+    fn value(&self) -> f64 {
+        self.value
     }
+
+    fn unit(&self) -> Self::Unit {
+        self.scale
+    }
+}
+
+impl AffinePoint for Temperature {
+    type Delta = TemperatureDelta;
 }
 
-// Subtraction treats right operand as degrees (not scale)
+// Subtracting two readings accounts for their zero points; the difference is
+// expressed in degrees of the left operand's scale.
 impl Sub for Temperature {
-    type Output = Temperature;
+    type Output = TemperatureDelta;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let rhs_degrees = rhs.convert(self.scale, false).value;
-        Temperature::new(self.value - rhs_degrees, self.scale)
+        TemperatureDelta::new(
+            self.value - rhs.to_scale(self.scale),
+            self.scale.delta_unit(),
+        )
     }
 }
 
-impl Mul<f64> for Temperature {
+impl Add<TemperatureDelta> for Temperature {
     type Output = Temperature;
 
-    fn mul(self, rhs: f64) -> Self::Output {
-        Temperature::new(self.value * rhs, self.scale)
+    fn add(self, rhs: TemperatureDelta) -> Self::Output {
+        Temperature::new(self.value + rhs.to(self.scale.delta_unit()), self.scale)
     }
 }
 
-impl Mul<Temperature> for f64 {
+impl Sub<TemperatureDelta> for Temperature {
     type Output = Temperature;
 
-    fn mul(self, rhs: Temperature) -> Self::Output {
-        Temperature::new(self * rhs.value, rhs.scale)
+    fn sub(self, rhs: TemperatureDelta) -> Self::Output {
+        Temperature::new(self.value - rhs.to(self.scale.delta_unit()), self.scale)
     }
 }
 
-impl Neg for Temperature {
+impl Add<Temperature> for TemperatureDelta {
     type Output = Temperature;
 
-    fn neg(self) -> Self::Output {
-        Temperature::new(-self.value, self.scale)
+    fn add(self, rhs: Temperature) -> Self::Output {
+        rhs + self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for Temperature {
+    type Err = crate::core::error::QuantityParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TemperatureDimension::parse(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Temperature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
     }
 }
 
-// Cross-quantity: Temperature * ThermalCapacity = Energy
-use super::thermal_capacity::ThermalCapacity;
-use crate::core::Quantity;
-use crate::energy::{Energy, EnergyUnit};
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Temperature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <alloc::string::String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Dimension for Temperature.
+///
+/// Besides the scale symbols and long names, parsing accepts `degC`, `degF`
+/// and `degR`, the bare letters `C`, `F` and `R` and the scale names, so
+/// `"72 °F"`, `"300K"`, `"-40 degC"` and `"20 Celsius"` all parse.
+pub struct TemperatureDimension;
+
+impl Dimension for TemperatureDimension {
+    type Quantity = Temperature;
+    type Unit = TemperatureScale;
 
-impl Mul<ThermalCapacity> for Temperature {
-    type Output = Energy;
+    fn name() -> &'static str {
+        "Temperature"
+    }
+
+    fn primary_unit() -> Self::Unit {
+        TemperatureScale::Kelvin
+    }
 
-    fn mul(self, rhs: ThermalCapacity) -> Self::Output {
-        let joules = self.to_kelvin_scale() * rhs.to_joules_per_kelvin();
-        Energy::new(joules, EnergyUnit::Joules)
+    fn si_unit() -> Self::Unit {
+        TemperatureScale::Kelvin
+    }
+
+    fn units() -> &'static [Self::Unit] {
+        TemperatureScale::ALL
+    }
+
+    fn base_dimensions() -> BaseDimensions {
+        BaseDimensions::new([0, 0, 0, 0, 1, 0, 0])
+    }
+
+    // A runtime quantity in kelvin is read as an absolute temperature.
+    fn from_dynamic(quantity: DynQuantity) -> Option<Self::Quantity> {
+        (BaseDimensions::new(quantity.dims()) == Self::base_dimensions())
+            .then(|| Temperature::kelvin(quantity.value()))
     }
 }
 
@@ -328,6 +479,8 @@ impl TemperatureConversions for f64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::quantity_range::QuantityRange;
+    use crate::thermal::temperature_delta::TemperatureDeltaDimension;
 
     #[test]
     fn test_celsius_to_fahrenheit_scale() {
@@ -386,17 +539,34 @@ mod tests {
 
     #[test]
     fn test_mixed_scale_subtraction() {
-        // 100°F - 5°C (as degrees) = 100 - 9 = 91°F
-        let t = Temperature::fahrenheit(100.0) - Temperature::celsius(5.0);
+        // 100°F - 5 °C of difference = 100 - 9 = 91°F
+        let t = Temperature::fahrenheit(100.0) - TemperatureDelta::celsius_degrees(5.0);
         assert!((t.value() - 91.0).abs() < 1e-10);
         assert_eq!(t.scale(), TemperatureScale::Fahrenheit);
     }
 
     #[test]
     fn test_mixed_scale_addition() {
-        // 50°F + 10°C (as degrees) = 50 + 18 = 68°F
-        let t = Temperature::fahrenheit(50.0) + Temperature::celsius(10.0);
+        // 50°F + 10 °C of difference = 50 + 18 = 68°F
+        let t = Temperature::fahrenheit(50.0) + TemperatureDelta::celsius_degrees(10.0);
         assert!((t.value() - 68.0).abs() < 1e-10);
+        let t = TemperatureDelta::kelvin_degrees(10.0) + Temperature::celsius(50.0);
+        assert_eq!(t, Temperature::celsius(60.0));
+    }
+
+    #[test]
+    fn test_point_minus_point() {
+        // 212°F and 0°C are 100 °C apart, expressed in the left scale
+        let delta = Temperature::fahrenheit(212.0) - Temperature::celsius(0.0);
+        assert_eq!(delta.unit(), TemperatureDeltaUnit::FahrenheitDegrees);
+        assert!((delta.to_celsius_degrees() - 100.0).abs() < 1e-10);
+
+        let back = Temperature::celsius(0.0) + delta;
+        assert!((back.to_fahrenheit_scale() - 212.0).abs() < 1e-10);
+        assert_eq!(
+            Temperature::celsius(10.0).midpoint(Temperature::celsius(30.0)),
+            Temperature::celsius(20.0)
+        );
     }
 
     #[test]
@@ -421,6 +591,74 @@ mod tests {
         assert_eq!(format!("{}", f), "72°F");
     }
 
+    #[test]
+    fn test_scale_as_unit_of_measure() {
+        assert!(TemperatureScale::Kelvin.is_primary());
+        assert!(!TemperatureScale::Celsius.is_primary());
+        assert!(TemperatureScale::Kelvin.is_si());
+        let c: f64 = TemperatureScale::Fahrenheit.convert_to(-40.0, &TemperatureScale::Celsius);
+        assert!((c + 40.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_quantity_trait() {
+        let t = Temperature::celsius(25.0);
+        assert!((Quantity::to(&t, TemperatureScale::Kelvin) - 298.15).abs() < 1e-10);
+        assert!((t.to_primary() - 298.15).abs() < 1e-10);
+        assert_eq!(
+            t.in_unit(TemperatureScale::Kelvin).unit(),
+            TemperatureScale::Kelvin
+        );
+
+        let range =
+            QuantityRange::new(Temperature::celsius(0.0), Temperature::celsius(100.0)).unwrap();
+        assert!(range.contains(&Temperature::fahrenheit(72.0)));
+        assert!(!range.contains(&Temperature::kelvin(200.0)));
+    }
+
+    #[test]
+    fn test_parse() {
+        let t: Temperature = "72 °F".parse().unwrap();
+        assert_eq!(t.scale(), TemperatureScale::Fahrenheit);
+        assert_eq!(t.value(), 72.0);
+
+        let t: Temperature = "300K".parse().unwrap();
+        assert_eq!(t, Temperature::kelvin(300.0));
+
+        let t: Temperature = "-40 degC".parse().unwrap();
+        assert_eq!(t, Temperature::celsius(-40.0));
+        assert!((t.to_fahrenheit_scale() + 40.0).abs() < 1e-10);
+
+        assert!("72 °X".parse::<Temperature>().is_err());
+        assert!("hot".parse::<Temperature>().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let t = Temperature::celsius(-40.0);
+        let json = serde_json::to_string(&t).unwrap();
+        assert_eq!(json, "\"-40°C\"");
+        let back: Temperature = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.scale(), TemperatureScale::Celsius);
+        assert_eq!(back, t);
+    }
+
+    #[test]
+    fn test_dimension() {
+        assert_eq!(TemperatureDimension::name(), "Temperature");
+        assert_eq!(
+            TemperatureDimension::primary_unit(),
+            TemperatureScale::Kelvin
+        );
+        assert!(TemperatureDimension::is_compatible_with::<
+            TemperatureDeltaDimension,
+        >());
+        let t = TemperatureDimension::from_dynamic(DynQuantity::new(300.0, [0, 0, 0, 0, 1, 0, 0]));
+        assert_eq!(t, Some(Temperature::kelvin(300.0)));
+        assert!(TemperatureDimension::from_dynamic(DynQuantity::dimensionless(1.0)).is_none());
+    }
+
     #[test]
     fn test_in_scale() {
         let t = Temperature::celsius(100.0);
@@ -0,0 +1,96 @@
+//! Temperature difference quantity and units.
+
+use crate::core::Rational;
+
+crate::quantity! {
+    /// A difference between two temperatures.
+    ///
+    /// Unlike [`Temperature`](super::Temperature), which is a reading on a
+    /// scale with its own zero point, a temperature delta is an ordinary
+    /// quantity: its units differ only in size, so 1 °C of difference is
+    /// 1 K and 1.8 °F.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rquants::prelude::*;
+    ///
+    /// let rise = Temperature::celsius(30.0) - Temperature::celsius(20.0);
+    /// assert!((rise.to_fahrenheit_degrees() - 18.0).abs() < 1e-10);
+    /// assert_eq!(rise.to_kelvin_degrees(), 10.0);
+    /// ```
+    pub quantity TemperatureDelta {
+        unit: TemperatureDeltaUnit;
+        dimension: TemperatureDeltaDimension;
+        conversions: TemperatureDeltaConversions;
+        name: "TemperatureDelta";
+        primary: Kelvin;
+        si: Kelvin;
+        dimensions: [0, 0, 0, 0, 1, 0, 0];
+
+        units {
+            /// Kelvin (K) - SI unit
+            Kelvin {
+                symbol: "K",
+                name: "kelvins",
+                factor: 1.0,
+                ctor: kelvin_degrees,
+                to: to_kelvin_degrees,
+                si: true
+            },
+            /// Degrees Celsius (°C), the same size as kelvin
+            CelsiusDegrees {
+                symbol: "°C",
+                name: ("degrees Celsius", "degree Celsius"),
+                factor: 1.0,
+                ctor: celsius_degrees,
+                to: to_celsius_degrees,
+                si: false
+            },
+            /// Degrees Fahrenheit (°F) - 5/9 K
+            FahrenheitDegrees {
+                symbol: "°F",
+                name: ("degrees Fahrenheit", "degree Fahrenheit"),
+                factor: Rational::new(5, 9),
+                ctor: fahrenheit_degrees,
+                to: to_fahrenheit_degrees,
+                si: false
+            },
+            /// Degrees Rankine (°R), the same size as degrees Fahrenheit
+            RankineDegrees {
+                symbol: "°R",
+                name: ("degrees Rankine", "degree Rankine"),
+                factor: Rational::new(5, 9),
+                ctor: rankine_degrees,
+                to: to_rankine_degrees,
+                si: false
+            }
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Quantity;
+
+    #[test]
+    fn test_temperature_delta_creation() {
+        let d = TemperatureDelta::celsius_degrees(5.0);
+        assert_eq!(d.value(), 5.0);
+        assert_eq!(d.unit(), TemperatureDeltaUnit::CelsiusDegrees);
+    }
+
+    #[test]
+    fn test_temperature_delta_conversions() {
+        let d = TemperatureDelta::celsius_degrees(5.0);
+        assert!((d.to_fahrenheit_degrees() - 9.0).abs() < 1e-10);
+        assert_eq!(d.to_kelvin_degrees(), 5.0);
+        assert!((TemperatureDelta::rankine_degrees(9.0).to_kelvin_degrees() - 5.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_temperature_delta_parse() {
+        let d: TemperatureDelta = "18 °F".parse().unwrap();
+        assert!((d.to_celsius_degrees() - 10.0).abs() < 1e-10);
+    }
+}
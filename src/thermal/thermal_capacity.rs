@@ -1,6 +1,5 @@
 //! Thermal capacity (entropy) quantity and units.
-use crate::core::Quantity;
-use std::ops::Mul;
+
 crate::quantity! {
     /// A quantity of thermal capacity (also represents entropy).
     ///
@@ -11,15 +10,15 @@ crate::quantity! {
     ///
     /// ```rust
     /// use rquants::thermal::thermal_capacity::{ThermalCapacity, ThermalCapacityUnit};
-    /// use rquants::thermal::temperature::Temperature;
+    /// use rquants::thermal::temperature_delta::TemperatureDelta;
     /// use rquants::core::Quantity;
     ///
     /// let tc = ThermalCapacity::joules_per_kelvin(4186.0); // water, ~1 kg
-    /// let temp = Temperature::kelvin(300.0);
+    /// let warming = TemperatureDelta::kelvin_degrees(20.0);
     ///
-    /// // Energy = ThermalCapacity * Temperature
-    /// let energy = tc * temp;
-    /// assert!((energy.to_joules() - 1_255_800.0).abs() < 1.0);
+    /// // Energy = ThermalCapacity * TemperatureDelta
+    /// let energy = tc * warming;
+    /// assert!((energy.to_joules() - 83_720.0).abs() < 1e-9);
     /// ```
     pub quantity ThermalCapacity {
         unit: ThermalCapacityUnit;
@@ -28,6 +27,7 @@ crate::quantity! {
         name: "ThermalCapacity";
         primary: JoulesPerKelvin;
         si: JoulesPerKelvin;
+        dimensions: [2, 1, -2, 0, -1, 0, 0];
 
         units {
             /// Joules per kelvin (J/K) - SI unit
@@ -41,22 +41,11 @@ crate::quantity! {
         }
     }
 }
-// Cross-quantity: ThermalCapacity * Temperature = Energy
-use super::temperature::Temperature;
-use crate::energy::{Energy, EnergyUnit};
-
-impl Mul<Temperature> for ThermalCapacity {
-    type Output = Energy;
-
-    fn mul(self, rhs: Temperature) -> Self::Output {
-        let joules = self.to_joules_per_kelvin() * rhs.to_kelvin_scale();
-        Energy::new(joules, EnergyUnit::Joules)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::Quantity;
+    use crate::thermal::TemperatureDelta;
 
     #[test]
     fn test_thermal_capacity_creation() {
@@ -66,18 +55,11 @@ mod tests {
     }
 
     #[test]
-    fn test_thermal_capacity_times_temperature() {
-        let tc = ThermalCapacity::joules_per_kelvin(100.0);
-        let t = Temperature::kelvin(300.0);
-        let e = tc * t;
-        assert!((e.to_joules() - 30000.0).abs() < 1e-10);
-    }
-
-    #[test]
-    fn test_temperature_times_thermal_capacity() {
-        let t = Temperature::kelvin(300.0);
+    fn test_thermal_capacity_times_temperature_delta() {
         let tc = ThermalCapacity::joules_per_kelvin(100.0);
-        let e = t * tc;
-        assert!((e.to_joules() - 30000.0).abs() < 1e-10);
+        let dt = TemperatureDelta::fahrenheit_degrees(9.0);
+        assert!(((tc * dt).to_joules() - 500.0).abs() < 1e-10);
+        assert!(((dt * tc).to_joules() - 500.0).abs() < 1e-10);
+        assert!((((tc * dt) / tc).to_kelvin_degrees() - 5.0).abs() < 1e-10);
     }
 }
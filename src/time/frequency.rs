@@ -1,5 +1,6 @@
 //! Frequency quantity and units.
 
+use crate::core::Rational;
 use crate::systems::metric::{GIGA, KILO, MEGA, TERA};
 crate::quantity! {
     /// A quantity of frequency.
@@ -25,6 +26,7 @@ crate::quantity! {
         name: "Frequency";
         primary: Hertz;
         si: Hertz;
+        dimensions: [0, 0, -1, 0, 0, 0, 0];
 
         units {
             /// Hertz (Hz) - cycles per second, SI unit
@@ -70,7 +72,7 @@ crate::quantity! {
             /// Revolutions per minute (rpm)
             RevolutionsPerMinute {
                 symbol: "rpm",
-                factor: 1.0 / 60.0,
+                factor: Rational::new(1, 60),
                 ctor: rpm,
                 to: to_rpm,
                 si: false
@@ -4,6 +4,7 @@
 //! - [`Time`] - A quantity representing a duration of time
 //! - [`Frequency`] - A quantity representing cycles per time
 //! - [`TimeIntegral`] / [`TimeDerivative`] - Traits for calculus relationships
+//! - [`QuantitySeries`] - Time series of quantity samples
 //!
 //! # Example
 //!
@@ -20,9 +21,13 @@
 //! ```
 
 pub mod frequency;
+#[cfg(feature = "alloc")]
+pub mod quantity_series;
 pub mod time;
 pub mod time_derivative;
 
 pub use frequency::{Frequency, FrequencyConversions, FrequencyUnit};
-pub use time::{Time, TimeConversions, TimeDimension, TimeUnit};
+#[cfg(feature = "alloc")]
+pub use quantity_series::{QuantitySeries, Resampling};
+pub use time::{Epoch, Instant, Time, TimeConversions, TimeDimension, TimeUnit};
 pub use time_derivative::{SecondTimeDerivative, SecondTimeIntegral, TimeDerivative, TimeIntegral};
@@ -0,0 +1,602 @@
+//! Time series of quantity samples.
+//!
+//! A [`QuantitySeries`] holds `(Time, Q)` samples in strictly increasing time
+//! order and provides the usual signal operations: trapezoidal integration,
+//! finite-difference differentiation, resampling, rolling windows and slicing.
+//! Integration and differentiation use the [`TimeDerivative`] and
+//! [`TimeIntegral`] relationships, so a `Power` series integrates to `Energy`
+//! and a `Length` series differentiates to `Velocity`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rquants::prelude::*;
+//! use rquants::time::QuantitySeries;
+//!
+//! let series = QuantitySeries::from_samples([
+//!     (Time::hours(0.0), Power::kilowatts(0.0)),
+//!     (Time::hours(1.0), Power::kilowatts(2.0)),
+//!     (Time::hours(2.0), Power::kilowatts(2.0)),
+//! ])
+//! .unwrap();
+//!
+//! let energy: Energy = series.integrate().unwrap();
+//! assert!((energy.to_kilowatt_hours() - 3.0).abs() < 1e-10);
+//! ```
+
+use super::{Time, TimeDerivative, TimeIntegral};
+use crate::core::error::QuantityError;
+use crate::core::quantity_range::QuantityRange;
+use crate::core::{Quantity, UnitOfMeasure};
+use crate::math;
+use alloc::{format, vec, vec::Vec};
+
+/// How values between (or beyond) samples are reconstructed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resampling {
+    /// Linear interpolation between the neighbouring samples.
+    Linear,
+    /// The value of the latest sample at or before the requested time.
+    ///
+    /// Times outside the series have no value.
+    Step,
+    /// Like [`Resampling::Step`], but the first and last samples are also
+    /// held before the start and after the end of the series.
+    Hold,
+}
+
+/// A series of quantity samples ordered by strictly increasing time.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::prelude::*;
+/// use rquants::time::{QuantitySeries, Resampling};
+///
+/// let mut series = QuantitySeries::new();
+/// series.push(Time::seconds(0.0), Length::meters(0.0)).unwrap();
+/// series.push(Time::seconds(10.0), Length::meters(50.0)).unwrap();
+///
+/// let midpoint = series.value_at(Time::seconds(5.0), Resampling::Linear).unwrap();
+/// assert!((midpoint.to_meters() - 25.0).abs() < 1e-10);
+///
+/// let velocity: QuantitySeries<Velocity> = series.differentiate();
+/// assert!((velocity.values()[0].to_meters_per_second() - 5.0).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantitySeries<Q: Quantity> {
+    samples: Vec<(Time, Q)>,
+}
+
+impl<Q: Quantity> Default for QuantitySeries<Q> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Q: Quantity> QuantitySeries<Q> {
+    /// Creates an empty series.
+    pub fn new() -> Self {
+        Self {
+            samples: Vec::new(),
+        }
+    }
+
+    /// Creates a series from samples.
+    ///
+    /// Returns a `RangeError` if the sample times are not finite and strictly
+    /// increasing.
+    pub fn from_samples(
+        samples: impl IntoIterator<Item = (Time, Q)>,
+    ) -> Result<Self, QuantityError> {
+        let mut series = Self::new();
+        for (time, value) in samples {
+            series.push(time, value)?;
+        }
+        Ok(series)
+    }
+
+    /// Appends a sample.
+    ///
+    /// Returns a `RangeError` if `time` is not finite or not after the last
+    /// sample.
+    pub fn push(&mut self, time: Time, value: Q) -> Result<(), QuantityError> {
+        if !time.to_seconds().is_finite() {
+            return Err(QuantityError::RangeError(format!(
+                "sample time {time} is not finite"
+            )));
+        }
+        if let Some((last, _)) = self.samples.last() {
+            if time.to_seconds() <= last.to_seconds() {
+                return Err(QuantityError::RangeError(format!(
+                    "sample at {time} is not after the previous sample at {last}"
+                )));
+            }
+        }
+        self.samples.push((time, value));
+        Ok(())
+    }
+
+    /// Returns the number of samples.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns true if the series has no samples.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns the samples in time order.
+    pub fn samples(&self) -> &[(Time, Q)] {
+        &self.samples
+    }
+
+    /// Returns an iterator over the samples in time order.
+    pub fn iter(&self) -> core::slice::Iter<'_, (Time, Q)> {
+        self.samples.iter()
+    }
+
+    /// Returns the sample times.
+    pub fn times(&self) -> Vec<Time> {
+        self.samples.iter().map(|(time, _)| *time).collect()
+    }
+
+    /// Returns the sample values.
+    pub fn values(&self) -> Vec<Q> {
+        self.samples.iter().map(|(_, value)| *value).collect()
+    }
+
+    /// Returns the first sample.
+    pub fn first(&self) -> Option<(Time, Q)> {
+        self.samples.first().copied()
+    }
+
+    /// Returns the last sample.
+    pub fn last(&self) -> Option<(Time, Q)> {
+        self.samples.last().copied()
+    }
+
+    /// Returns the time between the first and last samples.
+    pub fn duration(&self) -> Option<Time> {
+        let (start, _) = self.first()?;
+        let (end, _) = self.last()?;
+        Some(Time::seconds(end.to_seconds() - start.to_seconds()))
+    }
+
+    /// Integrates the series with the trapezoidal rule.
+    ///
+    /// Returns `None` for an empty series and zero for a single sample.
+    pub fn integrate<I>(&self) -> Option<I>
+    where
+        Q: TimeDerivative<I>,
+        I: Quantity,
+    {
+        let (_, first) = self.first()?;
+        let unit = first.unit();
+        let total_unit = first.integrate_over(Time::seconds(0.0)).unit();
+        let mut total = 0.0;
+        for pair in self.samples.windows(2) {
+            let (t0, q0) = pair[0];
+            let (t1, q1) = pair[1];
+            let mean: Q = from_primary((q0.to_primary() + q1.to_primary()) / 2.0, unit);
+            let area = mean.integrate_over(Time::seconds(t1.to_seconds() - t0.to_seconds()));
+            total += area.to_primary();
+        }
+        Some(from_primary(total, total_unit))
+    }
+
+    /// Differentiates the series with finite differences.
+    ///
+    /// The result has one sample per input sample: central differences
+    /// inside the series and one-sided differences at both ends. A series
+    /// with fewer than two samples has no derivative and yields an empty
+    /// series.
+    pub fn differentiate<D>(&self) -> QuantitySeries<D>
+    where
+        Q: TimeIntegral<D>,
+        D: Quantity,
+    {
+        let n = self.samples.len();
+        if n < 2 {
+            return QuantitySeries::new();
+        }
+        let unit = self.samples[0].1.unit();
+        let samples = (0..n)
+            .map(|i| {
+                let (t0, q0) = self.samples[i.saturating_sub(1)];
+                let (t1, q1) = self.samples[(i + 1).min(n - 1)];
+                let delta: Q = from_primary(q1.to_primary() - q0.to_primary(), unit);
+                let rate = delta.per(Time::seconds(t1.to_seconds() - t0.to_seconds()));
+                (self.samples[i].0, rate)
+            })
+            .collect();
+        QuantitySeries { samples }
+    }
+
+    /// Returns the value at `time` reconstructed with `method`.
+    ///
+    /// A time that is not finite has no value.
+    pub fn value_at(&self, time: Time, method: Resampling) -> Option<Q> {
+        let (first_time, first) = self.first()?;
+        let (last_time, last) = self.last()?;
+        let t = time.to_seconds();
+        if !t.is_finite() {
+            return None;
+        }
+        if t < first_time.to_seconds() {
+            return (method == Resampling::Hold).then_some(first);
+        }
+        if t > last_time.to_seconds() {
+            return (method == Resampling::Hold).then_some(last);
+        }
+        // Index of the first sample strictly after `time`
+        let next = self
+            .samples
+            .partition_point(|(sample_time, _)| sample_time.to_seconds() <= t);
+        let (t0, q0) = self.samples[next - 1];
+        if method != Resampling::Linear || next == self.samples.len() {
+            return Some(q0);
+        }
+        let (t1, q1) = self.samples[next];
+        let unit = q0.unit();
+        let fraction = (t - t0.to_seconds()) / (t1.to_seconds() - t0.to_seconds());
+        let (p0, p1) = (q0.to_primary(), q1.to_primary());
+        Some(from_primary(p0 + (p1 - p0) * fraction, unit))
+    }
+
+    /// Resamples the series at the given times.
+    ///
+    /// Times without a value under `method` are skipped. Returns a
+    /// `RangeError` if the times are not strictly increasing.
+    pub fn resample(
+        &self,
+        times: impl IntoIterator<Item = Time>,
+        method: Resampling,
+    ) -> Result<Self, QuantityError> {
+        let mut series = Self::new();
+        for time in times {
+            if let Some(value) = self.value_at(time, method) {
+                series.push(time, value)?;
+            }
+        }
+        Ok(series)
+    }
+
+    /// Resamples the series on a uniform grid from its first sample time.
+    ///
+    /// Returns a `RangeError` if `interval` is not positive.
+    pub fn resample_every(
+        &self,
+        interval: Time,
+        method: Resampling,
+    ) -> Result<Self, QuantityError> {
+        let step = interval.to_seconds();
+        if step <= 0.0 || !step.is_finite() {
+            return Err(QuantityError::RangeError(format!(
+                "resampling interval must be positive, got {interval}"
+            )));
+        }
+        let (Some((start, _)), Some(duration)) = (self.first(), self.duration()) else {
+            return Ok(Self::new());
+        };
+        let count = math::floor(duration.to_seconds() / step + 1e-9) as usize;
+        let times = (0..=count).map(|i| Time::seconds(start.to_seconds() + i as f64 * step));
+        self.resample(times, method)
+    }
+
+    /// Returns the samples whose time lies within `range`.
+    ///
+    /// Like [`QuantityRange::contains`], the upper bound is exclusive.
+    pub fn slice(&self, range: &QuantityRange<Time>) -> Self {
+        let samples = self
+            .samples
+            .iter()
+            .filter(|(time, _)| range.contains(time))
+            .copied()
+            .collect();
+        Self { samples }
+    }
+
+    /// Returns the arithmetic mean of the sample values.
+    pub fn mean(&self) -> Option<Q> {
+        Self::mean_of(&self.samples)
+    }
+
+    /// Returns the smallest sample value.
+    pub fn min(&self) -> Option<Q> {
+        Self::min_of(&self.samples)
+    }
+
+    /// Returns the largest sample value.
+    pub fn max(&self) -> Option<Q> {
+        Self::max_of(&self.samples)
+    }
+
+    /// Returns the mean over a trailing window at every sample.
+    ///
+    /// The window for a sample at `t` covers the samples in `(t - window, t]`.
+    pub fn rolling_mean(&self, window: Time) -> Self {
+        self.rolling(window, Self::mean_of)
+    }
+
+    /// Returns the minimum over a trailing window at every sample.
+    ///
+    /// The window for a sample at `t` covers the samples in `(t - window, t]`.
+    pub fn rolling_min(&self, window: Time) -> Self {
+        self.rolling(window, Self::min_of)
+    }
+
+    /// Returns the maximum over a trailing window at every sample.
+    ///
+    /// The window for a sample at `t` covers the samples in `(t - window, t]`.
+    pub fn rolling_max(&self, window: Time) -> Self {
+        self.rolling(window, Self::max_of)
+    }
+
+    fn rolling(&self, window: Time, reduce: fn(&[(Time, Q)]) -> Option<Q>) -> Self {
+        let width = window.to_seconds();
+        let mut start = 0;
+        let samples = (0..self.samples.len())
+            .filter_map(|end| {
+                let time = self.samples[end].0;
+                while self.samples[start].0.to_seconds() <= time.to_seconds() - width && start < end
+                {
+                    start += 1;
+                }
+                reduce(&self.samples[start..=end]).map(|value| (time, value))
+            })
+            .collect();
+        Self { samples }
+    }
+
+    fn mean_of(samples: &[(Time, Q)]) -> Option<Q> {
+        let (_, first) = samples.first()?;
+        let unit = first.unit();
+        let sum: f64 = samples.iter().map(|(_, value)| value.to_primary()).sum();
+        Some(from_primary(sum / samples.len() as f64, unit))
+    }
+
+    fn min_of(samples: &[(Time, Q)]) -> Option<Q> {
+        samples
+            .iter()
+            .map(|(_, value)| *value)
+            .reduce(|a, b| if b < a { b } else { a })
+    }
+
+    fn max_of(samples: &[(Time, Q)]) -> Option<Q> {
+        samples
+            .iter()
+            .map(|(_, value)| *value)
+            .reduce(|a, b| if b > a { b } else { a })
+    }
+}
+
+impl<'a, Q: Quantity> IntoIterator for &'a QuantitySeries<Q> {
+    type Item = &'a (Time, Q);
+    type IntoIter = core::slice::Iter<'a, (Time, Q)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.samples.iter()
+    }
+}
+
+impl<Q: Quantity> IntoIterator for QuantitySeries<Q> {
+    type Item = (Time, Q);
+    type IntoIter = vec::IntoIter<(Time, Q)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.samples.into_iter()
+    }
+}
+
+/// Returns the quantity whose value in the primary unit is `primary`,
+/// expressed in `unit`.
+///
+/// Sample arithmetic is done on primary values, since a unit such as psig or
+/// Beaufort is not a linear scale of the primary unit.
+fn from_primary<Q: Quantity>(primary: f64, unit: Q::Unit) -> Q {
+    Q::new(unit.convert_from_primary(primary), unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::energy::{Energy, Power};
+    use crate::information::{DataRate, Information};
+    use crate::motion::{Acceleration, Velocity, VelocityUnit};
+    use crate::space::Length;
+
+    fn close(left: f64, right: f64) -> bool {
+        (left - right).abs() < 1e-9
+    }
+
+    fn ramp() -> QuantitySeries<Velocity> {
+        QuantitySeries::from_samples((0..5).map(|i| {
+            (
+                Time::seconds(i as f64),
+                Velocity::meters_per_second(2.0 * i as f64),
+            )
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_push_rejects_unordered_samples() {
+        let mut series = QuantitySeries::new();
+        series
+            .push(Time::seconds(1.0), Length::meters(1.0))
+            .unwrap();
+        assert!(series
+            .push(Time::seconds(1.0), Length::meters(2.0))
+            .is_err());
+        assert!(series
+            .push(Time::seconds(0.5), Length::meters(2.0))
+            .is_err());
+        assert!(series
+            .push(Time::seconds(f64::INFINITY), Length::meters(2.0))
+            .is_err());
+        assert_eq!(series.len(), 1);
+
+        let mut empty = QuantitySeries::new();
+        assert!(empty
+            .push(Time::seconds(f64::NAN), Length::meters(1.0))
+            .is_err());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_integrate_power_to_energy() {
+        let series = QuantitySeries::from_samples([
+            (Time::minutes(0.0), Power::watts(100.0)),
+            (Time::minutes(30.0), Power::watts(300.0)),
+            (Time::minutes(60.0), Power::watts(300.0)),
+        ])
+        .unwrap();
+        let energy: Energy = series.integrate().unwrap();
+        // 0.5 h * 200 W + 0.5 h * 300 W
+        assert!(close(energy.to_watt_hours(), 250.0));
+    }
+
+    #[test]
+    fn test_nonlinear_units_use_primary_values() {
+        let series = QuantitySeries::from_samples([
+            (Time::seconds(0.0), Velocity::beaufort(0.0)),
+            (Time::seconds(10.0), Velocity::beaufort(12.0)),
+        ])
+        .unwrap();
+        // 12 Bft is 0.836·12^1.5 ≈ 34.75 m/s, averaged over 10 s
+        let top = Velocity::beaufort(12.0).to_meters_per_second();
+        let distance: Length = series.integrate().unwrap();
+        assert!(close(distance.to_meters(), top * 5.0));
+        assert!((distance.to_meters() - 173.8).abs() < 0.05);
+
+        let mean = series.mean().unwrap();
+        assert_eq!(mean.unit(), VelocityUnit::Beaufort);
+        assert!(close(mean.to_meters_per_second(), top / 2.0));
+
+        let midpoint = series
+            .value_at(Time::seconds(5.0), Resampling::Linear)
+            .unwrap();
+        assert!(close(midpoint.to_meters_per_second(), top / 2.0));
+
+        let acceleration: QuantitySeries<Acceleration> = series.differentiate();
+        for (_, a) in &acceleration {
+            assert!(close(a.to_meters_per_second_squared(), top / 10.0));
+        }
+    }
+
+    #[test]
+    fn test_integrate_edge_cases() {
+        let empty: QuantitySeries<DataRate> = QuantitySeries::new();
+        let total: Option<Information> = empty.integrate();
+        assert!(total.is_none());
+
+        let single =
+            QuantitySeries::from_samples([(Time::seconds(3.0), DataRate::bytes_per_second(9.0))])
+                .unwrap();
+        let total: Information = single.integrate().unwrap();
+        assert_eq!(total.to_bytes(), 0.0);
+    }
+
+    #[test]
+    fn test_differentiate_velocity_to_acceleration() {
+        let acceleration: QuantitySeries<Acceleration> = ramp().differentiate();
+        assert_eq!(acceleration.len(), 5);
+        for (_, a) in &acceleration {
+            assert!(close(a.to_meters_per_second_squared(), 2.0));
+        }
+        assert_eq!(acceleration.times(), ramp().times());
+
+        let single =
+            QuantitySeries::from_samples([(Time::seconds(0.0), Length::meters(1.0))]).unwrap();
+        let velocity: QuantitySeries<Velocity> = single.differentiate();
+        assert!(velocity.is_empty());
+    }
+
+    #[test]
+    fn test_value_at() {
+        let series = ramp();
+        let at = |t: f64, method| {
+            series
+                .value_at(Time::seconds(t), method)
+                .map(|v| v.to_meters_per_second())
+        };
+        assert_eq!(at(1.5, Resampling::Linear), Some(3.0));
+        assert_eq!(at(1.5, Resampling::Step), Some(2.0));
+        assert_eq!(at(4.0, Resampling::Linear), Some(8.0));
+        assert_eq!(at(-1.0, Resampling::Linear), None);
+        assert_eq!(at(5.0, Resampling::Step), None);
+        assert_eq!(at(-1.0, Resampling::Hold), Some(0.0));
+        assert_eq!(at(5.0, Resampling::Hold), Some(8.0));
+        assert_eq!(at(f64::NAN, Resampling::Linear), None);
+        assert_eq!(at(f64::NAN, Resampling::Hold), None);
+        assert_eq!(at(f64::INFINITY, Resampling::Hold), None);
+    }
+
+    #[test]
+    fn test_resample() {
+        let series = ramp();
+        let every = series
+            .resample_every(Time::milliseconds(500.0), Resampling::Linear)
+            .unwrap();
+        assert_eq!(every.len(), 9);
+        assert!(close(every.values()[3].to_meters_per_second(), 3.0));
+
+        let held = series
+            .resample(
+                [Time::seconds(-1.0), Time::seconds(2.5), Time::seconds(6.0)],
+                Resampling::Hold,
+            )
+            .unwrap();
+        let values: Vec<f64> = held.values().iter().map(|v| v.value()).collect();
+        assert_eq!(values, vec![0.0, 4.0, 8.0]);
+
+        let stepped = series
+            .resample([Time::seconds(-1.0), Time::seconds(2.5)], Resampling::Step)
+            .unwrap();
+        assert_eq!(stepped.len(), 1);
+
+        assert!(series
+            .resample_every(Time::seconds(0.0), Resampling::Linear)
+            .is_err());
+    }
+
+    #[test]
+    fn test_slice_and_statistics() {
+        let series = ramp();
+        let range = QuantityRange::new(Time::seconds(1.0), Time::seconds(3.0)).unwrap();
+        let sliced = series.slice(&range);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.first().unwrap().0, Time::seconds(1.0));
+
+        assert!(close(series.mean().unwrap().to_meters_per_second(), 4.0));
+        assert_eq!(series.min().unwrap(), Velocity::meters_per_second(0.0));
+        assert_eq!(series.max().unwrap(), Velocity::meters_per_second(8.0));
+        assert!(QuantitySeries::<Velocity>::new().mean().is_none());
+    }
+
+    #[test]
+    fn test_rolling_windows() {
+        let series = ramp();
+        let window = Time::seconds(2.0);
+        let mean: Vec<f64> = series
+            .rolling_mean(window)
+            .values()
+            .iter()
+            .map(|v| v.value())
+            .collect();
+        assert_eq!(mean, vec![0.0, 1.0, 3.0, 5.0, 7.0]);
+
+        let min: Vec<f64> = series
+            .rolling_min(window)
+            .values()
+            .iter()
+            .map(|v| v.value())
+            .collect();
+        assert_eq!(min, vec![0.0, 0.0, 2.0, 4.0, 6.0]);
+
+        let max = series.rolling_max(window);
+        assert_eq!(max.values(), series.values());
+    }
+}
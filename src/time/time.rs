@@ -1,6 +1,7 @@
 //! Time quantity and units.
 
-use crate::systems::metric::{MICRO, MILLI, NANO};
+use crate::core::affine::{Frame, Point};
+use crate::core::{CompositeUnit, Rational};
 
 /// Time conversion constants.
 pub mod constants {
@@ -50,12 +51,14 @@ crate::quantity! {
         name: "Time";
         primary: Seconds;
         si: Seconds;
+        dimensions: [0, 0, 1, 0, 0, 0, 0];
+        families: [ALL];
 
         units {
             /// Nanoseconds (ns) - 10^-9 seconds
             Nanoseconds {
                 symbol: "ns",
-                factor: NANO,
+                factor: Rational::pow10(-9),
                 ctor: nanoseconds,
                 to: to_nanoseconds,
                 si: true
@@ -63,7 +66,7 @@ crate::quantity! {
             /// Microseconds (µs) - 10^-6 seconds
             Microseconds {
                 symbol: "µs",
-                factor: MICRO,
+                factor: Rational::pow10(-6),
                 ctor: microseconds,
                 to: to_microseconds,
                 si: true
@@ -71,7 +74,8 @@ crate::quantity! {
             /// Milliseconds (ms) - 10^-3 seconds
             Milliseconds {
                 symbol: "ms",
-                factor: MILLI,
+                aliases: ["msec"],
+                factor: Rational::pow10(-3),
                 ctor: milliseconds,
                 to: to_milliseconds,
                 si: true
@@ -79,6 +83,7 @@ crate::quantity! {
             /// Seconds (s) - SI base unit
             Seconds {
                 symbol: "s",
+                aliases: ["sec", "secs"],
                 factor: 1.0,
                 ctor: seconds,
                 to: to_seconds,
@@ -87,6 +92,7 @@ crate::quantity! {
             /// Minutes (min) - 60 seconds
             Minutes {
                 symbol: "min",
+                aliases: ["mins"],
                 factor: SECONDS_PER_MINUTE,
                 ctor: minutes,
                 to: to_minutes,
@@ -95,6 +101,7 @@ crate::quantity! {
             /// Hours (h) - 3600 seconds
             Hours {
                 symbol: "h",
+                aliases: ["hr", "hrs"],
                 factor: SECONDS_PER_HOUR,
                 ctor: hours,
                 to: to_hours,
@@ -111,12 +118,47 @@ crate::quantity! {
         }
     }
 }
+
+impl CompositeUnit<TimeUnit> {
+    /// Days, hours, minutes and seconds, written compactly as `1h 23m 4s`.
+    pub const DURATION: Self = CompositeUnit::new(&[
+        TimeUnit::Days,
+        TimeUnit::Hours,
+        TimeUnit::Minutes,
+        TimeUnit::Seconds,
+    ])
+    .labels(&["d", "h", "m", "s"])
+    .compact();
+}
+
 impl Time {
     /// Returns the value in milliseconds as a long (truncated).
     pub fn millis(&self) -> i64 {
         self.to_milliseconds() as i64
     }
 }
+
+/// Frame of instants, measured from an application-defined epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Epoch;
+
+impl Frame for Epoch {}
+
+/// A point in time, measured as a [`Time`] since an [`Epoch`].
+///
+/// Subtracting two instants gives the duration between them as a `Time`.
+///
+/// # Example
+///
+/// ```rust
+/// use rquants::prelude::*;
+/// use rquants::time::Instant;
+///
+/// let launch = Instant::new(Time::seconds(30.0));
+/// let burnout = launch + Time::minutes(2.5);
+/// assert_eq!((burnout - launch).to_seconds(), 150.0);
+/// ```
+pub type Instant = Point<Time, Epoch>;
 #[cfg(test)]
 mod tests {
     use super::*;
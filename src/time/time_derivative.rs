@@ -8,9 +8,32 @@
 //! - Velocity is the TimeDerivative of Length (Velocity * Time = Length)
 //! - Velocity is also the TimeIntegral of Acceleration
 //! - Acceleration is the TimeDerivative of Velocity
+//!
+//! Every rate pair in the crate implements both traits, so accumulation and
+//! rate calculations can be written generically:
+//!
+//! ```rust
+//! use rquants::prelude::*;
+//!
+//! fn accumulate<D: TimeDerivative<I>, I: Quantity>(rate: D, dt: Time) -> I {
+//!     rate.integrate_over(dt)
+//! }
+//!
+//! let energy = accumulate(Power::kilowatts(2.0), Time::hours(3.0));
+//! assert!((energy.to_kilowatt_hours() - 6.0).abs() < 1e-10);
+//!
+//! let data = accumulate(DataRate::megabytes_per_second(10.0), Time::seconds(4.0));
+//! assert!((data.to_megabytes() - 40.0).abs() < 1e-10);
+//! ```
 
 use super::Time;
 use crate::core::Quantity;
+use crate::electro::{ElectricCharge, ElectricCurrent, ElectricPotential, MagneticFlux};
+use crate::energy::{Energy, Power, PowerRamp};
+use crate::information::{DataRate, Information};
+use crate::motion::{Acceleration, Force, Momentum, Velocity, VolumeFlow};
+use crate::photo::{Illuminance, LuminousEnergy, LuminousExposure, LuminousFlux};
+use crate::space::{Length, Volume};
 
 /// A quantity that is the time derivative of another quantity.
 ///
@@ -113,8 +136,116 @@ pub trait SecondTimeIntegral<SecondDerivative: Quantity>: Quantity {
     fn per_time_squared(&self, time_squared: f64, time_unit: Time) -> SecondDerivative;
 }
 
+/// Implements `TimeDerivative` and `TimeIntegral` for rate pairs.
+///
+/// The time unit is the one a single unit of the derivative naturally
+/// integrates over, e.g. one hour for `Power` so that 1 kW becomes 1 kWh.
+macro_rules! time_derivatives {
+    ($($derivative:ident => $integral:ident per $time:expr;)+) => {
+        $(
+            impl TimeDerivative<$integral> for $derivative {
+                fn integrate_over(&self, time: Time) -> $integral {
+                    *self * time
+                }
+
+                fn time_integrated(&self) -> $integral {
+                    self.integrate_over(self.derivative_time())
+                }
+
+                fn derivative_time(&self) -> Time {
+                    $time
+                }
+            }
+
+            impl TimeIntegral<$derivative> for $integral {
+                fn per(&self, time: Time) -> $derivative {
+                    *self / time
+                }
+
+                fn time_derived(&self) -> $derivative {
+                    self.per(self.integral_time())
+                }
+
+                fn integral_time(&self) -> Time {
+                    $time
+                }
+            }
+        )+
+    };
+}
+
+time_derivatives! {
+    Velocity => Length per Time::seconds(1.0);
+    Acceleration => Velocity per Time::seconds(1.0);
+    Force => Momentum per Time::seconds(1.0);
+    VolumeFlow => Volume per Time::seconds(1.0);
+    Power => Energy per Time::hours(1.0);
+    PowerRamp => Power per Time::hours(1.0);
+    ElectricCurrent => ElectricCharge per Time::seconds(1.0);
+    ElectricPotential => MagneticFlux per Time::seconds(1.0);
+    DataRate => Information per Time::seconds(1.0);
+    LuminousFlux => LuminousEnergy per Time::seconds(1.0);
+    Illuminance => LuminousExposure per Time::seconds(1.0);
+}
+
 #[cfg(test)]
 mod tests {
-    // Tests will be added when we implement concrete quantities
-    // that use these traits (e.g., Length, Velocity, Acceleration)
+    use super::*;
+
+    fn accumulate<D: TimeDerivative<I>, I: Quantity>(rate: D, dt: Time) -> I {
+        rate.integrate_over(dt)
+    }
+
+    fn average_rate<I: TimeIntegral<D>, D: Quantity>(total: I, dt: Time) -> D {
+        total.per(dt)
+    }
+
+    #[test]
+    fn test_generic_accumulation() {
+        let length: Length = accumulate(Velocity::meters_per_second(3.0), Time::seconds(4.0));
+        assert!((length.to_meters() - 12.0).abs() < 1e-10);
+
+        let charge: ElectricCharge = accumulate(ElectricCurrent::amperes(2.0), Time::hours(1.0));
+        assert!((charge.to_coulombs() - 7200.0).abs() < 1e-9);
+
+        let volume: Volume = accumulate(VolumeFlow::liters_per_minute(6.0), Time::minutes(10.0));
+        assert!((volume.to_liters() - 60.0).abs() < 1e-9);
+
+        let energy: LuminousEnergy = accumulate(LuminousFlux::lumens(100.0), Time::seconds(2.0));
+        assert!((energy.to_lumen_seconds() - 200.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_generic_rate() {
+        let velocity: Velocity = average_rate(Length::kilometers(10.0), Time::hours(0.5));
+        assert!((velocity.to_kilometers_per_hour() - 20.0).abs() < 1e-9);
+
+        let rate: DataRate = average_rate(Information::gigabytes(3.6), Time::hours(1.0));
+        assert!((rate.to_megabytes_per_second() - 1.0).abs() < 1e-9);
+
+        let ramp: PowerRamp = Power::kilowatts(10.0).divide_by_time(Time::hours(2.0));
+        assert!((ramp.to_kilowatts_per_hour() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unit_time_conversions() {
+        let power = Power::kilowatts(3.0);
+        assert_eq!(
+            TimeDerivative::<Energy>::derivative_time(&power),
+            Time::hours(1.0)
+        );
+        assert!((power.time_integrated().to_kilowatt_hours() - 3.0).abs() < 1e-10);
+
+        let energy = Energy::kilowatt_hours(3.0);
+        assert_eq!(energy.integral_time(), Time::hours(1.0));
+        assert!((energy.time_derived().to_kilowatts() - 3.0).abs() < 1e-10);
+
+        let velocity = Velocity::meters_per_second(5.0);
+        assert!(
+            (TimeIntegral::<Acceleration>::time_derived(&velocity).to_meters_per_second_squared()
+                - 5.0)
+                .abs()
+                < 1e-10
+        );
+    }
 }
@@ -0,0 +1,296 @@
+//! Defines quantities outside the crate with the public `quantity!` macro.
+#![deny(missing_docs)]
+
+#[cfg(feature = "std")]
+use rquants::core::unit_expr::{matching_dimensions, register, UnitExpr};
+use rquants::core::UnitMatchError;
+use rquants::prelude::*;
+use rquants::{Dimension, Quantity};
+
+rquants::quantity! {
+    /// Data transferred per unit of energy.
+    pub quantity EnergyEfficiency {
+        unit: EnergyEfficiencyUnit;
+        dimension: EnergyEfficiencyDimension;
+        conversions: EnergyEfficiencyConversions;
+        name: "EnergyEfficiency";
+        primary: BytesPerJoule;
+        si: BytesPerJoule;
+        dimensions: [-2, -1, 2, 0, 0, 0, 0];
+        information: 1;
+
+        units {
+            /// Bytes per joule
+            BytesPerJoule {
+                symbol: "B/J",
+                factor: 1.0,
+                ctor: bytes_per_joule,
+                to: to_bytes_per_joule,
+                si: true
+            },
+            /// Megabytes per kilowatt-hour
+            MegabytesPerKilowattHour {
+                symbol: "MB/kWh",
+                factor: 1e6 / 3.6e6,
+                ctor: megabytes_per_kilowatt_hour,
+                to: to_megabytes_per_kilowatt_hour,
+                si: false
+            }
+        }
+
+        relations {
+            EnergyEfficiency * Energy => Information;
+            Information / EnergyEfficiency => Energy;
+        }
+    }
+}
+
+rquants::quantity! {
+    /// Kinematic viscosity, defined without a DSL trait.
+    pub quantity KinematicViscosity {
+        unit: KinematicViscosityUnit;
+        dimension: KinematicViscosityDimension;
+        name: "KinematicViscosity";
+        primary: SquareMetersPerSecond;
+        si: SquareMetersPerSecond;
+        dimensions: [2, 0, -1, 0, 0, 0, 0];
+        matching: UnitMatching::relaxed();
+
+        units {
+            /// Square meters per second
+            SquareMetersPerSecond {
+                symbol: "m²/s",
+                aliases: ["m2/s", "m2 s-1"],
+                factor: 1.0,
+                ctor: square_meters_per_second,
+                to: to_square_meters_per_second,
+                si: true
+            },
+            /// Stokes (cm²/s)
+            Stokes {
+                symbol: "St",
+                name: ("stokes", "stokes"),
+                factor: 1e-4,
+                ctor: stokes,
+                to: to_stokes,
+                si: true
+            }
+        }
+    }
+}
+
+rquants::quantity! {
+    /// Pitch of a tone, with a logarithmic note-number unit.
+    pub quantity Pitch {
+        unit: PitchUnit;
+        dimension: PitchDimension;
+        name: "Pitch";
+        primary: Hertz;
+        si: Hertz;
+        dimensions: [0, 0, -1, 0, 0, 0, 0];
+
+        units {
+            /// Hertz
+            Hertz {
+                symbol: "Hz",
+                factor: 1.0,
+                ctor: hertz,
+                to: to_hertz,
+                si: true
+            },
+            /// MIDI note number, with A4 = 69 = 440 Hz
+            MidiNote {
+                symbol: "note",
+                factor: 1.0,
+                convert: (
+                    |note| 440.0 * 2f64.powf((note - 69.0) / 12.0),
+                    |hz| 69.0 + 12.0 * (hz / 440.0).log2()
+                ),
+                ctor: midi_note,
+                to: to_midi_note,
+                si: false
+            },
+            /// Hertz above concert A (440 Hz)
+            HertzAboveA4 {
+                symbol: "Hz+A4",
+                name: ("hertz above A4", "hertz above A4"),
+                factor: 1.0,
+                offset: 440.0,
+                ctor: hertz_above_a4,
+                to: to_hertz_above_a4,
+                si: false
+            }
+        }
+    }
+}
+
+fn close(left: f64, right: f64) -> bool {
+    (left - right).abs() <= left.abs().max(right.abs()).max(1.0) * 1e-9
+}
+
+#[test]
+fn custom_quantity_behaves_like_builtin() {
+    let efficiency = EnergyEfficiency::megabytes_per_kilowatt_hour(3.6);
+    assert!(close(efficiency.to_bytes_per_joule(), 1.0));
+    assert_eq!(efficiency.to_string(), "3.6 MB/kWh");
+    assert!(close(
+        (2.0.bytes_per_joule() + efficiency).to_bytes_per_joule(),
+        3.0
+    ));
+
+    assert_eq!(EnergyEfficiencyDimension::name(), "EnergyEfficiency");
+    assert_eq!(
+        EnergyEfficiencyDimension::primary_unit(),
+        EnergyEfficiencyUnit::BytesPerJoule
+    );
+    assert_eq!(EnergyEfficiencyUnit::ALL.len(), 2);
+    assert_eq!(
+        EnergyEfficiencyUnit::MegabytesPerKilowattHour.symbol(),
+        "MB/kWh"
+    );
+
+    let parsed: EnergyEfficiency = "7 B/J".parse().unwrap();
+    assert_eq!(parsed.unit(), EnergyEfficiencyUnit::BytesPerJoule);
+    assert!(close(parsed.value(), 7.0));
+}
+
+#[test]
+fn base_dimensions_are_exposed() {
+    let dims = EnergyEfficiencyDimension::base_dimensions();
+    assert_eq!(dims.si(), [-2, -1, 2, 0, 0, 0, 0]);
+    assert_eq!(dims.information(), 1);
+    assert!(!KinematicViscosityDimension::base_dimensions().is_dimensionless());
+}
+
+#[test]
+fn relations_use_dimensional_analysis() {
+    let efficiency = EnergyEfficiency::bytes_per_joule(8.0);
+    let information = efficiency * Energy::kilojoules(2.0);
+    assert!(close(information.to_kilobytes(), 16.0));
+
+    let energy = Information::bytes(4.0) / efficiency;
+    assert!(close(energy.to_joules(), 0.5));
+}
+
+#[test]
+fn custom_quantities_support_other_scalars() {
+    let efficiency = EnergyEfficiency::<f32>::new(8.0, EnergyEfficiencyUnit::BytesPerJoule);
+    let information: Information<f32> = efficiency * Energy::kilojoules(2.0).cast();
+    assert!((information.to_kilobytes() - 16.0).abs() < 1e-4);
+    assert_eq!((efficiency * 0.5).to_bytes_per_joule(), 4.0_f32);
+}
+
+#[test]
+fn dynamic_round_trip() {
+    let dynamic: DynQuantity = KinematicViscosity::stokes(2.5).into();
+    assert_eq!(dynamic.dims(), [2, 0, -1, 0, 0, 0, 0]);
+    assert!(close(dynamic.value(), 2.5e-4));
+
+    let back = KinematicViscosity::try_from(dynamic).unwrap();
+    assert_eq!(back.unit(), KinematicViscosityUnit::SquareMetersPerSecond);
+    assert!(close(back.to_stokes(), 2.5));
+    assert!(KinematicViscosity::try_from(DynQuantity::from(1.0)).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn registered_units_join_compound_expressions() {
+    assert!(UnitExpr::parse("cSt·s").is_err());
+
+    register::<KinematicViscosityDimension>();
+    register::<KinematicViscosityDimension>();
+
+    let expr = UnitExpr::parse("cSt·s").unwrap();
+    assert!(close(expr.factor(), 1e-6));
+    assert_eq!(expr.dims(), [2, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        matching_dimensions(&[2, 0, -1, 0, 0, 0, 0]),
+        vec!["KinematicViscosity"]
+    );
+
+    let viscosity: KinematicViscosity = "3 mm²/s".parse().unwrap();
+    assert!(close(viscosity.to_stokes(), 0.03));
+}
+
+#[test]
+fn units_with_offsets_and_conversion_functions() {
+    let a4 = Pitch::midi_note(69.0);
+    assert!(close(a4.to_hertz(), 440.0));
+    assert!(close(Pitch::midi_note(60.0).to_hertz(), 261.6255653005986));
+    assert!(close(Pitch::hertz(880.0).to_midi_note(), 81.0));
+    assert!(close(Pitch::hertz(445.0).to_hertz_above_a4(), 5.0));
+    assert!(!PitchUnit::MidiNote.is_linear());
+    assert!(!PitchUnit::HertzAboveA4.is_linear());
+    assert!(PitchUnit::Hertz.is_exact());
+    assert!(!PitchUnit::MidiNote.is_exact());
+    assert_eq!(PitchUnit::HertzAboveA4.conversion_offset(), 440.0);
+
+    // Arithmetic happens in hertz: doubling the frequency is one octave up
+    let octave_up = a4 * 2.0;
+    assert_eq!(octave_up.unit(), PitchUnit::Hertz);
+    assert!(close(octave_up.to_midi_note(), 81.0));
+    let sum = Pitch::hertz_above_a4(10.0) + Pitch::hertz(10.0);
+    assert_eq!(sum.unit(), PitchUnit::Hertz);
+    assert!(close(sum.to_hertz_above_a4(), 20.0));
+
+    let parsed: Pitch = "57 note".parse().unwrap();
+    assert!(close(parsed.to_hertz(), 220.0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn nonlinear_units_stay_out_of_compound_expressions() {
+    register::<PitchDimension>();
+    assert!(UnitExpr::parse("Hz").is_ok());
+    assert!(UnitExpr::parse("note/s").is_err());
+}
+
+#[test]
+fn unit_names() {
+    let viscosity = KinematicViscosity::stokes(1.0);
+    assert_eq!(viscosity.display().long_names().to_string(), "1 stokes");
+    let viscosity = KinematicViscosity::square_meters_per_second(2.5);
+    assert_eq!(
+        viscosity.display().long_names().to_string(),
+        "2.5 square meters per second"
+    );
+    assert_eq!(
+        format!("{:.1}", Pitch::midi_note(1.0).display().long_names()),
+        "1.0 midi note"
+    );
+    assert_eq!(PitchUnit::HertzAboveA4.name().to_string(), "hertz above A4");
+}
+
+#[test]
+fn unit_aliases_and_matching() {
+    assert_eq!(
+        KinematicViscosityUnit::SquareMetersPerSecond.aliases(),
+        ["m2/s", "m2 s-1"]
+    );
+    let parsed: KinematicViscosity = "1.5 m2 s-1".parse().unwrap();
+    assert_eq!(parsed, KinematicViscosity::square_meters_per_second(1.5));
+    let parsed: KinematicViscosity = "2 stokes".parse().unwrap();
+    assert_eq!(parsed, KinematicViscosity::stokes(2.0));
+    // The quantity opts in to relaxed matching
+    let parsed: KinematicViscosity = "3 ST".parse().unwrap();
+    assert_eq!(parsed, KinematicViscosity::stokes(3.0));
+    assert!("3 b/j".parse::<EnergyEfficiency>().is_err());
+    assert_eq!(
+        EnergyEfficiencyDimension::parse_with("3 b/j", UnitMatching::relaxed()).unwrap(),
+        EnergyEfficiency::bytes_per_joule(3.0)
+    );
+    assert_eq!(
+        KinematicViscosityDimension::find_unit("M²/S", UnitMatching::exact()),
+        Err(UnitMatchError::Unknown)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let efficiency = EnergyEfficiency::bytes_per_joule(12.5);
+    let json = serde_json::to_string(&efficiency).unwrap();
+    assert_eq!(json, "\"12.5 B/J\"");
+    let back: EnergyEfficiency = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, efficiency);
+}
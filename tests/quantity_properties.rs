@@ -36,10 +36,18 @@ where
         + Sub<Output = D::Quantity>
         + Mul<f64, Output = D::Quantity>
         + Div<f64, Output = D::Quantity>
-        + FromStr<Err = QuantityParseError>,
+        + FromStr<Err = QuantityParseError>
+        + Into<DynQuantity>
+        + TryFrom<DynQuantity, Error = QuantityError>,
     <D::Quantity as FromStr>::Err: Debug,
 {
-    let units = D::units();
+    // Affine units hold over the whole range; units with their own
+    // conversion functions only over their domain (see below).
+    let units: Vec<D::Unit> = D::units()
+        .iter()
+        .copied()
+        .filter(|unit| unit.is_linear() || unit.conversion_offset() != 0.0)
+        .collect();
     prop_assert!(!units.is_empty());
 
     let source = units[source_index % units.len()];
@@ -105,6 +113,16 @@ where
         D::name()
     );
 
+    let dynamic: DynQuantity = quantity.into();
+    prop_assert_eq!(dynamic.dims(), D::base_dimensions().si());
+    let from_dynamic = D::Quantity::try_from(dynamic)
+        .map_err(|err| TestCaseError::fail(format!("dynamic {dynamic}: {err}")))?;
+    prop_assert!(
+        close(from_dynamic.to_primary(), quantity.to_primary()),
+        "dynamic round-trip failed for {}",
+        D::name()
+    );
+
     Ok(())
 }
 
@@ -171,6 +189,7 @@ quantity_properties! {
     momentum_properties => rquants::motion::momentum::MomentumDimension;
     pressure_properties => rquants::motion::pressure::PressureDimension;
     velocity_properties => rquants::motion::velocity::VelocityDimension;
+    volume_flow_properties => rquants::motion::volume_flow::VolumeFlowDimension;
     illuminance_properties => rquants::photo::illuminance::IlluminanceDimension;
     luminance_properties => rquants::photo::luminance::LuminanceDimension;
     luminous_energy_properties => rquants::photo::luminous_energy::LuminousEnergyDimension;
@@ -187,6 +206,7 @@ quantity_properties! {
     spectral_power_properties => rquants::radio::spectral_power::SpectralPowerDimension;
     angle_properties => rquants::space::angle::AngleDimension;
     area_properties => rquants::space::area::AreaDimension;
+    fuel_economy_properties => rquants::space::fuel_economy::FuelEconomyDimension;
     length_properties => rquants::space::length::LengthDimension;
     solid_angle_properties => rquants::space::solid_angle::SolidAngleDimension;
     volume_properties => rquants::space::volume::VolumeDimension;
@@ -195,6 +215,35 @@ quantity_properties! {
     time_properties => rquants::time::time::TimeDimension;
 }
 
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 64,
+        failure_persistence: Some(Box::new(FileFailurePersistence::Off)),
+        .. ProptestConfig::default()
+    })]
+
+    #[test]
+    fn non_linear_units_round_trip(
+        gauge in -3.0f64..40.0,
+        force in 0.0f64..17.0,
+        consumption in 0.5f64..50.0,
+    ) {
+        let wire = Area::american_wire_gauge(gauge);
+        let in_mm2 = wire.in_unit(AreaUnit::SquareMillimeters);
+        prop_assert!(close(in_mm2.to_american_wire_gauge(), gauge));
+        let parsed: Area = wire.to_string().parse().unwrap();
+        prop_assert!(close(parsed.to_primary(), wire.to_primary()));
+
+        let wind = Velocity::beaufort(force);
+        prop_assert!(close(wind.in_unit(VelocityUnit::Knots).to_beaufort(), force));
+
+        let economy = FuelEconomy::liters_per_100_kilometers(consumption);
+        let in_mpg = economy.in_unit(FuelEconomyUnit::MilesPerGallon);
+        prop_assert!(close(in_mpg.to_liters_per_100_kilometers(), consumption));
+        prop_assert!((economy * 2.0).to_liters_per_100_kilometers() < consumption);
+    }
+}
+
 #[test]
 fn standard_traits_work_for_generated_quantities() {
     let total: Length = [Length::meters(1.0), Length::centimeters(50.0)]